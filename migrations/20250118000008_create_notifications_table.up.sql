@@ -0,0 +1,32 @@
+-- Create notifications table
+CREATE TABLE IF NOT EXISTS notifications (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    recipient_id UUID NOT NULL,
+    actor_id UUID NOT NULL,
+    kind VARCHAR(50) NOT NULL,
+    article_id UUID,
+    comment_id UUID,
+    read_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT fk_notifications_recipient FOREIGN KEY (recipient_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_notifications_actor FOREIGN KEY (actor_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_notifications_article FOREIGN KEY (article_id) REFERENCES articles(id) ON DELETE CASCADE,
+    CONSTRAINT fk_notifications_comment FOREIGN KEY (comment_id) REFERENCES comments(id) ON DELETE CASCADE,
+    CONSTRAINT chk_notifications_kind CHECK (kind IN ('article_commented', 'article_favorited', 'user_followed'))
+);
+
+-- Create index on recipient_id and created_at for listing notifications of a user
+CREATE INDEX idx_notifications_recipient_id_created_at ON notifications(recipient_id, created_at DESC);
+
+-- Create partial index for counting unread notifications
+CREATE INDEX idx_notifications_unread ON notifications(recipient_id) WHERE read_at IS NULL;
+
+-- Create notification_preferences table, a missing row means every event is recorded
+CREATE TABLE IF NOT EXISTS notification_preferences (
+    user_id UUID PRIMARY KEY,
+    article_commented BOOLEAN NOT NULL DEFAULT TRUE,
+    article_favorited BOOLEAN NOT NULL DEFAULT TRUE,
+    user_followed BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT fk_notification_preferences_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,14 @@
+-- Create outbox_events table, domain events are written here in the same transaction as the
+-- change that produced them and published to the event sinks afterwards
+CREATE TABLE IF NOT EXISTS outbox_events (
+    id BIGSERIAL PRIMARY KEY,
+    event_type VARCHAR(50) NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    processed_at TIMESTAMPTZ
+);
+
+-- Create partial index for picking up events that haven't been published yet
+CREATE INDEX idx_outbox_events_pending ON outbox_events(id) WHERE processed_at IS NULL;
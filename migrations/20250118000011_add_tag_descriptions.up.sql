@@ -0,0 +1,2 @@
+-- Add an optional description to tags, set by admins
+ALTER TABLE tags ADD COLUMN IF NOT EXISTS description TEXT;
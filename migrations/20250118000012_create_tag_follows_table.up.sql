@@ -0,0 +1,12 @@
+-- Create tag_follows join table for users following tags
+CREATE TABLE IF NOT EXISTS tag_follows (
+    user_id UUID NOT NULL,
+    tag_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, tag_id),
+    CONSTRAINT fk_tag_follows_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_tag_follows_tag FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE
+);
+
+-- Create index on tag_id for faster lookups of a tag's followers
+CREATE INDEX idx_tag_follows_tag_id ON tag_follows(tag_id);
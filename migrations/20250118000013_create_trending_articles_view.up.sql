@@ -0,0 +1,31 @@
+-- Create trending_articles materialized view ranking articles by recent activity.
+-- Favorites and comments inside each window are weighted and decay exponentially with age,
+-- so the ranking is computed on refresh rather than on every request.
+CREATE INDEX IF NOT EXISTS idx_article_favorites_created_at ON article_favorites(created_at DESC);
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS trending_articles AS
+WITH windows (name, duration, decay_hours) AS (
+    VALUES
+        ('24h', INTERVAL '24 hours', 6.0),
+        ('7d', INTERVAL '7 days', 48.0)
+),
+activity (article_id, created_at, weight) AS (
+    SELECT article_id, created_at, 1.0 FROM article_favorites
+    UNION ALL
+    SELECT article_id, created_at, 2.0 FROM comments
+)
+SELECT
+    windows.name AS time_window,
+    activity.article_id,
+    SUM(
+        activity.weight
+        * EXP(-EXTRACT(EPOCH FROM NOW() - activity.created_at) / 3600.0 / windows.decay_hours)
+    )::DOUBLE PRECISION AS score
+FROM windows
+INNER JOIN activity ON activity.created_at >= NOW() - windows.duration
+GROUP BY windows.name, activity.article_id;
+
+-- A unique index is required to refresh the view concurrently
+CREATE UNIQUE INDEX IF NOT EXISTS idx_trending_articles_window_article ON trending_articles(time_window, article_id);
+
+CREATE INDEX IF NOT EXISTS idx_trending_articles_window_score ON trending_articles(time_window, score DESC);
@@ -0,0 +1,14 @@
+-- Create bookmarks table for private reading lists, unlike the public favorites
+CREATE TABLE IF NOT EXISTS bookmarks (
+    user_id UUID NOT NULL,
+    article_id UUID NOT NULL,
+    folder VARCHAR(50),
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, article_id),
+    CONSTRAINT fk_bookmarks_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_bookmarks_article FOREIGN KEY (article_id) REFERENCES articles(id) ON DELETE CASCADE
+);
+
+-- Create index for listing a user's bookmarks, newest first
+CREATE INDEX idx_bookmarks_user_id_created_at ON bookmarks(user_id, created_at DESC);
@@ -0,0 +1,15 @@
+-- Count views on the article itself, so that showing them doesn't require counting rows
+ALTER TABLE articles ADD COLUMN views_count BIGINT NOT NULL DEFAULT 0;
+
+-- Create article_views table, with at most one view per viewer and article in each time window
+CREATE TABLE IF NOT EXISTS article_views (
+    article_id UUID NOT NULL,
+    viewer VARCHAR(64) NOT NULL,
+    window_start TIMESTAMPTZ NOT NULL,
+    viewed_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (article_id, viewer, window_start),
+    CONSTRAINT fk_article_views_article FOREIGN KEY (article_id) REFERENCES articles(id) ON DELETE CASCADE
+);
+
+-- Create index for the views of an article over time
+CREATE INDEX idx_article_views_article_id_viewed_at ON article_views(article_id, viewed_at);
@@ -0,0 +1,22 @@
+-- Create user_blocks table, blocked users can't follow the blocker, comment on their
+-- articles or see their profile
+CREATE TABLE IF NOT EXISTS user_blocks (
+    blocker_id UUID NOT NULL,
+    blocked_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (blocker_id, blocked_id),
+    CONSTRAINT fk_user_blocks_blocker FOREIGN KEY (blocker_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_user_blocks_blocked FOREIGN KEY (blocked_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT chk_no_self_block CHECK (blocker_id != blocked_id)
+);
+
+-- Create user_mutes table, articles and comments of muted users are hidden from the muter
+CREATE TABLE IF NOT EXISTS user_mutes (
+    muter_id UUID NOT NULL,
+    muted_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (muter_id, muted_id),
+    CONSTRAINT fk_user_mutes_muter FOREIGN KEY (muter_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_user_mutes_muted FOREIGN KEY (muted_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT chk_no_self_mute CHECK (muter_id != muted_id)
+);
@@ -0,0 +1,19 @@
+-- Private accounts approve their followers, follows become requests until then
+ALTER TABLE users ADD COLUMN private BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE IF NOT EXISTS follow_requests (
+    requester_id UUID NOT NULL,
+    target_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (requester_id, target_id),
+    CONSTRAINT fk_follow_requests_requester FOREIGN KEY (requester_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_follow_requests_target FOREIGN KEY (target_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT chk_no_self_follow_request CHECK (requester_id != target_id)
+);
+
+-- Create index for listing the pending requests of a user, newest first
+CREATE INDEX idx_follow_requests_target_id_created_at ON follow_requests(target_id, created_at DESC);
+
+ALTER TABLE notifications DROP CONSTRAINT chk_notifications_kind;
+ALTER TABLE notifications ADD CONSTRAINT chk_notifications_kind
+    CHECK (kind IN ('article_commented', 'article_favorited', 'user_followed', 'follow_requested'));
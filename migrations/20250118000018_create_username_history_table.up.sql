@@ -0,0 +1,35 @@
+-- Previous usernames of users, still resolving to their profile after a change
+ALTER TABLE users ADD COLUMN username_changed_at TIMESTAMPTZ;
+
+CREATE TABLE IF NOT EXISTS username_history (
+    user_id UUID NOT NULL,
+    username VARCHAR(255) NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT fk_username_history_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- A previous username belongs to a single user, nobody else can take it
+CREATE UNIQUE INDEX idx_username_history_username_lower ON username_history(LOWER(username));
+
+-- Create index on user_id for cleaning up the history of a user
+CREATE INDEX idx_username_history_user_id ON username_history(user_id);
+
+-- Usernames only differing in case are told apart first: the oldest account keeps its username,
+-- the others get the start of their id appended, e.g. alice_1a2b3c4d. The username they had goes
+-- to their history, to one of them when several of them only differed in case
+WITH renamed AS (
+    UPDATE users
+    SET username = LEFT(users.username, 246) || '_' || LEFT(users.id::text, 8)
+    FROM (
+        SELECT id, username, ROW_NUMBER() OVER (PARTITION BY LOWER(username) ORDER BY created_at, id) AS position
+        FROM users
+    ) AS ranked
+    WHERE ranked.id = users.id AND ranked.position > 1
+    RETURNING users.id, ranked.username AS previous_username
+)
+INSERT INTO username_history (user_id, username)
+SELECT id, previous_username FROM renamed
+ON CONFLICT DO NOTHING;
+
+-- Usernames are unique regardless of case
+CREATE UNIQUE INDEX idx_users_username_lower ON users(LOWER(username));
@@ -0,0 +1,35 @@
+-- Emails and usernames are compared NFKC normalized and ignoring case, the application
+-- normalizes them before storing them. Accounts that would collide once normalized have to be
+-- merged or renamed by hand, the migration stops and lists them. Usernames only differing in
+-- case were already renamed, those left are the ones only differing once NFKC normalized.
+DO $$
+DECLARE
+    -- NORMALIZE is only available when the server encoding is UTF8
+    utf8 BOOLEAN := current_setting('server_encoding') = 'UTF8';
+    folded TEXT;
+    collisions TEXT;
+    column_name TEXT;
+BEGIN
+    FOREACH column_name IN ARRAY ARRAY['email', 'username'] LOOP
+        folded := CASE WHEN utf8 THEN 'LOWER(NORMALIZE(%1$I, NFKC))' ELSE 'LOWER(%1$I)' END;
+
+        EXECUTE format(
+            'SELECT string_agg(value, '', '') FROM (SELECT ' || folded
+                || ' AS value FROM users GROUP BY 1 HAVING COUNT(*) > 1) AS duplicates',
+            column_name
+        ) INTO collisions;
+
+        IF collisions IS NOT NULL THEN
+            RAISE EXCEPTION 'Several users have the % %, once normalized', column_name, collisions;
+        END IF;
+    END LOOP;
+
+    IF utf8 THEN
+        EXECUTE 'UPDATE users SET email = LOWER(NORMALIZE(email, NFKC)), username = NORMALIZE(username, NFKC)';
+    ELSE
+        UPDATE users SET email = LOWER(email);
+    END IF;
+END $$;
+
+-- Usernames are already unique regardless of case
+CREATE UNIQUE INDEX idx_users_email_lower ON users(LOWER(email));
@@ -0,0 +1,12 @@
+-- Create article_co_authors table, co-authors can edit the article but only its author deletes it
+CREATE TABLE IF NOT EXISTS article_co_authors (
+    article_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (article_id, user_id),
+    CONSTRAINT fk_article_co_authors_article FOREIGN KEY (article_id) REFERENCES articles(id) ON DELETE CASCADE,
+    CONSTRAINT fk_article_co_authors_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- Create index on user_id for listing the articles a user co-authored
+CREATE INDEX idx_article_co_authors_user_id ON article_co_authors(user_id);
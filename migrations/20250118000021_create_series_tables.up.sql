@@ -0,0 +1,25 @@
+-- Create series table, grouping articles of the same author such as multi-part tutorials
+CREATE TABLE IF NOT EXISTS series (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    slug VARCHAR(255) NOT NULL UNIQUE,
+    title VARCHAR(255) NOT NULL,
+    description TEXT,
+    owner_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT fk_series_owner FOREIGN KEY (owner_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- Create index on owner_id for faster lookups of series by owner
+CREATE INDEX idx_series_owner_id ON series(owner_id);
+
+-- Create series_articles table, an article belongs to one series at most
+CREATE TABLE IF NOT EXISTS series_articles (
+    series_id UUID NOT NULL,
+    article_id UUID NOT NULL UNIQUE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (series_id, article_id),
+    CONSTRAINT uq_series_articles_position UNIQUE (series_id, position),
+    CONSTRAINT fk_series_articles_series FOREIGN KEY (series_id) REFERENCES series(id) ON DELETE CASCADE,
+    CONSTRAINT fk_series_articles_article FOREIGN KEY (article_id) REFERENCES articles(id) ON DELETE CASCADE
+);
@@ -0,0 +1,69 @@
+-- Create article_reactions table, favoriting an article is the 'like' reaction
+CREATE TABLE IF NOT EXISTS article_reactions (
+    user_id UUID NOT NULL,
+    article_id UUID NOT NULL,
+    kind VARCHAR(20) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, article_id, kind),
+    CONSTRAINT fk_article_reactions_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_article_reactions_article FOREIGN KEY (article_id) REFERENCES articles(id) ON DELETE CASCADE,
+    CONSTRAINT chk_article_reactions_kind CHECK (kind IN ('like', 'insightful', 'funny'))
+);
+
+-- Create index for counting the reactions of an article
+CREATE INDEX idx_article_reactions_article_id_kind ON article_reactions(article_id, kind);
+
+-- Create index for the recent favorites ranked by trending_articles
+CREATE INDEX idx_article_reactions_like_created_at ON article_reactions(created_at DESC) WHERE kind = 'like';
+
+INSERT INTO article_reactions (user_id, article_id, kind, created_at)
+SELECT user_id, article_id, 'like', created_at FROM article_favorites;
+
+-- Favorites keep being read from article_favorites, now a view of the likes.
+-- trending_articles depends on it, so it's recreated as is.
+DROP MATERIALIZED VIEW IF EXISTS trending_articles;
+DROP TABLE article_favorites;
+
+CREATE VIEW article_favorites AS
+SELECT user_id, article_id, created_at FROM article_reactions WHERE kind = 'like';
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS trending_articles AS
+WITH windows (name, duration, decay_hours) AS (
+    VALUES
+        ('24h', INTERVAL '24 hours', 6.0),
+        ('7d', INTERVAL '7 days', 48.0)
+),
+activity (article_id, created_at, weight) AS (
+    SELECT article_id, created_at, 1.0 FROM article_favorites
+    UNION ALL
+    SELECT article_id, created_at, 2.0 FROM comments
+)
+SELECT
+    windows.name AS time_window,
+    activity.article_id,
+    SUM(
+        activity.weight
+        * EXP(-EXTRACT(EPOCH FROM NOW() - activity.created_at) / 3600.0 / windows.decay_hours)
+    )::DOUBLE PRECISION AS score
+FROM windows
+INNER JOIN activity ON activity.created_at >= NOW() - windows.duration
+GROUP BY windows.name, activity.article_id;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_trending_articles_window_article ON trending_articles(time_window, article_id);
+
+CREATE INDEX IF NOT EXISTS idx_trending_articles_window_score ON trending_articles(time_window, score DESC);
+
+-- Create comment_reactions table
+CREATE TABLE IF NOT EXISTS comment_reactions (
+    user_id UUID NOT NULL,
+    comment_id UUID NOT NULL,
+    kind VARCHAR(20) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, comment_id, kind),
+    CONSTRAINT fk_comment_reactions_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_comment_reactions_comment FOREIGN KEY (comment_id) REFERENCES comments(id) ON DELETE CASCADE,
+    CONSTRAINT chk_comment_reactions_kind CHECK (kind IN ('like', 'insightful', 'funny'))
+);
+
+-- Create index for counting the reactions of comments
+CREATE INDEX idx_comment_reactions_comment_id_kind ON comment_reactions(comment_id, kind);
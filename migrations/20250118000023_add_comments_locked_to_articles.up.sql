@@ -0,0 +1,2 @@
+-- Locked articles keep their comments but don't accept new ones
+ALTER TABLE articles ADD COLUMN comments_locked BOOLEAN NOT NULL DEFAULT FALSE;
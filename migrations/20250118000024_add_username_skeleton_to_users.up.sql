@@ -0,0 +1,5 @@
+-- UTS #39 skeleton of the username, so that names looking alike can be found. It's computed by
+-- the application, which fills it in for existing users on startup
+ALTER TABLE users ADD COLUMN username_skeleton TEXT;
+
+CREATE INDEX idx_users_username_skeleton ON users (username_skeleton);
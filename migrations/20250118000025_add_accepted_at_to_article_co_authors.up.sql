@@ -0,0 +1,5 @@
+-- Co-authors are invited by the author and only edit the article once they accept, those added
+-- before invitations existed keep their access
+ALTER TABLE article_co_authors ADD COLUMN accepted_at TIMESTAMPTZ;
+
+UPDATE article_co_authors SET accepted_at = created_at;
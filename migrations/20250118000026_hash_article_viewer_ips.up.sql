@@ -0,0 +1,6 @@
+-- Anonymous views are keyed by a salted digest of the IP address instead of the address itself
+ALTER TABLE article_views ALTER COLUMN viewer TYPE VARCHAR(80);
+
+-- The salt is only known to the application, so existing addresses are replaced by random keys.
+-- Only views in the current window could be counted twice because of it
+UPDATE article_views SET viewer = 'ip:' || md5(gen_random_uuid()::text) WHERE viewer LIKE 'ip:%';
@@ -1,4 +1,7 @@
+use crate::model::values::user_id::UserId;
+use crate::utils::unicode_text::skeleton;
 use tryphon::{Config, ConfigValueDecoder, ErrorPrintMode, Secret};
+use uuid::Uuid;
 
 #[derive(Debug, Config, Clone)]
 pub struct HttpConfig {
@@ -8,6 +11,10 @@ pub struct HttpConfig {
     #[env("HTTP_PORT")]
     #[default(8080)]
     pub(crate) port: u16,
+    /// Whether to take the client IP from the X-Forwarded-For header set by a reverse proxy.
+    #[env("HTTP_TRUST_FORWARDED_FOR")]
+    #[default(false)]
+    pub(crate) trust_forwarded_for: bool,
 }
 
 impl HttpConfig {
@@ -52,6 +59,10 @@ pub struct SecretsConfig {
     #[env("JWT_SECRET")]
     #[default(Secret("default_jwt_secret_change_in_production".to_string()))]
     pub jwt: Secret<String>,
+    /// Key of the digests anonymous article views are stored under instead of the IP address.
+    #[env("VIEWER_SALT")]
+    #[default(Secret("default_viewer_salt".to_string()))]
+    pub viewer_salt: Secret<String>,
 }
 
 #[derive(Debug, Clone, ConfigValueDecoder)]
@@ -69,6 +80,217 @@ pub struct TracingConfig {
     pub formatting: LogFormatting,
 }
 
+#[derive(Debug, Clone, ConfigValueDecoder)]
+pub enum EventsBackend {
+    Memory,
+    Postgres,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct EventsConfig {
+    #[env("EVENTS_BACKEND")]
+    #[default(EventsBackend::Memory)]
+    pub backend: EventsBackend,
+    #[env("EVENTS_CHANNEL_CAPACITY")]
+    #[default(1024)]
+    pub channel_capacity: usize,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct WebhooksConfig {
+    #[env("WEBHOOKS_POLL_INTERVAL_MS")]
+    #[default(1000)]
+    pub poll_interval_ms: u64,
+    #[env("WEBHOOKS_BATCH_SIZE")]
+    #[default(50)]
+    pub batch_size: u64,
+    #[env("WEBHOOKS_REQUEST_TIMEOUT_MS")]
+    #[default(10000)]
+    pub request_timeout_ms: u64,
+    #[env("WEBHOOKS_MAX_ATTEMPTS")]
+    #[default(8)]
+    pub max_attempts: u32,
+    #[env("WEBHOOKS_RETRY_BASE_DELAY_MS")]
+    #[default(30000)]
+    pub retry_base_delay_ms: u64,
+    #[env("WEBHOOKS_RETRY_MAX_DELAY_MS")]
+    #[default(3600000)]
+    pub retry_max_delay_ms: u64,
+    /// Whether webhooks may target localhost and private networks, e.g. for local development.
+    #[env("WEBHOOKS_ALLOW_PRIVATE_HOSTS")]
+    #[default(false)]
+    pub allow_private_hosts: bool,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct OutboxConfig {
+    #[env("OUTBOX_POLL_INTERVAL_MS")]
+    #[default(1000)]
+    pub poll_interval_ms: u64,
+    #[env("OUTBOX_BATCH_SIZE")]
+    #[default(100)]
+    pub batch_size: u64,
+    #[env("OUTBOX_MAX_ATTEMPTS")]
+    #[default(5)]
+    pub max_attempts: u32,
+    /// How long processed events are kept, e.g. to look into the ones that were given up on.
+    #[env("OUTBOX_RETENTION_SECS")]
+    #[default(86400)]
+    pub retention_secs: u64,
+    #[env("OUTBOX_CLEANUP_INTERVAL_MS")]
+    #[default(3600000)]
+    pub cleanup_interval_ms: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct AdminConfig {
+    /// Comma separated ids of the users allowed to manage tags and moderate articles.
+    ///
+    /// Ids rather than usernames, which others can register once an admin changes theirs.
+    #[env("ADMIN_USER_IDS")]
+    #[default("")]
+    pub user_ids: String,
+}
+
+impl AdminConfig {
+    pub(crate) fn is_admin(&self, user_id: UserId) -> bool {
+        self.user_ids
+            .split(',')
+            .filter_map(|admin| Uuid::parse_str(admin.trim()).ok())
+            .any(|admin| admin == user_id.value())
+    }
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct UsernamesConfig {
+    /// Comma separated usernames nobody can register or change to, ignoring case.
+    #[env("USERNAMES_RESERVED")]
+    #[default("")]
+    pub reserved: String,
+    /// Minimum number of days between two username changes of a user.
+    #[env("USERNAMES_CHANGE_COOLDOWN_DAYS")]
+    #[default(30)]
+    pub change_cooldown_days: u32,
+}
+
+impl UsernamesConfig {
+    /// Whether the username is reserved, or looks like a reserved one.
+    pub(crate) fn is_reserved(&self, username: &str) -> bool {
+        let username = skeleton(username);
+
+        self.reserved
+            .split(',')
+            .map(str::trim)
+            .any(|reserved| !reserved.is_empty() && skeleton(reserved) == username)
+    }
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct TagsConfig {
+    #[env("TAGS_CLEANUP_INTERVAL_MS")]
+    #[default(3600000)]
+    pub cleanup_interval_ms: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct TrendingConfig {
+    #[env("TRENDING_REFRESH_INTERVAL_MS")]
+    #[default(300000)]
+    pub refresh_interval_ms: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct ViewsConfig {
+    #[env("VIEWS_FLUSH_INTERVAL_MS")]
+    #[default(10000)]
+    pub flush_interval_ms: u64,
+    /// Views of an article by the same user or IP within a window of this length count once.
+    #[env("VIEWS_DEDUP_WINDOW_SECS")]
+    #[default(1800)]
+    pub dedup_window_secs: u64,
+    /// Views kept in memory until the next flush at most, further ones are dropped while the
+    /// database is slow or unavailable.
+    #[env("VIEWS_MAX_PENDING")]
+    #[default(100000)]
+    pub max_pending: usize,
+    /// How often views older than both the dedup window and the longest stats period are deleted.
+    #[env("VIEWS_CLEANUP_INTERVAL_MS")]
+    #[default(3600000)]
+    pub cleanup_interval_ms: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct MarkdownConfig {
+    /// Number of articles whose rendered body is kept in memory.
+    #[env("MARKDOWN_CACHE_CAPACITY")]
+    #[default(1000)]
+    pub cache_capacity: usize,
+}
+
+#[derive(Debug, Clone, ConfigValueDecoder)]
+pub enum StorageBackend {
+    Local,
+    S3,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct UploadsConfig {
+    #[env("UPLOADS_STORAGE_BACKEND")]
+    #[default(StorageBackend::Local)]
+    pub storage_backend: StorageBackend,
+    /// Base URL uploads are served from, as seen by clients.
+    #[env("UPLOADS_PUBLIC_URL")]
+    #[default("http://localhost:8080")]
+    pub public_url: String,
+    #[env("UPLOADS_MAX_BYTES")]
+    #[default(5242880)]
+    pub max_bytes: usize,
+    /// Largest accepted width or height, in pixels.
+    #[env("UPLOADS_MAX_DIMENSION")]
+    #[default(8000)]
+    pub max_dimension: u32,
+    /// Thumbnails fit in a square with sides of this many pixels.
+    #[env("UPLOADS_THUMBNAIL_SIZE")]
+    #[default(256)]
+    pub thumbnail_size: u32,
+    /// Directory of the local storage backend.
+    #[env("UPLOADS_LOCAL_DIR")]
+    #[default("uploads")]
+    pub local_dir: String,
+}
+
+impl UploadsConfig {
+    pub(crate) fn url(&self, key: &str) -> String {
+        format!(
+            "{}/api/uploads/{}",
+            self.public_url.trim_end_matches('/'),
+            key
+        )
+    }
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct S3Config {
+    #[env("S3_ENDPOINT")]
+    #[default("http://localhost:9000")]
+    pub endpoint: String,
+    #[env("S3_BUCKET")]
+    #[default("realworld")]
+    pub bucket: String,
+    #[env("S3_REGION")]
+    #[default("us-east-1")]
+    pub region: String,
+    #[env("S3_ACCESS_KEY_ID")]
+    #[default("")]
+    pub access_key_id: String,
+    #[env("S3_SECRET_ACCESS_KEY")]
+    #[default(Secret("".to_string()))]
+    pub secret_access_key: Secret<String>,
+    #[env("S3_REQUEST_TIMEOUT_MS")]
+    #[default(10000)]
+    pub request_timeout_ms: u64,
+}
+
 #[derive(Debug, Config, Clone)]
 pub struct AppConfig {
     #[config]
@@ -79,6 +301,28 @@ pub struct AppConfig {
     pub secrets: SecretsConfig,
     #[config]
     pub tracing: TracingConfig,
+    #[config]
+    pub events: EventsConfig,
+    #[config]
+    pub webhooks: WebhooksConfig,
+    #[config]
+    pub outbox: OutboxConfig,
+    #[config]
+    pub admin: AdminConfig,
+    #[config]
+    pub usernames: UsernamesConfig,
+    #[config]
+    pub tags: TagsConfig,
+    #[config]
+    pub trending: TrendingConfig,
+    #[config]
+    pub views: ViewsConfig,
+    #[config]
+    pub markdown: MarkdownConfig,
+    #[config]
+    pub uploads: UploadsConfig,
+    #[config]
+    pub s3: S3Config,
 }
 
 pub fn load_config() -> AppConfig {
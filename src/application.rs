@@ -1,21 +1,43 @@
 use crate::app_config::load_config;
 use crate::database::connect_db;
+use crate::events::event_bus::create_event_bus;
 use crate::persistence::article_repository::ArticleRepository;
 use crate::persistence::comment_repository::CommentRepository;
+use crate::persistence::notification_repository::NotificationRepository;
+use crate::persistence::outbox_repository::OutboxRepository;
 use crate::persistence::profile_repository::ProfileRepository;
+use crate::persistence::series_repository::SeriesRepository;
+use crate::persistence::stats_repository::StatsRepository;
 use crate::persistence::tag_repository::TagRepository;
 use crate::persistence::user_repository::UserRepository;
+use crate::persistence::webhook_repository::WebhookRepository;
 use crate::server::init_server;
+use crate::storage::create_storage;
 use crate::tracing::init_tracing;
 use crate::utils::hasher::Hasher;
 use crate::utils::jwt::JwtHandler;
 use crate::{domain, http};
 use domain::article_service::ArticleService;
+use domain::body_renderer::BodyRenderer;
 use domain::comment_service::CommentService;
+use domain::notification_service::NotificationService;
+use domain::outbox::Outbox;
+use domain::outbox_cleanup_job::OutboxCleanupJob;
+use domain::outbox_dispatcher::OutboxDispatcher;
 use domain::profile_service::ProfileService;
+use domain::series_service::SeriesService;
+use domain::stats_service::StatsService;
+use domain::tag_cleanup_job::TagCleanupJob;
 use domain::tag_service::TagService;
+use domain::trending_refresh_job::TrendingRefreshJob;
+use domain::upload_service::UploadService;
 use domain::user_service::UserService;
+use domain::view_cleanup_job::ViewCleanupJob;
+use domain::view_recorder::ViewRecorder;
+use domain::webhook_dispatcher::WebhookDispatcher;
+use domain::webhook_service::WebhookService;
 use http::AppState;
+use std::sync::Arc;
 use tracing::info;
 
 pub async fn start_app() {
@@ -35,6 +57,10 @@ pub async fn create_app_state(config: &crate::app_config::AppConfig) -> AppState
         .await
         .expect("Failed to connect to database");
 
+    let event_bus = create_event_bus(&config.events, db.clone())
+        .await
+        .expect("Failed to initialize event bus");
+
     let jwt = JwtHandler::new(config.secrets.jwt.0.clone());
     let hasher = Hasher::new(config.secrets.pepper.0.clone());
 
@@ -43,19 +69,94 @@ pub async fn create_app_state(config: &crate::app_config::AppConfig) -> AppState
     let tag_repo = TagRepository::new(db.clone());
     let comment_repo = CommentRepository::new(db.clone());
     let profile_repo = ProfileRepository::new(db.clone());
+    let notification_repo = NotificationRepository::new(db.clone());
+    let series_repo = SeriesRepository::new(db.clone());
+    let webhook_repo = WebhookRepository::new(db.clone());
+    let stats_repo = StatsRepository::new(db.clone());
+    let outbox_repo = OutboxRepository::new();
+
+    let outbox = Outbox::new(db.clone(), outbox_repo.clone());
+
+    let filled = user_repo
+        .fill_username_skeletons()
+        .await
+        .expect("Failed to fill in username skeletons");
+    if filled > 0 {
+        info!("Filled in the username skeletons of {} users", filled);
+    }
+
+    WebhookDispatcher::new(webhook_repo.clone(), config.webhooks.clone()).spawn();
+    TagCleanupJob::new(tag_repo.clone(), config.tags.clone()).spawn();
+    OutboxCleanupJob::new(db.clone(), outbox_repo.clone(), config.outbox.clone()).spawn();
+    TrendingRefreshJob::new(article_repo.clone(), config.trending.clone()).spawn();
+
+    let view_recorder = ViewRecorder::new(
+        article_repo.clone(),
+        config.views.clone(),
+        config.secrets.viewer_salt.0.clone(),
+    );
+    view_recorder.clone().spawn();
+    ViewCleanupJob::new(article_repo.clone(), config.views.clone()).spawn();
+
+    let notification_service = NotificationService::new(notification_repo);
+    let webhook_service = WebhookService::new(
+        webhook_repo,
+        article_repo.clone(),
+        comment_repo.clone(),
+        user_repo.clone(),
+        config.webhooks.clone(),
+    );
+    let user_service =
+        UserService::new(user_repo, hasher, outbox.clone(), config.usernames.clone());
+    let article_service = ArticleService::new(
+        article_repo.clone(),
+        tag_repo.clone(),
+        notification_service.clone(),
+        outbox.clone(),
+        view_recorder,
+        BodyRenderer::new(config.markdown.clone()),
+    );
+    let series_service = SeriesService::new(series_repo, article_repo.clone(), outbox.clone());
+    let comment_service = CommentService::new(
+        comment_repo,
+        article_repo,
+        profile_repo.clone(),
+        notification_service.clone(),
+        outbox.clone(),
+        config.admin.clone(),
+    );
+    let tag_service = TagService::new(tag_repo.clone(), outbox.clone(), config.admin.clone());
+    let profile_service =
+        ProfileService::new(profile_repo, notification_service.clone(), outbox.clone());
+    let stats_service = StatsService::new(stats_repo);
+    let upload_service = UploadService::new(
+        create_storage(&config.uploads, &config.s3),
+        config.uploads.clone(),
+    );
 
-    let user_service = UserService::new(user_repo, hasher);
-    let article_service = ArticleService::new(article_repo, tag_repo.clone());
-    let comment_service = CommentService::new(comment_repo);
-    let tag_service = TagService::new(tag_repo);
-    let profile_service = ProfileService::new(profile_repo);
+    OutboxDispatcher::new(
+        outbox,
+        outbox_repo,
+        vec![
+            Arc::new(event_bus.clone()),
+            Arc::new(webhook_service.clone()),
+        ],
+        config.outbox.clone(),
+    )
+    .spawn();
 
     AppState {
         user_service,
         article_service,
+        series_service,
         comment_service,
         tag_service,
         profile_service,
+        stats_service,
+        upload_service,
+        notification_service,
+        event_bus,
+        webhook_service,
         config: config.clone(),
         jwt,
     }
@@ -1,9 +1,71 @@
 use crate::app_config::DatabaseConfig;
 use sqlx::migrate::MigrateError;
+use sqlx::pool::PoolConnection;
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
-use sqlx::{Error, Pool, Postgres};
+use sqlx::{Error, PgConnection, Pool, Postgres, Transaction};
+use std::ops::{Deref, DerefMut};
 use tracing::info;
 
+/// What repositories run their queries on: the pool for standalone statements, or the
+/// connection of a transaction when several statements have to succeed or fail together.
+pub enum DbExecutor<'a> {
+    Pool(&'a Pool<Postgres>),
+    Connection(&'a mut PgConnection),
+}
+
+impl<'a> DbExecutor<'a> {
+    /// Borrows a connection from the pool, or hands out the one of the transaction.
+    pub async fn acquire(self) -> Result<DbConnection<'a>, Error> {
+        match self {
+            DbExecutor::Pool(pool) => Ok(DbConnection::Pooled(pool.acquire().await?)),
+            DbExecutor::Connection(conn) => Ok(DbConnection::Borrowed(conn)),
+        }
+    }
+}
+
+impl<'a> From<&'a Database> for DbExecutor<'a> {
+    fn from(database: &'a Database) -> Self {
+        DbExecutor::Pool(database.pool())
+    }
+}
+
+impl<'a> From<&'a mut PgConnection> for DbExecutor<'a> {
+    fn from(conn: &'a mut PgConnection) -> Self {
+        DbExecutor::Connection(conn)
+    }
+}
+
+impl<'a> From<&'a mut Transaction<'static, Postgres>> for DbExecutor<'a> {
+    fn from(tx: &'a mut Transaction<'static, Postgres>) -> Self {
+        DbExecutor::Connection(tx)
+    }
+}
+
+pub enum DbConnection<'a> {
+    Pooled(PoolConnection<Postgres>),
+    Borrowed(&'a mut PgConnection),
+}
+
+impl Deref for DbConnection<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            DbConnection::Pooled(conn) => conn,
+            DbConnection::Borrowed(conn) => conn,
+        }
+    }
+}
+
+impl DerefMut for DbConnection<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            DbConnection::Pooled(conn) => conn,
+            DbConnection::Borrowed(conn) => conn,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Database(Pool<Postgres>);
 
@@ -11,6 +73,10 @@ impl Database {
     pub fn pool(&self) -> &Pool<Postgres> {
         &self.0
     }
+
+    pub async fn begin(&self) -> Result<Transaction<'static, Postgres>, Error> {
+        self.0.begin().await
+    }
 }
 
 pub async fn connect_db(config: &DatabaseConfig) -> Result<Database, Error> {
@@ -1,36 +1,88 @@
 use crate::app_error::AppError;
+use crate::domain::body_renderer::BodyRenderer;
+use crate::domain::commands::bookmark_article_command::BookmarkArticleCommand;
 use crate::domain::commands::create_article_command::CreateArticleCommand;
 use crate::domain::commands::get_feed_query::GetFeedQuery;
+use crate::domain::commands::get_related_query::GetRelatedQuery;
+use crate::domain::commands::get_trending_query::GetTrendingQuery;
 use crate::domain::commands::list_articles_query::ListArticlesQuery;
+use crate::domain::commands::list_bookmarks_query::ListBookmarksQuery;
 use crate::domain::commands::update_article_command::UpdateArticleCommand;
+use crate::domain::notification_service::NotificationService;
+use crate::domain::outbox::{Outbox, UnitOfWork};
+use crate::domain::view_recorder::ViewRecorder;
+use crate::events::app_event::AppEvent;
+use crate::model::feed_source::FeedSource;
 use crate::model::indexed_article_field::IndexedArticleField;
 use crate::model::persistence::article_view::{ArticleListView, ArticleView};
+use crate::model::persistence::bookmark_view::BookmarkView;
+use crate::model::trending_window::TrendingWindow;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::bookmark_folder::BookmarkFolder;
+use crate::model::values::notification_kind::NotificationKind;
+use crate::model::values::reaction_kind::ReactionKind;
 use crate::model::values::slug::Slug;
 use crate::model::values::tag_name::TagName;
 use crate::model::values::user_id::UserId;
+use crate::model::viewer::Viewer;
 use crate::persistence::article_repository::ArticleRepository;
+use crate::persistence::params::insert_notification_params::InsertNotificationParams;
 use crate::persistence::params::list_articles_params::ListArticlesParams;
 use crate::persistence::tag_repository::TagRepository;
+use crate::utils::markdown::RenderedMarkdown;
 use anyhow::Result;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct ArticleService {
     article_repo: ArticleRepository,
     tag_repo: TagRepository,
+    notification_service: NotificationService,
+    outbox: Outbox,
+    view_recorder: ViewRecorder,
+    body_renderer: BodyRenderer,
 }
 
 impl ArticleService {
-    pub fn new(article_repo: ArticleRepository, tag_repo: TagRepository) -> Self {
+    pub fn new(
+        article_repo: ArticleRepository,
+        tag_repo: TagRepository,
+        notification_service: NotificationService,
+        outbox: Outbox,
+        view_recorder: ViewRecorder,
+        body_renderer: BodyRenderer,
+    ) -> Self {
         ArticleService {
             article_repo,
             tag_repo,
+            notification_service,
+            outbox,
+            view_recorder,
+            body_renderer,
         }
     }
 
-    async fn verify_slug(&self, slug: &Slug) -> Result<(), AppError> {
+    async fn record_favorites_changed(
+        &self,
+        uow: &mut UnitOfWork,
+        article_id: ArticleId,
+    ) -> Result<(), AppError> {
+        let favorites_count = self
+            .article_repo
+            .count_favorites(uow.conn(), article_id)
+            .await?;
+
+        uow.record(AppEvent::FavoritesChanged {
+            article_id,
+            favorites_count,
+        })
+        .await
+    }
+
+    async fn verify_slug(&self, uow: &mut UnitOfWork, slug: &Slug) -> Result<(), AppError> {
         if self
             .article_repo
-            .get_article_by(IndexedArticleField::Slug, slug)
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, slug)
             .await?
             .is_some()
         {
@@ -49,21 +101,28 @@ impl ArticleService {
     ) -> Result<ArticleView, AppError> {
         let slug = Slug::from_title(command.title.value());
 
-        self.verify_slug(&slug).await?;
+        let mut uow = self.outbox.begin().await?;
+
+        self.verify_slug(&mut uow, &slug).await?;
 
         let params = command.to_insert_params(slug);
-        let article = self.article_repo.insert_article(params).await?;
+        let article = self.article_repo.insert_article(uow.conn(), params).await?;
 
-        let tag_ids = self.get_or_create_tags(&command.tag_list).await?;
+        let tag_ids = self.get_or_create_tags(&mut uow, &command.tag_list).await?;
         self.article_repo
-            .add_tags_to_article(article.id, &tag_ids)
+            .add_tags_to_article(uow.conn(), article.id, &tag_ids)
             .await?;
 
-        let article_view = self
-            .article_repo
+        uow.record(AppEvent::ArticleCreated {
+            article_id: article.id,
+        })
+        .await?;
+
+        uow.commit().await?;
+
+        self.article_repo
             .get_article_by_id(article.id, Some(command.author_id))
-            .await?;
-        Ok(article_view)
+            .await
     }
 
     pub async fn get_article(
@@ -76,45 +135,200 @@ impl ArticleService {
             .await
     }
 
+    /// Resolves the slug whoever asks, for services checking access themselves.
+    pub async fn get_article_id(&self, slug: &Slug) -> Result<Option<ArticleId>, AppError> {
+        self.article_repo.get_article_id(slug).await
+    }
+
+    /// Gets the article for reading it, counting the view unless the viewer is its author.
+    pub async fn view_article(
+        &self,
+        slug: &Slug,
+        user_id: Option<UserId>,
+        viewer: Option<Viewer>,
+    ) -> Result<Option<ArticleView>, AppError> {
+        let article = self.get_article(slug, user_id).await?;
+
+        if let (Some(article), Some(viewer)) = (&article, viewer)
+            && viewer != Viewer::User(article.author_id)
+        {
+            self.view_recorder.record(article.id, viewer);
+        }
+
+        Ok(article)
+    }
+
+    pub fn render_body(&self, article: &ArticleView) -> Arc<RenderedMarkdown> {
+        self.body_renderer.render(article)
+    }
+
     pub async fn update_article(
         &self,
         command: UpdateArticleCommand,
         user_id: UserId,
     ) -> Result<ArticleView, AppError> {
+        if command.tag_list.is_some()
+            && (!command.add_tags.is_empty() || !command.remove_tags.is_empty())
+        {
+            return Err(AppError::BadData(
+                "tagList cannot be combined with addTags or removeTags".to_string(),
+            ));
+        }
+
+        let mut uow = self.outbox.begin().await?;
+
         let article = self
             .article_repo
-            .get_article_by(IndexedArticleField::Slug, &command.old_slug)
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, &command.old_slug)
             .await?
             .ok_or(AppError::NotFound)?;
 
         let params = command.to_params(article.id);
 
-        if article.author_id != user_id {
+        if article.author_id != user_id
+            && !self
+                .article_repo
+                .is_co_author(uow.conn(), article.id, user_id)
+                .await?
+        {
             Err(AppError::Forbidden)
+        } else if params.as_list().is_empty() && !command.changes_tags() {
+            Err(AppError::BadData("No fields to update".to_string()))
         } else {
             if let Some(ref slug) = params.slug {
-                self.verify_slug(slug).await?;
+                self.verify_slug(&mut uow, slug).await?;
             }
 
-            let article = self.article_repo.update_article(params).await?;
-            Ok(self
-                .article_repo
+            let article = self.article_repo.update_article(uow.conn(), params).await?;
+
+            self.update_tags(&mut uow, article.id, &command).await?;
+
+            uow.record(AppEvent::ArticleUpdated {
+                article_id: article.id,
+            })
+            .await?;
+
+            uow.commit().await?;
+
+            self.article_repo
                 .get_article_by_id(article.id, Some(user_id))
-                .await?)
+                .await
+        }
+    }
+
+    /// Only the author invites co-authors, who can then update the article but not delete it,
+    /// once they accept.
+    pub async fn invite_co_author(
+        &self,
+        user_id: UserId,
+        slug: &Slug,
+        co_author_id: UserId,
+    ) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let article = self
+            .article_repo
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if article.author_id != user_id {
+            return Err(AppError::Forbidden);
+        }
+
+        if co_author_id == user_id {
+            return Err(AppError::BadData(
+                "The author cannot be a co-author".to_string(),
+            ));
+        }
+
+        self.article_repo
+            .invite_co_author(uow.conn(), article.id, co_author_id)
+            .await?;
+
+        uow.commit().await
+    }
+
+    pub async fn accept_co_author_invitation(
+        &self,
+        user_id: UserId,
+        slug: &Slug,
+    ) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let article = self
+            .article_repo
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if !self
+            .article_repo
+            .accept_co_author_invitation(uow.conn(), article.id, user_id)
+            .await?
+        {
+            return Err(AppError::NotFound);
+        }
+
+        uow.commit().await
+    }
+
+    /// The author removes co-authors, co-authors can also remove themselves. Invitations are
+    /// withdrawn or declined the same way.
+    pub async fn remove_co_author(
+        &self,
+        user_id: UserId,
+        slug: &Slug,
+        co_author_id: UserId,
+    ) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let article = self
+            .article_repo
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if article.author_id != user_id && co_author_id != user_id {
+            return Err(AppError::Forbidden);
         }
+
+        if !self
+            .article_repo
+            .remove_co_author(uow.conn(), article.id, co_author_id)
+            .await?
+        {
+            return Err(AppError::NotFound);
+        }
+
+        uow.commit().await
     }
 
     pub async fn delete_article(&self, slug: Slug, user_id: UserId) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
         let article = self
             .article_repo
-            .get_article_by(IndexedArticleField::Slug, &slug)
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, &slug)
             .await?;
 
         if let Some(article) = article {
             if article.author_id != user_id {
                 Err(AppError::Forbidden)
             } else {
-                self.article_repo.delete_article(article.id).await
+                self.article_repo
+                    .delete_article(uow.conn(), article.id)
+                    .await?;
+
+                uow.record(AppEvent::ArticleDeleted {
+                    article_id: article.id,
+                    author_id: article.author_id,
+                    slug: article.slug,
+                    title: article.title,
+                })
+                .await?;
+
+                uow.commit().await
             }
         } else {
             Err(AppError::NotFound)
@@ -141,48 +355,247 @@ impl ArticleService {
             .await
     }
 
-    pub(crate) async fn count_feed_articles(&self, user_id: UserId) -> Result<u64, AppError> {
-        self.article_repo.count_feed_articles(user_id).await
+    pub(crate) async fn count_feed_articles(
+        &self,
+        user_id: UserId,
+        source: FeedSource,
+    ) -> Result<u64, AppError> {
+        self.article_repo.count_feed_articles(user_id, source).await
     }
 
     pub async fn get_feed(&self, query: GetFeedQuery) -> Result<Vec<ArticleListView>, AppError> {
         self.article_repo
-            .get_feed_articles(query.user_id, query.limit, query.offset)
+            .get_feed_articles(query.user_id, query.source, query.limit, query.offset)
             .await
     }
 
-    pub async fn favorite_article(&self, user_id: UserId, slug: &Slug) -> Result<(), AppError> {
+    pub async fn get_trending(
+        &self,
+        query: GetTrendingQuery,
+    ) -> Result<Vec<ArticleListView>, AppError> {
+        self.article_repo
+            .get_trending_articles(query.user_id, query.window, query.limit, query.offset)
+            .await
+    }
+
+    pub(crate) async fn count_trending_articles(
+        &self,
+        user_id: Option<UserId>,
+        window: TrendingWindow,
+    ) -> Result<u64, AppError> {
+        self.article_repo
+            .count_trending_articles(user_id, window)
+            .await
+    }
+
+    pub async fn get_related(
+        &self,
+        query: GetRelatedQuery,
+    ) -> Result<Vec<ArticleListView>, AppError> {
         let article = self
             .article_repo
-            .get_article_by(IndexedArticleField::Slug, slug)
+            .get_article_view_by(IndexedArticleField::Slug, &query.slug, query.user_id)
             .await?
             .ok_or(AppError::NotFound)?;
 
         self.article_repo
-            .favorite_article(user_id, article.id)
+            .get_related_articles(article.id, query.user_id, query.limit)
             .await
     }
 
+    /// Favoriting is the `like` reaction, kept for clients of the original API.
+    pub async fn favorite_article(&self, user_id: UserId, slug: &Slug) -> Result<(), AppError> {
+        self.add_reaction(user_id, slug, ReactionKind::Like).await
+    }
+
     pub async fn unfavorite_article(&self, user_id: UserId, slug: &Slug) -> Result<(), AppError> {
+        self.remove_reaction(user_id, slug, ReactionKind::Like)
+            .await
+    }
+
+    /// Only likes notify the author and change the favorites count.
+    pub async fn add_reaction(
+        &self,
+        user_id: UserId,
+        slug: &Slug,
+        kind: ReactionKind,
+    ) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
         let article = self
             .article_repo
-            .get_article_by(IndexedArticleField::Slug, slug)
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, slug)
             .await?
             .ok_or(AppError::NotFound)?;
 
+        if !self
+            .article_repo
+            .is_readable(uow.conn(), article.id, user_id)
+            .await?
+        {
+            return Err(AppError::NotFound);
+        }
+
+        if self
+            .article_repo
+            .add_reaction(uow.conn(), user_id, article.id, kind)
+            .await?
+            && kind == ReactionKind::Like
+        {
+            self.notification_service
+                .notify(
+                    &mut uow,
+                    InsertNotificationParams {
+                        recipient_id: article.author_id,
+                        actor_id: user_id,
+                        kind: NotificationKind::ArticleFavorited,
+                        article_id: Some(article.id),
+                        comment_id: None,
+                    },
+                )
+                .await?;
+
+            self.record_favorites_changed(&mut uow, article.id).await?;
+        }
+
+        uow.commit().await
+    }
+
+    pub async fn remove_reaction(
+        &self,
+        user_id: UserId,
+        slug: &Slug,
+        kind: ReactionKind,
+    ) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let article = self
+            .article_repo
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if self
+            .article_repo
+            .remove_reaction(uow.conn(), user_id, article.id, kind)
+            .await?
+            && kind == ReactionKind::Like
+        {
+            self.record_favorites_changed(&mut uow, article.id).await?;
+        }
+
+        uow.commit().await
+    }
+
+    pub async fn bookmark_article(&self, command: BookmarkArticleCommand) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let article = self
+            .article_repo
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, &command.slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if !self
+            .article_repo
+            .is_readable(uow.conn(), article.id, command.user_id)
+            .await?
+        {
+            return Err(AppError::NotFound);
+        }
+
         self.article_repo
-            .unfavorite_article(user_id, article.id)
+            .bookmark_article(uow.conn(), command.to_params(article.id))
+            .await?;
+
+        uow.commit().await
+    }
+
+    pub async fn unbookmark_article(&self, user_id: UserId, slug: &Slug) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let article = self
+            .article_repo
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        self.article_repo
+            .unbookmark_article(uow.conn(), user_id, article.id)
+            .await?;
+
+        uow.commit().await
+    }
+
+    pub async fn list_bookmarks(
+        &self,
+        query: &ListBookmarksQuery,
+    ) -> Result<Vec<BookmarkView>, AppError> {
+        self.article_repo
+            .list_bookmarks(
+                query.user_id,
+                query.folder.as_ref(),
+                query.limit,
+                query.offset,
+            )
             .await
     }
 
-    async fn get_or_create_tags(&self, tag_names: &[TagName]) -> Result<Vec<uuid::Uuid>, AppError> {
+    pub async fn count_bookmarks(
+        &self,
+        user_id: UserId,
+        folder: Option<&BookmarkFolder>,
+    ) -> Result<u64, AppError> {
+        self.article_repo.count_bookmarks(user_id, folder).await
+    }
+
+    async fn update_tags(
+        &self,
+        uow: &mut UnitOfWork,
+        article_id: ArticleId,
+        command: &UpdateArticleCommand,
+    ) -> Result<(), AppError> {
+        if let Some(tag_list) = &command.tag_list {
+            let tag_ids = self.get_or_create_tags(uow, tag_list).await?;
+            self.article_repo
+                .retain_article_tags(uow.conn(), article_id, &tag_ids)
+                .await?;
+            self.article_repo
+                .add_tags_to_article(uow.conn(), article_id, &tag_ids)
+                .await?;
+        }
+
+        if !command.add_tags.is_empty() {
+            let tag_ids = self.get_or_create_tags(uow, &command.add_tags).await?;
+            self.article_repo
+                .add_tags_to_article(uow.conn(), article_id, &tag_ids)
+                .await?;
+        }
+
+        if !command.remove_tags.is_empty() {
+            self.article_repo
+                .remove_tags_from_article(uow.conn(), article_id, &command.remove_tags)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_or_create_tags(
+        &self,
+        uow: &mut UnitOfWork,
+        tag_names: &[TagName],
+    ) -> Result<Vec<uuid::Uuid>, AppError> {
         let mut tag_ids = Vec::new();
 
         for tag_name in tag_names {
             let tag_name = TagName::try_from(tag_name.as_str())
                 .map_err(|e| AppError::BadData(format!("Invalid tag name: {}", e)))?;
 
-            let tag = self.tag_repo.get_or_create_tag(&tag_name).await?;
+            let tag = self
+                .tag_repo
+                .get_or_create_tag(uow.conn(), &tag_name)
+                .await?;
             tag_ids.push(tag.id.value());
         }
 
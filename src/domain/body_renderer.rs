@@ -0,0 +1,68 @@
+use crate::app_config::MarkdownConfig;
+use crate::model::persistence::article_view::ArticleView;
+use crate::model::values::article_id::ArticleId;
+use crate::utils::markdown::{RenderedMarkdown, render};
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct RenderCache {
+    entries: HashMap<ArticleId, (DateTime<Utc>, Arc<RenderedMarkdown>)>,
+    /// Cached articles, oldest first, to evict when the cache is full.
+    order: VecDeque<ArticleId>,
+}
+
+/// Renders article bodies, keeping the rendering of the latest revision of the most recently
+/// rendered articles so that an article is only rendered again once it's updated.
+#[derive(Clone)]
+pub struct BodyRenderer {
+    config: MarkdownConfig,
+    cache: Arc<Mutex<RenderCache>>,
+}
+
+impl BodyRenderer {
+    pub fn new(config: MarkdownConfig) -> Self {
+        BodyRenderer {
+            config,
+            cache: Arc::new(Mutex::new(RenderCache::default())),
+        }
+    }
+
+    pub fn render(&self, article: &ArticleView) -> Arc<RenderedMarkdown> {
+        if let Some((revision, rendered)) = self
+            .cache
+            .lock()
+            .expect("Render cache lock is not poisoned")
+            .entries
+            .get(&article.id)
+            && *revision == article.updated_at
+        {
+            return rendered.clone();
+        }
+
+        // Rendered outside of the lock, not to hold up the readers of other articles.
+        let rendered = Arc::new(render(article.body.value()));
+
+        let mut cache = self
+            .cache
+            .lock()
+            .expect("Render cache lock is not poisoned");
+
+        if cache
+            .entries
+            .insert(article.id, (article.updated_at, rendered.clone()))
+            .is_none()
+        {
+            cache.order.push_back(article.id);
+        }
+
+        while cache.order.len() > self.config.cache_capacity {
+            if let Some(evicted) = cache.order.pop_front() {
+                cache.entries.remove(&evicted);
+            }
+        }
+
+        rendered
+    }
+}
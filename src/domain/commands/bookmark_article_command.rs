@@ -0,0 +1,35 @@
+use crate::http::dto::bookmark::BookmarkRequest;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::bookmark_folder::BookmarkFolder;
+use crate::model::values::bookmark_note::BookmarkNote;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_bookmark_params::InsertBookmarkParams;
+
+#[derive(Debug, Clone)]
+pub struct BookmarkArticleCommand {
+    pub slug: Slug,
+    pub user_id: UserId,
+    pub folder: Option<BookmarkFolder>,
+    pub note: Option<BookmarkNote>,
+}
+
+impl BookmarkArticleCommand {
+    pub fn from_request(dto: BookmarkRequest, slug: Slug, user_id: UserId) -> Self {
+        BookmarkArticleCommand {
+            slug,
+            user_id,
+            folder: dto.bookmark.folder,
+            note: dto.bookmark.note,
+        }
+    }
+
+    pub fn to_params(&self, article_id: ArticleId) -> InsertBookmarkParams {
+        InsertBookmarkParams {
+            user_id: self.user_id,
+            article_id,
+            folder: self.folder.clone(),
+            note: self.note.clone(),
+        }
+    }
+}
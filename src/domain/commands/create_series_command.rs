@@ -0,0 +1,35 @@
+use crate::http::dto::series::CreateSeriesRequest;
+use crate::model::values::series_description::SeriesDescription;
+use crate::model::values::series_title::SeriesTitle;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_series_params::InsertSeriesParams;
+
+#[derive(Debug, Clone)]
+pub struct CreateSeriesCommand {
+    pub title: SeriesTitle,
+    pub description: Option<SeriesDescription>,
+    /// Slugs of the articles, in reading order.
+    pub articles: Vec<Slug>,
+    pub owner_id: UserId,
+}
+
+impl CreateSeriesCommand {
+    pub fn from_request(dto: CreateSeriesRequest, owner_id: UserId) -> Self {
+        CreateSeriesCommand {
+            title: dto.series.title,
+            description: dto.series.description,
+            articles: dto.series.articles.unwrap_or_default(),
+            owner_id,
+        }
+    }
+
+    pub fn to_insert_params(&self, slug: Slug) -> InsertSeriesParams {
+        InsertSeriesParams {
+            slug,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            owner_id: self.owner_id,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::http::dto::webhook::CreateWebhookRequest;
+use crate::model::values::user_id::UserId;
+use crate::model::values::webhook_event::WebhookEvent;
+use crate::model::values::webhook_url::WebhookUrl;
+use crate::persistence::params::insert_webhook_params::InsertWebhookParams;
+
+#[derive(Debug, Clone)]
+pub struct CreateWebhookCommand {
+    pub owner_id: UserId,
+    pub url: WebhookUrl,
+    pub events: Vec<WebhookEvent>,
+}
+
+impl CreateWebhookCommand {
+    pub fn from_request(request: CreateWebhookRequest, owner_id: UserId) -> Self {
+        let mut events = Vec::new();
+
+        for event in request.webhook.events {
+            if !events.contains(&event) {
+                events.push(event);
+            }
+        }
+
+        CreateWebhookCommand {
+            owner_id,
+            url: request.webhook.url,
+            events,
+        }
+    }
+
+    pub fn to_insert_params(&self, secret: String) -> InsertWebhookParams {
+        InsertWebhookParams {
+            owner_id: self.owner_id,
+            url: self.url.clone(),
+            secret,
+            events: self.events.clone(),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::http::dto::article::ArticleFeedListQuery;
+use crate::model::feed_source::FeedSource;
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
 use crate::model::values::user_id::UserId;
@@ -6,6 +7,7 @@ use crate::model::values::user_id::UserId;
 #[derive(Debug, Clone)]
 pub struct GetFeedQuery {
     pub user_id: UserId,
+    pub source: FeedSource,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
 }
@@ -14,6 +16,7 @@ impl GetFeedQuery {
     pub fn from_request(dto: ArticleFeedListQuery, user_id: UserId) -> Self {
         GetFeedQuery {
             user_id,
+            source: dto.source.unwrap_or_default(),
             limit: dto.limit,
             offset: dto.offset,
         }
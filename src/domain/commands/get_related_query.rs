@@ -0,0 +1,21 @@
+use crate::http::dto::article::ArticleRelatedListQuery;
+use crate::model::limit::Limit;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+
+#[derive(Debug, Clone)]
+pub struct GetRelatedQuery {
+    pub slug: Slug,
+    pub user_id: Option<UserId>,
+    pub limit: Option<Limit>,
+}
+
+impl GetRelatedQuery {
+    pub fn from_request(dto: ArticleRelatedListQuery, slug: Slug, user_id: Option<UserId>) -> Self {
+        GetRelatedQuery {
+            slug,
+            user_id,
+            limit: dto.limit,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::http::dto::stats::StatsQuery;
+use crate::model::values::user_id::UserId;
+use chrono::{DateTime, Days, Utc};
+
+#[derive(Debug, Clone)]
+pub struct GetStatsQuery {
+    pub user_id: UserId,
+    pub since: DateTime<Utc>,
+}
+
+impl GetStatsQuery {
+    pub fn from_request(dto: StatsQuery, user_id: UserId) -> Self {
+        let today = Utc::now().date_naive();
+        let first_day = today
+            .checked_sub_days(Days::new(u64::from(dto.days()) - 1))
+            .unwrap_or(today);
+
+        GetStatsQuery {
+            user_id,
+            since: first_day.and_time(Default::default()).and_utc(),
+        }
+    }
+}
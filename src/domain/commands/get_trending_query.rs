@@ -0,0 +1,24 @@
+use crate::http::dto::article::ArticleTrendingListQuery;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::trending_window::TrendingWindow;
+use crate::model::values::user_id::UserId;
+
+#[derive(Debug, Clone)]
+pub struct GetTrendingQuery {
+    pub user_id: Option<UserId>,
+    pub window: TrendingWindow,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl GetTrendingQuery {
+    pub fn from_request(dto: ArticleTrendingListQuery, user_id: Option<UserId>) -> Self {
+        GetTrendingQuery {
+            user_id,
+            window: dto.window.unwrap_or_default(),
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
@@ -1,24 +1,46 @@
 use crate::http::dto::article::ArticleListQuery as ArticleListQueryDto;
+use crate::model::article_sort::{ArticleSort, SortOrder, TagMatch};
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
 use crate::model::values::tag_name::TagName;
 use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
 pub struct ListArticlesQuery {
-    pub tag: Option<TagName>,
+    pub tags: Vec<TagName>,
+    pub tag_match: TagMatch,
+    pub excluded_tags: Vec<TagName>,
     pub author: Option<Username>,
     pub favorited_by: Option<Username>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub sort: ArticleSort,
+    pub order: SortOrder,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
 }
 
 impl ListArticlesQuery {
     pub fn from_request(dto: ArticleListQueryDto) -> Self {
+        let mut tags: Vec<TagName> = dto.tag.into_iter().collect();
+
+        for tag in dto.tags.map(|t| t.into_vec()).unwrap_or_default() {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
         ListArticlesQuery {
-            tag: dto.tag,
+            tags,
+            tag_match: dto.tag_mode.unwrap_or_default(),
+            excluded_tags: dto.exclude_tags.map(|t| t.into_vec()).unwrap_or_default(),
             author: dto.author,
             favorited_by: dto.favorited,
+            created_after: dto.created_after,
+            created_before: dto.created_before,
+            sort: dto.sort.unwrap_or_default(),
+            order: dto.order.unwrap_or_default(),
             limit: dto.limit,
             offset: dto.offset,
         }
@@ -0,0 +1,24 @@
+use crate::http::dto::bookmark::BookmarkListQuery;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::values::bookmark_folder::BookmarkFolder;
+use crate::model::values::user_id::UserId;
+
+#[derive(Debug, Clone)]
+pub struct ListBookmarksQuery {
+    pub user_id: UserId,
+    pub folder: Option<BookmarkFolder>,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl ListBookmarksQuery {
+    pub fn from_request(dto: BookmarkListQuery, user_id: UserId) -> Self {
+        ListBookmarksQuery {
+            user_id,
+            folder: dto.folder,
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::http::dto::comment::CommentListQuery;
+use crate::model::comment_sort::CommentSort;
+use crate::model::limit::Limit;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::comment_id::CommentId;
+
+#[derive(Debug, Clone)]
+pub struct ListCommentsQuery {
+    pub article_id: ArticleId,
+    pub sort: CommentSort,
+    pub cursor: Option<CommentId>,
+    pub limit: Option<Limit>,
+}
+
+impl ListCommentsQuery {
+    pub fn from_request(dto: CommentListQuery, article_id: ArticleId) -> Self {
+        ListCommentsQuery {
+            article_id,
+            sort: dto.sort.unwrap_or_default(),
+            cursor: dto.cursor,
+            limit: dto.limit,
+        }
+    }
+}
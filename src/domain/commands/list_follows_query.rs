@@ -0,0 +1,31 @@
+use crate::http::dto::profile::FollowListQuery;
+use crate::model::follow_direction::FollowDirection;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::values::user_id::UserId;
+
+#[derive(Debug, Clone)]
+pub struct ListFollowsQuery {
+    pub user_id: UserId,
+    pub direction: FollowDirection,
+    pub viewer_id: Option<UserId>,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl ListFollowsQuery {
+    pub fn from_request(
+        dto: FollowListQuery,
+        user_id: UserId,
+        direction: FollowDirection,
+        viewer_id: Option<UserId>,
+    ) -> Self {
+        ListFollowsQuery {
+            user_id,
+            direction,
+            viewer_id,
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
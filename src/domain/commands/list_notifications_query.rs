@@ -0,0 +1,21 @@
+use crate::http::dto::notification::NotificationListQuery;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::values::user_id::UserId;
+
+#[derive(Debug, Clone)]
+pub struct ListNotificationsQuery {
+    pub user_id: UserId,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl ListNotificationsQuery {
+    pub fn from_request(dto: NotificationListQuery, user_id: UserId) -> Self {
+        ListNotificationsQuery {
+            user_id,
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
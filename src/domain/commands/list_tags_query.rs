@@ -0,0 +1,30 @@
+use crate::http::dto::tag::TagListQuery;
+use crate::model::limit::Limit;
+use crate::model::tag_sort::TagSort;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::list_tags_params::ListTagsParams;
+
+#[derive(Debug, Clone)]
+pub struct ListTagsQuery {
+    pub sort: TagSort,
+    pub limit: Option<Limit>,
+    pub user_id: Option<UserId>,
+}
+
+impl ListTagsQuery {
+    pub fn from_request(dto: TagListQuery, user_id: Option<UserId>) -> Self {
+        ListTagsQuery {
+            sort: dto.sort.unwrap_or_default(),
+            limit: dto.limit,
+            user_id,
+        }
+    }
+
+    pub fn to_params(&self) -> ListTagsParams {
+        ListTagsParams {
+            sort: self.sort,
+            limit: self.limit,
+            user_id: self.user_id,
+        }
+    }
+}
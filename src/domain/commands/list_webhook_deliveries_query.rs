@@ -0,0 +1,28 @@
+use crate::http::dto::webhook::WebhookDeliveryListQuery;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::values::user_id::UserId;
+use crate::model::values::webhook_id::WebhookId;
+
+#[derive(Debug, Clone)]
+pub struct ListWebhookDeliveriesQuery {
+    pub webhook_id: WebhookId,
+    pub user_id: UserId,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl ListWebhookDeliveriesQuery {
+    pub fn from_request(
+        dto: WebhookDeliveryListQuery,
+        webhook_id: WebhookId,
+        user_id: UserId,
+    ) -> Self {
+        ListWebhookDeliveriesQuery {
+            webhook_id,
+            user_id,
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
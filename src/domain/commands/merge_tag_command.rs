@@ -0,0 +1,20 @@
+use crate::http::dto::tag::MergeTagRequest;
+use crate::model::values::tag_name::TagName;
+use crate::model::values::user_id::UserId;
+
+#[derive(Debug, Clone)]
+pub struct MergeTagCommand {
+    pub user_id: UserId,
+    pub source: TagName,
+    pub target: TagName,
+}
+
+impl MergeTagCommand {
+    pub fn from_request(dto: MergeTagRequest, source: TagName, user_id: UserId) -> Self {
+        MergeTagCommand {
+            user_id,
+            source,
+            target: dto.tag.into,
+        }
+    }
+}
@@ -1,8 +1,24 @@
 pub mod add_comment_command;
+pub mod bookmark_article_command;
 pub mod create_article_command;
+pub mod create_series_command;
+pub mod create_webhook_command;
 pub mod get_feed_query;
+pub mod get_related_query;
+pub mod get_stats_query;
+pub mod get_trending_query;
 pub mod list_articles_query;
+pub mod list_bookmarks_query;
+pub mod list_comments_query;
+pub mod list_follows_query;
+pub mod list_notifications_query;
+pub mod list_tags_query;
+pub mod list_webhook_deliveries_query;
 pub mod login_command;
+pub mod merge_tag_command;
 pub mod register_command;
 pub mod update_article_command;
+pub mod update_notification_preferences_command;
+pub mod update_series_command;
+pub mod update_tag_command;
 pub mod update_user_command;
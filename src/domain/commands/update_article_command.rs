@@ -4,6 +4,7 @@ use crate::model::values::article_description::ArticleDescription;
 use crate::model::values::article_id::ArticleId;
 use crate::model::values::article_title::ArticleTitle;
 use crate::model::values::slug::Slug;
+use crate::model::values::tag_name::TagName;
 use crate::persistence::params::update_article_params::UpdateArticleParams;
 
 #[derive(Debug, Clone)]
@@ -13,6 +14,9 @@ pub struct UpdateArticleCommand {
     pub title: Option<ArticleTitle>,
     pub description: Option<ArticleDescription>,
     pub body: Option<ArticleBody>,
+    pub tag_list: Option<Vec<TagName>>,
+    pub add_tags: Vec<TagName>,
+    pub remove_tags: Vec<TagName>,
 }
 
 impl UpdateArticleCommand {
@@ -29,9 +33,16 @@ impl UpdateArticleCommand {
             title: dto.article.title,
             description: dto.article.description,
             body: dto.article.body,
+            tag_list: dto.article.tag_list,
+            add_tags: dto.article.add_tags.unwrap_or_default(),
+            remove_tags: dto.article.remove_tags.unwrap_or_default(),
         }
     }
 
+    pub fn changes_tags(&self) -> bool {
+        self.tag_list.is_some() || !self.add_tags.is_empty() || !self.remove_tags.is_empty()
+    }
+
     pub fn to_params(&self, article_id: ArticleId) -> UpdateArticleParams {
         UpdateArticleParams {
             article_id,
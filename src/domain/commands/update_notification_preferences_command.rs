@@ -0,0 +1,31 @@
+use crate::http::dto::notification::UpdateNotificationPreferencesRequest;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::update_notification_preferences_params::UpdateNotificationPreferencesParams;
+
+#[derive(Debug, Clone)]
+pub struct UpdateNotificationPreferencesCommand {
+    pub user_id: UserId,
+    pub article_commented: Option<bool>,
+    pub article_favorited: Option<bool>,
+    pub user_followed: Option<bool>,
+}
+
+impl UpdateNotificationPreferencesCommand {
+    pub fn from_request(dto: UpdateNotificationPreferencesRequest, user_id: UserId) -> Self {
+        UpdateNotificationPreferencesCommand {
+            user_id,
+            article_commented: dto.preferences.article_commented,
+            article_favorited: dto.preferences.article_favorited,
+            user_followed: dto.preferences.user_followed,
+        }
+    }
+
+    pub fn to_params(&self) -> UpdateNotificationPreferencesParams {
+        UpdateNotificationPreferencesParams {
+            user_id: self.user_id,
+            article_commented: self.article_commented,
+            article_favorited: self.article_favorited,
+            user_followed: self.user_followed,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::http::dto::series::UpdateSeriesRequest;
+use crate::model::values::series_description::SeriesDescription;
+use crate::model::values::series_id::SeriesId;
+use crate::model::values::series_title::SeriesTitle;
+use crate::model::values::slug::Slug;
+use crate::persistence::params::update_series_params::UpdateSeriesParams;
+
+#[derive(Debug, Clone)]
+pub struct UpdateSeriesCommand {
+    pub old_slug: Slug,
+    pub new_slug: Option<Slug>,
+    pub title: Option<SeriesTitle>,
+    pub description: Option<SeriesDescription>,
+    /// Replaces the articles of the series, in reading order.
+    pub articles: Option<Vec<Slug>>,
+}
+
+impl UpdateSeriesCommand {
+    pub fn from_request(dto: UpdateSeriesRequest, slug: Slug) -> Self {
+        let new_slug = dto
+            .series
+            .title
+            .as_ref()
+            .map(|t| Slug::from_title(t.value()));
+
+        UpdateSeriesCommand {
+            old_slug: slug,
+            new_slug,
+            title: dto.series.title,
+            description: dto.series.description,
+            articles: dto.series.articles,
+        }
+    }
+
+    pub fn to_params(&self, series_id: SeriesId) -> UpdateSeriesParams {
+        UpdateSeriesParams {
+            series_id,
+            slug: self.new_slug.clone(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::http::dto::tag::UpdateTagRequest;
+use crate::model::values::tag_description::TagDescription;
+use crate::model::values::tag_id::TagId;
+use crate::model::values::tag_name::TagName;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::update_tag_params::UpdateTagParams;
+
+#[derive(Debug, Clone)]
+pub struct UpdateTagCommand {
+    pub user_id: UserId,
+    pub name: TagName,
+    pub new_name: Option<TagName>,
+    pub description: Option<TagDescription>,
+}
+
+impl UpdateTagCommand {
+    pub fn from_request(dto: UpdateTagRequest, name: TagName, user_id: UserId) -> Self {
+        UpdateTagCommand {
+            user_id,
+            name,
+            new_name: dto.tag.name,
+            description: dto.tag.description,
+        }
+    }
+
+    pub fn to_params(&self, tag_id: TagId) -> UpdateTagParams {
+        UpdateTagParams {
+            tag_id,
+            name: self.new_name.clone(),
+            description: self.description.clone(),
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::model::values::password_hash::PasswordHash;
 use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
 use crate::persistence::params::update_user_params::UpdateUserParams;
+use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
 pub struct UpdateUserCommand {
@@ -16,6 +17,7 @@ pub struct UpdateUserCommand {
     pub password: Option<Password>,
     pub bio: Option<Bio>,
     pub image: Option<Image>,
+    pub private: Option<bool>,
 }
 
 impl UpdateUserCommand {
@@ -27,10 +29,15 @@ impl UpdateUserCommand {
             password: dto.user.password,
             bio: dto.user.bio,
             image: dto.user.image,
+            private: dto.user.private,
         }
     }
 
-    pub(crate) fn to_params(&self, password_hash: Option<PasswordHash>) -> UpdateUserParams {
+    pub(crate) fn to_params(
+        &self,
+        password_hash: Option<PasswordHash>,
+        username_changed_at: Option<DateTime<Utc>>,
+    ) -> UpdateUserParams {
         UpdateUserParams {
             user_id: self.user_id,
             email: self.email.clone(),
@@ -38,6 +45,8 @@ impl UpdateUserCommand {
             password_hash,
             bio: self.bio.clone(),
             image: self.image.clone(),
+            private: self.private,
+            username_changed_at,
         }
     }
 }
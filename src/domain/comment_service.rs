@@ -1,20 +1,78 @@
+use crate::app_config::AdminConfig;
 use crate::app_error::AppError;
 use crate::domain::commands::add_comment_command::AddCommentCommand;
+use crate::domain::commands::list_comments_query::ListCommentsQuery;
+use crate::domain::notification_service::NotificationService;
+use crate::domain::outbox::{Outbox, UnitOfWork};
+use crate::events::app_event::AppEvent;
+use crate::model::indexed_article_field::IndexedArticleField;
+use crate::model::limit::Limit;
+use crate::model::persistence::comment::Comment;
 use crate::model::persistence::comment_view::CommentView;
-use crate::model::values::article_id::ArticleId;
 use crate::model::values::comment_id::CommentId;
+use crate::model::values::notification_kind::NotificationKind;
+use crate::model::values::reaction_kind::ReactionKind;
+use crate::model::values::slug::Slug;
 use crate::model::values::user_id::UserId;
+use crate::persistence::article_repository::ArticleRepository;
 use crate::persistence::comment_repository::CommentRepository;
+use crate::persistence::params::insert_notification_params::InsertNotificationParams;
+use crate::persistence::profile_repository::ProfileRepository;
 use anyhow::Result;
 
 #[derive(Clone)]
 pub struct CommentService {
     comment_repo: CommentRepository,
+    article_repo: ArticleRepository,
+    profile_repo: ProfileRepository,
+    notification_service: NotificationService,
+    outbox: Outbox,
+    admin_config: AdminConfig,
 }
 
 impl CommentService {
-    pub fn new(comment_repo: CommentRepository) -> Self {
-        CommentService { comment_repo }
+    pub fn new(
+        comment_repo: CommentRepository,
+        article_repo: ArticleRepository,
+        profile_repo: ProfileRepository,
+        notification_service: NotificationService,
+        outbox: Outbox,
+        admin_config: AdminConfig,
+    ) -> Self {
+        CommentService {
+            comment_repo,
+            article_repo,
+            profile_repo,
+            notification_service,
+            outbox,
+            admin_config,
+        }
+    }
+
+    /// The author or a moderator, one of the admins, locks or unlocks the comments.
+    pub async fn set_comments_locked(
+        &self,
+        user_id: UserId,
+        slug: &Slug,
+        locked: bool,
+    ) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let article = self
+            .article_repo
+            .get_article_by(uow.conn(), IndexedArticleField::Slug, slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if article.author_id != user_id && !self.admin_config.is_admin(user_id) {
+            return Err(AppError::Forbidden);
+        }
+
+        self.article_repo
+            .set_comments_locked(uow.conn(), article.id, locked)
+            .await?;
+
+        uow.commit().await
     }
 
     pub async fn delete_comment(
@@ -22,15 +80,29 @@ impl CommentService {
         comment_id: CommentId,
         user_id: UserId,
     ) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
         if !self
             .comment_repo
-            .is_comment_author(comment_id, user_id)
+            .is_comment_author(uow.conn(), comment_id, user_id)
             .await?
         {
             return Err(AppError::Forbidden);
         }
 
-        self.comment_repo.delete_comment(comment_id).await
+        if let Some(article_id) = self
+            .comment_repo
+            .delete_comment(uow.conn(), comment_id)
+            .await?
+        {
+            uow.record(AppEvent::CommentDeleted {
+                article_id,
+                comment_id,
+            })
+            .await?;
+        }
+
+        uow.commit().await
     }
 
     pub async fn add_comment(
@@ -38,22 +110,181 @@ impl CommentService {
         command: AddCommentCommand,
         user_id: UserId,
     ) -> Result<CommentView, AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let article = self
+            .article_repo
+            .get_article_by(uow.conn(), IndexedArticleField::Id, command.article_id)
+            .await?;
+
+        if let Some(article) = &article {
+            if self
+                .profile_repo
+                .is_blocking(uow.conn(), article.author_id, user_id)
+                .await?
+            {
+                return Err(AppError::Forbidden);
+            }
+
+            // Past the block, those the author hides the article from don't learn it exists.
+            if !self
+                .article_repo
+                .is_readable(uow.conn(), article.id, user_id)
+                .await?
+            {
+                return Err(AppError::NotFound);
+            }
+
+            if article.comments_locked {
+                return Err(AppError::DataConflict(
+                    "Comments are locked on this article".to_string(),
+                ));
+            }
+        }
+
         let params = command.to_insert_params();
-        let comment = self.comment_repo.insert_comment(params).await?;
+        let comment = self.comment_repo.insert_comment(uow.conn(), params).await?;
 
-        let comment = self
-            .comment_repo
+        if let Some(article) = article {
+            self.notification_service
+                .notify(
+                    &mut uow,
+                    InsertNotificationParams {
+                        recipient_id: article.author_id,
+                        actor_id: user_id,
+                        kind: NotificationKind::ArticleCommented,
+                        article_id: Some(article.id),
+                        comment_id: Some(comment.id),
+                    },
+                )
+                .await?;
+        }
+
+        uow.record(AppEvent::CommentCreated {
+            article_id: comment.article_id,
+            comment_id: comment.id,
+        })
+        .await?;
+
+        uow.commit().await?;
+
+        self.comment_repo
             .get_comment(comment.id, Some(user_id))
-            .await?;
+            .await
+    }
 
-        Ok(comment)
+    pub async fn get_comment(
+        &self,
+        comment_id: CommentId,
+        user_id: Option<UserId>,
+    ) -> Result<CommentView, AppError> {
+        self.comment_repo.get_comment(comment_id, user_id).await
     }
 
+    /// Returns a page of comments and the cursor of the next page, if there is one.
     pub async fn get_comments(
         &self,
-        article_id: ArticleId,
+        query: &ListCommentsQuery,
         user_id: Option<UserId>,
-    ) -> Result<Vec<CommentView>, AppError> {
-        self.comment_repo.get_comments(article_id, user_id).await
+    ) -> Result<(Vec<CommentView>, Option<CommentId>), AppError> {
+        let limit = query.limit.unwrap_or_default().value();
+
+        // One more comment than asked tells whether there is a next page.
+        let mut comments = self
+            .comment_repo
+            .get_comments(
+                query.article_id,
+                user_id,
+                query.sort,
+                query.cursor,
+                Limit::new(limit.saturating_add(1)),
+            )
+            .await?;
+
+        let next_cursor = if comments.len() as u64 > limit {
+            comments.truncate(limit as usize);
+            comments.last().map(|comment| comment.id)
+        } else {
+            None
+        };
+
+        Ok((comments, next_cursor))
+    }
+
+    /// The comment, as long as the user can read its article.
+    async fn get_readable_comment(
+        &self,
+        uow: &mut UnitOfWork,
+        comment_id: CommentId,
+        user_id: UserId,
+    ) -> Result<Comment, AppError> {
+        let comment = self
+            .comment_repo
+            .get_comment_by_id(uow.conn(), comment_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if !self
+            .article_repo
+            .is_readable(uow.conn(), comment.article_id, user_id)
+            .await?
+        {
+            return Err(AppError::NotFound);
+        }
+
+        Ok(comment)
+    }
+
+    /// Users blocked by the author of the comment can't react to it.
+    pub async fn add_reaction(
+        &self,
+        comment_id: CommentId,
+        user_id: UserId,
+        kind: ReactionKind,
+    ) -> Result<CommentView, AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let comment = self
+            .get_readable_comment(&mut uow, comment_id, user_id)
+            .await?;
+
+        if self
+            .profile_repo
+            .is_blocking(uow.conn(), comment.author_id, user_id)
+            .await?
+        {
+            return Err(AppError::Forbidden);
+        }
+
+        self.comment_repo
+            .add_reaction(uow.conn(), user_id, comment_id, kind)
+            .await?;
+        uow.commit().await?;
+
+        self.comment_repo
+            .get_comment(comment_id, Some(user_id))
+            .await
+    }
+
+    /// Reactions can still be taken back once blocked by the author of the comment.
+    pub async fn remove_reaction(
+        &self,
+        comment_id: CommentId,
+        user_id: UserId,
+        kind: ReactionKind,
+    ) -> Result<CommentView, AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        self.get_readable_comment(&mut uow, comment_id, user_id)
+            .await?;
+
+        self.comment_repo
+            .remove_reaction(uow.conn(), user_id, comment_id, kind)
+            .await?;
+        uow.commit().await?;
+
+        self.comment_repo
+            .get_comment(comment_id, Some(user_id))
+            .await
     }
 }
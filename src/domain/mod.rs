@@ -1,6 +1,20 @@
 pub mod article_service;
+pub mod body_renderer;
 pub mod commands;
 pub mod comment_service;
+pub mod notification_service;
+pub mod outbox;
+pub mod outbox_cleanup_job;
+pub mod outbox_dispatcher;
 pub mod profile_service;
+pub mod series_service;
+pub mod stats_service;
+pub mod tag_cleanup_job;
 pub mod tag_service;
+pub mod trending_refresh_job;
+pub mod upload_service;
 pub mod user_service;
+pub mod view_cleanup_job;
+pub mod view_recorder;
+pub mod webhook_dispatcher;
+pub mod webhook_service;
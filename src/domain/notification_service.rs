@@ -0,0 +1,127 @@
+use crate::app_error::AppError;
+use crate::domain::commands::list_notifications_query::ListNotificationsQuery;
+use crate::domain::commands::update_notification_preferences_command::UpdateNotificationPreferencesCommand;
+use crate::domain::outbox::UnitOfWork;
+use crate::events::app_event::AppEvent;
+use crate::model::persistence::notification_preferences::NotificationPreferences;
+use crate::model::persistence::notification_view::NotificationView;
+use crate::model::values::notification_id::NotificationId;
+use crate::model::values::user_id::UserId;
+use crate::persistence::notification_repository::NotificationRepository;
+use crate::persistence::params::insert_notification_params::InsertNotificationParams;
+use anyhow::Result;
+
+#[derive(Clone)]
+pub struct NotificationService {
+    notification_repo: NotificationRepository,
+}
+
+impl NotificationService {
+    pub fn new(notification_repo: NotificationRepository) -> Self {
+        NotificationService { notification_repo }
+    }
+
+    pub async fn notify(
+        &self,
+        uow: &mut UnitOfWork,
+        params: InsertNotificationParams,
+    ) -> Result<(), AppError> {
+        if params.recipient_id == params.actor_id {
+            return Ok(());
+        }
+
+        // Read on the caller's connection, which holds the transaction already.
+        let preferences = self
+            .notification_repo
+            .find_preferences(uow.conn(), params.recipient_id)
+            .await?
+            .unwrap_or_else(|| NotificationPreferences::default_for(params.recipient_id));
+
+        if preferences.is_enabled(params.kind) {
+            let recipient_id = params.recipient_id;
+            let notification_id = self
+                .notification_repo
+                .insert_notification(uow.conn(), params)
+                .await?;
+
+            uow.record(AppEvent::NotificationCreated {
+                recipient_id,
+                notification_id,
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn list_notifications(
+        &self,
+        query: ListNotificationsQuery,
+    ) -> Result<Vec<NotificationView>, AppError> {
+        self.notification_repo
+            .list_notifications(query.user_id, query.limit, query.offset)
+            .await
+    }
+
+    pub async fn get_notification(
+        &self,
+        user_id: UserId,
+        notification_id: NotificationId,
+    ) -> Result<Option<NotificationView>, AppError> {
+        self.notification_repo
+            .get_notification(user_id, notification_id)
+            .await
+    }
+
+    pub async fn count_notifications(&self, user_id: UserId) -> Result<u64, AppError> {
+        self.notification_repo
+            .count_notifications(user_id, false)
+            .await
+    }
+
+    pub async fn count_unread(&self, user_id: UserId) -> Result<u64, AppError> {
+        self.notification_repo
+            .count_notifications(user_id, true)
+            .await
+    }
+
+    pub async fn mark_as_read(
+        &self,
+        user_id: UserId,
+        notification_id: NotificationId,
+    ) -> Result<(), AppError> {
+        if self
+            .notification_repo
+            .mark_as_read(user_id, notification_id)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(AppError::NotFound)
+        }
+    }
+
+    pub async fn mark_all_as_read(&self, user_id: UserId) -> Result<(), AppError> {
+        self.notification_repo.mark_all_as_read(user_id).await
+    }
+
+    pub async fn get_preferences(
+        &self,
+        user_id: UserId,
+    ) -> Result<NotificationPreferences, AppError> {
+        Ok(self
+            .notification_repo
+            .get_preferences(user_id)
+            .await?
+            .unwrap_or_else(|| NotificationPreferences::default_for(user_id)))
+    }
+
+    pub async fn update_preferences(
+        &self,
+        command: UpdateNotificationPreferencesCommand,
+    ) -> Result<NotificationPreferences, AppError> {
+        self.notification_repo
+            .update_preferences(command.to_params())
+            .await
+    }
+}
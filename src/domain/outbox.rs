@@ -0,0 +1,102 @@
+use crate::app_error::AppError;
+use crate::database::{Database, DbExecutor};
+use crate::events::app_event::AppEvent;
+use crate::persistence::outbox_repository::OutboxRepository;
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+/// Opens units of work and lets the outbox dispatcher know when they've committed events.
+#[derive(Clone)]
+pub struct Outbox {
+    database: Database,
+    outbox_repo: OutboxRepository,
+    pending: Arc<Notify>,
+}
+
+impl Outbox {
+    pub fn new(database: Database, outbox_repo: OutboxRepository) -> Self {
+        Outbox {
+            database,
+            outbox_repo,
+            pending: Arc::new(Notify::new()),
+        }
+    }
+
+    pub async fn begin(&self) -> Result<UnitOfWork, AppError> {
+        Ok(UnitOfWork {
+            tx: self.database.begin().await?,
+            outbox_repo: self.outbox_repo.clone(),
+            pending: self.pending.clone(),
+            has_events: false,
+        })
+    }
+
+    /// Resolves once a unit of work committed new events.
+    pub async fn wait_for_events(&self) {
+        self.pending.notified().await
+    }
+}
+
+/// A transaction the repositories run in, together with the events it produces.
+///
+/// Nothing is written unless [`UnitOfWork::commit`] is called; dropping it rolls back.
+pub struct UnitOfWork {
+    tx: Transaction<'static, Postgres>,
+    outbox_repo: OutboxRepository,
+    pending: Arc<Notify>,
+    has_events: bool,
+}
+
+impl UnitOfWork {
+    pub fn conn(&mut self) -> DbExecutor<'_> {
+        (&mut self.tx).into()
+    }
+
+    /// Writes the event to the outbox, it's published only if the unit of work commits.
+    pub async fn record(&mut self, event: AppEvent) -> Result<(), AppError> {
+        self.outbox_repo
+            .insert_event((&mut self.tx).into(), &event)
+            .await?;
+        self.has_events = true;
+
+        Ok(())
+    }
+
+    /// Marks a point to roll back to, without giving up what was done before it.
+    pub async fn savepoint(&mut self) -> Result<(), AppError> {
+        sqlx::query("SAVEPOINT unit_of_work")
+            .execute(&mut *self.tx)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Undoes everything since the last [`UnitOfWork::savepoint`].
+    pub async fn rollback_to_savepoint(&mut self) -> Result<(), AppError> {
+        sqlx::query("ROLLBACK TO SAVEPOINT unit_of_work")
+            .execute(&mut *self.tx)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Keeps everything since the last [`UnitOfWork::savepoint`].
+    pub async fn release_savepoint(&mut self) -> Result<(), AppError> {
+        sqlx::query("RELEASE SAVEPOINT unit_of_work")
+            .execute(&mut *self.tx)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn commit(self) -> Result<(), AppError> {
+        self.tx.commit().await?;
+
+        if self.has_events {
+            self.pending.notify_one();
+        }
+
+        Ok(())
+    }
+}
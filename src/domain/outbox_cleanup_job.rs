@@ -0,0 +1,44 @@
+use crate::app_config::OutboxConfig;
+use crate::database::Database;
+use crate::persistence::outbox_repository::OutboxRepository;
+use chrono::{TimeDelta, Utc};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, error};
+
+/// Background job deleting the outbox events that were processed longer ago than the
+/// retention, so that the table only grows with the events still waiting to be published.
+pub struct OutboxCleanupJob {
+    database: Database,
+    outbox_repo: OutboxRepository,
+    config: OutboxConfig,
+}
+
+impl OutboxCleanupJob {
+    pub fn new(database: Database, outbox_repo: OutboxRepository, config: OutboxConfig) -> Self {
+        OutboxCleanupJob {
+            database,
+            outbox_repo,
+            config,
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(self.config.cleanup_interval_ms)).await;
+
+                let before = Utc::now() - TimeDelta::seconds(self.config.retention_secs as i64);
+
+                match self
+                    .outbox_repo
+                    .delete_processed((&self.database).into(), before)
+                    .await
+                {
+                    Ok(deleted) => debug!(deleted, "Deleted processed outbox events"),
+                    Err(e) => error!(error = %e, "Failed to delete processed outbox events"),
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use crate::app_config::OutboxConfig;
+use crate::app_error::AppError;
+use crate::domain::outbox::{Outbox, UnitOfWork};
+use crate::events::event_sink::EventSink;
+use crate::model::persistence::outbox_event::OutboxEvent;
+use crate::persistence::outbox_repository::OutboxRepository;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, warn};
+
+/// Background worker publishing committed outbox events to every sink, in commit order. The
+/// sinks that aren't transactional come last, once the event is processed.
+///
+/// It runs as soon as a unit of work commits events and polls for leftovers, e.g. events of
+/// a crashed instance or ones that failed before.
+pub struct OutboxDispatcher {
+    outbox: Outbox,
+    outbox_repo: OutboxRepository,
+    sinks: Vec<Arc<dyn EventSink>>,
+    config: OutboxConfig,
+}
+
+impl OutboxDispatcher {
+    pub fn new(
+        outbox: Outbox,
+        outbox_repo: OutboxRepository,
+        sinks: Vec<Arc<dyn EventSink>>,
+        config: OutboxConfig,
+    ) -> Self {
+        OutboxDispatcher {
+            outbox,
+            outbox_repo,
+            sinks,
+            config,
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match self.dispatch_pending().await {
+                    // A full batch means more events are probably waiting already.
+                    Ok(dispatched) if dispatched as u64 == self.config.batch_size => continue,
+                    Ok(_) => {}
+                    Err(e) => error!(error = %e, "Failed to dispatch outbox events"),
+                }
+
+                tokio::select! {
+                    _ = self.outbox.wait_for_events() => {}
+                    _ = tokio::time::sleep(Duration::from_millis(self.config.poll_interval_ms)) => {}
+                }
+            }
+        })
+    }
+
+    /// Publishes the oldest pending events, stopping at the first one that fails so the
+    /// rest isn't published out of order.
+    pub async fn dispatch_pending(&self) -> Result<usize, AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let events = self
+            .outbox_repo
+            .claim_pending(uow.conn(), self.config.batch_size)
+            .await?;
+
+        let mut dispatched = 0;
+
+        for event in events {
+            uow.savepoint().await?;
+
+            match self.publish(&mut uow, &event).await {
+                Ok(()) => {
+                    uow.release_savepoint().await?;
+                    self.outbox_repo
+                        .mark_processed(uow.conn(), event.id)
+                        .await?;
+                    self.broadcast(&mut uow, &event).await;
+                    dispatched += 1;
+                }
+                Err(e) => {
+                    // Whatever the sinks wrote before one of them failed goes away with it.
+                    uow.rollback_to_savepoint().await?;
+
+                    let give_up = (event.attempts + 1) as u32 >= self.config.max_attempts;
+
+                    warn!(
+                        event_id = event.id,
+                        attempt = event.attempts + 1,
+                        give_up,
+                        error = %e,
+                        "Failed to publish outbox event"
+                    );
+
+                    self.outbox_repo
+                        .mark_failed(uow.conn(), event.id, e.to_string(), give_up)
+                        .await?;
+
+                    if !give_up {
+                        break;
+                    }
+
+                    dispatched += 1;
+                }
+            }
+        }
+
+        uow.commit().await?;
+
+        Ok(dispatched)
+    }
+
+    async fn publish(&self, uow: &mut UnitOfWork, event: &OutboxEvent) -> Result<(), AppError> {
+        for sink in self.sinks.iter().filter(|sink| sink.is_transactional()) {
+            sink.publish(uow, event).await.inspect_err(
+                |e| debug!(sink = sink.name(), event_id = event.id, error = %e, "Sink failed"),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Feeds the sinks that can't be rolled back, which would repeat the event if it was retried.
+    async fn broadcast(&self, uow: &mut UnitOfWork, event: &OutboxEvent) {
+        for sink in self.sinks.iter().filter(|sink| !sink.is_transactional()) {
+            if let Err(e) = sink.publish(uow, event).await {
+                warn!(sink = sink.name(), event_id = event.id, error = %e, "Failed to broadcast outbox event");
+            }
+        }
+    }
+}
@@ -1,16 +1,36 @@
 use crate::app_error::AppError;
+use crate::domain::commands::list_follows_query::ListFollowsQuery;
+use crate::domain::notification_service::NotificationService;
+use crate::domain::outbox::{Outbox, UnitOfWork};
+use crate::events::app_event::AppEvent;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::profile_view::ProfileView;
+use crate::model::values::notification_kind::NotificationKind;
 use crate::model::values::user_id::UserId;
+use crate::model::values::username::Username;
+use crate::persistence::params::insert_notification_params::InsertNotificationParams;
 use crate::persistence::profile_repository::ProfileRepository;
 use anyhow::Result;
 
 #[derive(Clone)]
 pub struct ProfileService {
     profile_repo: ProfileRepository,
+    notification_service: NotificationService,
+    outbox: Outbox,
 }
 
 impl ProfileService {
-    pub fn new(profile_repo: ProfileRepository) -> Self {
-        ProfileService { profile_repo }
+    pub fn new(
+        profile_repo: ProfileRepository,
+        notification_service: NotificationService,
+        outbox: Outbox,
+    ) -> Self {
+        ProfileService {
+            profile_repo,
+            notification_service,
+            outbox,
+        }
     }
 
     pub async fn follow_user(
@@ -22,11 +42,161 @@ impl ProfileService {
             return Err(AppError::BadData("Cannot follow yourself".to_string()));
         }
 
+        let mut uow = self.outbox.begin().await?;
+
+        if self
+            .profile_repo
+            .is_blocking(uow.conn(), followee_id, follower_id)
+            .await?
+        {
+            return Err(AppError::Forbidden);
+        }
+
+        if self
+            .profile_repo
+            .is_private(uow.conn(), followee_id)
+            .await?
+            && !self
+                .profile_repo
+                .is_following(uow.conn(), follower_id, followee_id)
+                .await?
+        {
+            return self.request_follow(uow, follower_id, followee_id).await;
+        }
+
+        if self
+            .profile_repo
+            .follow_user(uow.conn(), follower_id, followee_id)
+            .await?
+        {
+            self.notification_service
+                .notify(
+                    &mut uow,
+                    InsertNotificationParams {
+                        recipient_id: followee_id,
+                        actor_id: follower_id,
+                        kind: NotificationKind::UserFollowed,
+                        article_id: None,
+                        comment_id: None,
+                    },
+                )
+                .await?;
+
+            uow.record(AppEvent::UserFollowed {
+                follower_id,
+                followee_id,
+            })
+            .await?;
+        }
+
+        uow.commit().await
+    }
+
+    /// Private users approve their followers, following them only sends a request.
+    async fn request_follow(
+        &self,
+        mut uow: UnitOfWork,
+        requester_id: UserId,
+        target_id: UserId,
+    ) -> Result<(), AppError> {
+        if self
+            .profile_repo
+            .request_follow(uow.conn(), requester_id, target_id)
+            .await?
+        {
+            self.notification_service
+                .notify(
+                    &mut uow,
+                    InsertNotificationParams {
+                        recipient_id: target_id,
+                        actor_id: requester_id,
+                        kind: NotificationKind::FollowRequested,
+                        article_id: None,
+                        comment_id: None,
+                    },
+                )
+                .await?;
+        }
+
+        uow.commit().await
+    }
+
+    /// Requests are refused while either user blocks the other.
+    pub async fn approve_follow_request(
+        &self,
+        target_id: UserId,
+        requester_id: UserId,
+    ) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        if self
+            .profile_repo
+            .is_blocking(uow.conn(), target_id, requester_id)
+            .await?
+            || self
+                .profile_repo
+                .is_blocking(uow.conn(), requester_id, target_id)
+                .await?
+        {
+            return Err(AppError::Forbidden);
+        }
+
+        if !self
+            .profile_repo
+            .delete_follow_request(uow.conn(), requester_id, target_id)
+            .await?
+        {
+            return Err(AppError::NotFound);
+        }
+
+        if self
+            .profile_repo
+            .follow_user(uow.conn(), requester_id, target_id)
+            .await?
+        {
+            uow.record(AppEvent::UserFollowed {
+                follower_id: requester_id,
+                followee_id: target_id,
+            })
+            .await?;
+        }
+
+        uow.commit().await
+    }
+
+    pub async fn reject_follow_request(
+        &self,
+        target_id: UserId,
+        requester_id: UserId,
+    ) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        if !self
+            .profile_repo
+            .delete_follow_request(uow.conn(), requester_id, target_id)
+            .await?
+        {
+            return Err(AppError::NotFound);
+        }
+
+        uow.commit().await
+    }
+
+    pub async fn list_follow_requests(
+        &self,
+        target_id: UserId,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+    ) -> Result<Vec<ProfileView>, AppError> {
         self.profile_repo
-            .follow_user(follower_id, followee_id)
+            .list_follow_requests(target_id, limit, offset)
             .await
     }
 
+    pub async fn count_follow_requests(&self, target_id: UserId) -> Result<i64, AppError> {
+        self.profile_repo.count_follow_requests(target_id).await
+    }
+
     pub async fn unfollow_user(
         &self,
         follower_id: UserId,
@@ -37,13 +207,74 @@ impl ProfileService {
             .await
     }
 
-    pub async fn is_following(
+    pub async fn block_user(&self, blocker_id: UserId, blocked_id: UserId) -> Result<(), AppError> {
+        if blocker_id == blocked_id {
+            return Err(AppError::BadData("Cannot block yourself".to_string()));
+        }
+
+        let mut uow = self.outbox.begin().await?;
+
+        self.profile_repo
+            .block_user(uow.conn(), blocker_id, blocked_id)
+            .await?;
+
+        uow.commit().await
+    }
+
+    pub async fn unblock_user(
         &self,
-        follower_id: UserId,
-        followee_id: UserId,
-    ) -> Result<bool, AppError> {
+        blocker_id: UserId,
+        blocked_id: UserId,
+    ) -> Result<(), AppError> {
+        self.profile_repo.unblock_user(blocker_id, blocked_id).await
+    }
+
+    pub async fn mute_user(&self, muter_id: UserId, muted_id: UserId) -> Result<(), AppError> {
+        if muter_id == muted_id {
+            return Err(AppError::BadData("Cannot mute yourself".to_string()));
+        }
+
+        self.profile_repo.mute_user(muter_id, muted_id).await
+    }
+
+    pub async fn unmute_user(&self, muter_id: UserId, muted_id: UserId) -> Result<(), AppError> {
+        self.profile_repo.unmute_user(muter_id, muted_id).await
+    }
+
+    /// Profiles of users blocking the viewer are treated as missing.
+    pub async fn get_profile(
+        &self,
+        username: &Username,
+        viewer_id: Option<UserId>,
+    ) -> Result<Option<ProfileView>, AppError> {
+        Ok(self
+            .find_profile(username, viewer_id)
+            .await?
+            .filter(|profile| !profile.blocked_by))
+    }
+
+    /// Unlike [`Self::get_profile`], also finds users blocking the viewer, so that the viewer
+    /// can still block, mute or unfollow them.
+    pub async fn find_profile(
+        &self,
+        username: &Username,
+        viewer_id: Option<UserId>,
+    ) -> Result<Option<ProfileView>, AppError> {
+        self.profile_repo.get_profile(username, viewer_id).await
+    }
+
+    pub async fn list_follows(
+        &self,
+        query: &ListFollowsQuery,
+    ) -> Result<Vec<ProfileView>, AppError> {
         self.profile_repo
-            .is_following(follower_id, followee_id)
+            .list_follows(
+                query.user_id,
+                query.direction,
+                query.viewer_id,
+                query.limit,
+                query.offset,
+            )
             .await
     }
 }
@@ -0,0 +1,226 @@
+use crate::app_error::AppError;
+use crate::domain::commands::create_series_command::CreateSeriesCommand;
+use crate::domain::commands::update_series_command::UpdateSeriesCommand;
+use crate::domain::outbox::{Outbox, UnitOfWork};
+use crate::model::indexed_article_field::IndexedArticleField;
+use crate::model::persistence::article_view::ArticleListView;
+use crate::model::persistence::series_navigation::SeriesNavigation;
+use crate::model::persistence::series_view::SeriesView;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::series_id::SeriesId;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+use crate::persistence::article_repository::ArticleRepository;
+use crate::persistence::series_repository::SeriesRepository;
+use anyhow::Result;
+
+#[derive(Clone)]
+pub struct SeriesService {
+    series_repo: SeriesRepository,
+    article_repo: ArticleRepository,
+    outbox: Outbox,
+}
+
+impl SeriesService {
+    pub fn new(
+        series_repo: SeriesRepository,
+        article_repo: ArticleRepository,
+        outbox: Outbox,
+    ) -> Self {
+        SeriesService {
+            series_repo,
+            article_repo,
+            outbox,
+        }
+    }
+
+    async fn verify_slug(&self, uow: &mut UnitOfWork, slug: &Slug) -> Result<(), AppError> {
+        if self
+            .series_repo
+            .get_series_by_slug(uow.conn(), slug)
+            .await?
+            .is_some()
+        {
+            Err(AppError::DataConflict(format!(
+                "Series with slug '{}' already exists",
+                slug
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Resolves the slugs to articles of the owner, each listed once and belonging to no other
+    /// series.
+    async fn resolve_articles(
+        &self,
+        uow: &mut UnitOfWork,
+        series_id: SeriesId,
+        owner_id: UserId,
+        slugs: &[Slug],
+    ) -> Result<Vec<ArticleId>, AppError> {
+        let mut article_ids = Vec::with_capacity(slugs.len());
+
+        for slug in slugs {
+            let article = self
+                .article_repo
+                .get_article_by(uow.conn(), IndexedArticleField::Slug, slug)
+                .await?
+                .ok_or_else(|| AppError::BadData(format!("Article '{}' not found", slug)))?;
+
+            if article.author_id != owner_id {
+                return Err(AppError::Forbidden);
+            }
+
+            if article_ids.contains(&article.id) {
+                return Err(AppError::BadData(format!(
+                    "Article '{}' is listed more than once",
+                    slug
+                )));
+            }
+
+            if let Some(other) = self
+                .series_repo
+                .find_series_of_article(uow.conn(), article.id)
+                .await?
+                && other.id != series_id
+            {
+                return Err(AppError::DataConflict(format!(
+                    "Article '{}' already belongs to series '{}'",
+                    slug, other.slug
+                )));
+            }
+
+            article_ids.push(article.id);
+        }
+
+        Ok(article_ids)
+    }
+
+    pub async fn create_series(
+        &self,
+        command: CreateSeriesCommand,
+    ) -> Result<SeriesView, AppError> {
+        let slug = Slug::from_title(command.title.value());
+
+        let mut uow = self.outbox.begin().await?;
+
+        self.verify_slug(&mut uow, &slug).await?;
+
+        let series = self
+            .series_repo
+            .insert_series(uow.conn(), command.to_insert_params(slug))
+            .await?;
+
+        let article_ids = self
+            .resolve_articles(&mut uow, series.id, command.owner_id, &command.articles)
+            .await?;
+        self.series_repo
+            .set_series_articles(uow.conn(), series.id, &article_ids)
+            .await?;
+
+        uow.commit().await?;
+
+        self.series_repo
+            .get_series_view(&series.slug, Some(command.owner_id))
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    pub async fn get_series(
+        &self,
+        slug: &Slug,
+        user_id: Option<UserId>,
+    ) -> Result<Option<SeriesView>, AppError> {
+        self.series_repo.get_series_view(slug, user_id).await
+    }
+
+    pub async fn list_series_articles(
+        &self,
+        series_id: SeriesId,
+        user_id: Option<UserId>,
+    ) -> Result<Vec<ArticleListView>, AppError> {
+        self.article_repo
+            .list_series_articles(series_id, user_id)
+            .await
+    }
+
+    /// Where the article stands in its series, to link to the previous and next articles the
+    /// user can read.
+    pub async fn get_navigation(
+        &self,
+        article_id: ArticleId,
+        user_id: Option<UserId>,
+    ) -> Result<Option<SeriesNavigation>, AppError> {
+        self.series_repo.get_navigation(article_id, user_id).await
+    }
+
+    pub async fn update_series(
+        &self,
+        command: UpdateSeriesCommand,
+        user_id: UserId,
+    ) -> Result<SeriesView, AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let series = self
+            .series_repo
+            .get_series_by_slug(uow.conn(), &command.old_slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if series.owner_id != user_id {
+            return Err(AppError::Forbidden);
+        }
+
+        let params = command.to_params(series.id);
+
+        if params.as_list().is_empty() && command.articles.is_none() {
+            return Err(AppError::BadData("No fields to update".to_string()));
+        }
+
+        if let Some(ref slug) = params.slug
+            && *slug != series.slug
+        {
+            self.verify_slug(&mut uow, slug).await?;
+        }
+
+        let series = self.series_repo.update_series(uow.conn(), params).await?;
+
+        if let Some(ref slugs) = command.articles {
+            let article_ids = self
+                .resolve_articles(&mut uow, series.id, user_id, slugs)
+                .await?;
+            self.series_repo
+                .set_series_articles(uow.conn(), series.id, &article_ids)
+                .await?;
+        }
+
+        uow.commit().await?;
+
+        self.series_repo
+            .get_series_view(&series.slug, Some(user_id))
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// Deletes the series, its articles stay published on their own.
+    pub async fn delete_series(&self, slug: &Slug, user_id: UserId) -> Result<(), AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let series = self
+            .series_repo
+            .get_series_by_slug(uow.conn(), slug)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        if series.owner_id != user_id {
+            return Err(AppError::Forbidden);
+        }
+
+        self.series_repo
+            .delete_series(uow.conn(), series.id)
+            .await?;
+
+        uow.commit().await
+    }
+}
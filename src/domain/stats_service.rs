@@ -0,0 +1,33 @@
+use crate::app_error::AppError;
+use crate::domain::commands::get_stats_query::GetStatsQuery;
+use crate::model::persistence::article_stats_view::{ArticleStatsView, DailyArticleStatsView};
+use crate::model::values::user_id::UserId;
+use crate::persistence::stats_repository::StatsRepository;
+use anyhow::Result;
+
+#[derive(Clone)]
+pub struct StatsService {
+    stats_repo: StatsRepository,
+}
+
+impl StatsService {
+    pub fn new(stats_repo: StatsRepository) -> Self {
+        StatsService { stats_repo }
+    }
+
+    pub async fn get_article_stats(
+        &self,
+        author_id: UserId,
+    ) -> Result<Vec<ArticleStatsView>, AppError> {
+        self.stats_repo.get_article_stats(author_id).await
+    }
+
+    pub async fn get_daily_article_stats(
+        &self,
+        query: &GetStatsQuery,
+    ) -> Result<Vec<DailyArticleStatsView>, AppError> {
+        self.stats_repo
+            .get_daily_article_stats(query.user_id, query.since)
+            .await
+    }
+}
@@ -0,0 +1,31 @@
+use crate::app_config::TagsConfig;
+use crate::persistence::tag_repository::TagRepository;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, error};
+
+/// Background job deleting the tags that are no longer used by any article, e.g. after
+/// articles were retagged or deleted.
+pub struct TagCleanupJob {
+    tag_repo: TagRepository,
+    config: TagsConfig,
+}
+
+impl TagCleanupJob {
+    pub fn new(tag_repo: TagRepository, config: TagsConfig) -> Self {
+        TagCleanupJob { tag_repo, config }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(self.config.cleanup_interval_ms)).await;
+
+                match self.tag_repo.delete_orphaned_tags().await {
+                    Ok(deleted) => debug!(deleted, "Deleted orphaned tags"),
+                    Err(e) => error!(error = %e, "Failed to delete orphaned tags"),
+                }
+            }
+        })
+    }
+}
@@ -1,19 +1,153 @@
+use crate::app_config::AdminConfig;
 use crate::app_error::AppError;
+use crate::domain::commands::list_tags_query::ListTagsQuery;
+use crate::domain::commands::merge_tag_command::MergeTagCommand;
+use crate::domain::commands::update_tag_command::UpdateTagCommand;
+use crate::domain::outbox::Outbox;
+use crate::model::persistence::tag_view::TagView;
 use crate::model::values::tag_name::TagName;
+use crate::model::values::user_id::UserId;
 use crate::persistence::tag_repository::TagRepository;
 use anyhow::Result;
 
 #[derive(Clone)]
 pub struct TagService {
     tag_repo: TagRepository,
+    outbox: Outbox,
+    admin_config: AdminConfig,
 }
 
 impl TagService {
-    pub fn new(tag_repo: TagRepository) -> Self {
-        TagService { tag_repo }
+    pub fn new(tag_repo: TagRepository, outbox: Outbox, admin_config: AdminConfig) -> Self {
+        TagService {
+            tag_repo,
+            outbox,
+            admin_config,
+        }
     }
 
-    pub async fn get_all_tags(&self) -> Result<Vec<TagName>, AppError> {
-        self.tag_repo.get_all_tags().await
+    fn verify_admin(&self, user_id: UserId) -> Result<(), AppError> {
+        if self.admin_config.is_admin(user_id) {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden)
+        }
+    }
+
+    pub async fn list_tags(&self, query: ListTagsQuery) -> Result<Vec<TagView>, AppError> {
+        self.tag_repo.list_tags(query.to_params()).await
+    }
+
+    pub async fn update_tag(&self, command: UpdateTagCommand) -> Result<TagView, AppError> {
+        self.verify_admin(command.user_id)?;
+
+        let mut uow = self.outbox.begin().await?;
+
+        let tag = self
+            .tag_repo
+            .get_tag_by_name(uow.conn(), &command.name)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let params = command.to_params(tag.id);
+
+        if params.as_list().is_empty() {
+            return Err(AppError::BadData("No fields to update".to_string()));
+        }
+
+        if let Some(new_name) = command.new_name.as_ref().filter(|n| **n != tag.name)
+            && self
+                .tag_repo
+                .get_tag_by_name(uow.conn(), new_name)
+                .await?
+                .is_some()
+        {
+            return Err(AppError::DataConflict(format!(
+                "Tag '{}' already exists, merge the tags instead",
+                new_name
+            )));
+        }
+
+        self.tag_repo.update_tag(uow.conn(), params).await?;
+
+        uow.commit().await?;
+
+        self.tag_repo
+            .get_tag_view(tag.id, Some(command.user_id))
+            .await
+    }
+
+    pub async fn merge_tag(&self, command: MergeTagCommand) -> Result<TagView, AppError> {
+        self.verify_admin(command.user_id)?;
+
+        if command.source == command.target {
+            return Err(AppError::BadData(
+                "A tag can't be merged into itself".to_string(),
+            ));
+        }
+
+        let mut uow = self.outbox.begin().await?;
+
+        let source = self
+            .tag_repo
+            .get_tag_by_name(uow.conn(), &command.source)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let target = self
+            .tag_repo
+            .get_tag_by_name(uow.conn(), &command.target)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        self.tag_repo
+            .merge_tag(uow.conn(), source.id, target.id)
+            .await?;
+
+        uow.commit().await?;
+
+        self.tag_repo
+            .get_tag_view(target.id, Some(command.user_id))
+            .await
+    }
+
+    pub async fn get_followed_tags(&self, user_id: UserId) -> Result<Vec<TagName>, AppError> {
+        self.tag_repo.list_followed_tags(user_id).await
+    }
+
+    pub async fn follow_tag(&self, user_id: UserId, name: &TagName) -> Result<TagView, AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let tag = self
+            .tag_repo
+            .get_tag_by_name(uow.conn(), name)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        self.tag_repo
+            .follow_tag(uow.conn(), user_id, tag.id)
+            .await?;
+
+        uow.commit().await?;
+
+        self.tag_repo.get_tag_view(tag.id, Some(user_id)).await
+    }
+
+    pub async fn unfollow_tag(&self, user_id: UserId, name: &TagName) -> Result<TagView, AppError> {
+        let mut uow = self.outbox.begin().await?;
+
+        let tag = self
+            .tag_repo
+            .get_tag_by_name(uow.conn(), name)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        self.tag_repo
+            .unfollow_tag(uow.conn(), user_id, tag.id)
+            .await?;
+
+        uow.commit().await?;
+
+        self.tag_repo.get_tag_view(tag.id, Some(user_id)).await
     }
 }
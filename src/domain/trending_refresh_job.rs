@@ -0,0 +1,34 @@
+use crate::app_config::TrendingConfig;
+use crate::persistence::article_repository::ArticleRepository;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, error};
+
+/// Background job recomputing the trending articles ranking, so that serving it is a plain
+/// index lookup.
+pub struct TrendingRefreshJob {
+    article_repo: ArticleRepository,
+    config: TrendingConfig,
+}
+
+impl TrendingRefreshJob {
+    pub fn new(article_repo: ArticleRepository, config: TrendingConfig) -> Self {
+        TrendingRefreshJob {
+            article_repo,
+            config,
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match self.article_repo.refresh_trending_articles().await {
+                    Ok(()) => debug!("Refreshed trending articles"),
+                    Err(e) => error!(error = %e, "Failed to refresh trending articles"),
+                }
+
+                tokio::time::sleep(Duration::from_millis(self.config.refresh_interval_ms)).await;
+            }
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use crate::app_config::UploadsConfig;
+use crate::app_error::AppError;
+use crate::model::uploaded_image::UploadedImage;
+use crate::model::values::image::Image;
+use crate::storage::{Storage, StoredObject};
+use crate::utils::image_processor::process;
+use anyhow::{Context, anyhow};
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct UploadService {
+    storage: Arc<dyn Storage>,
+    config: UploadsConfig,
+}
+
+impl UploadService {
+    pub fn new(storage: Arc<dyn Storage>, config: UploadsConfig) -> Self {
+        UploadService { storage, config }
+    }
+
+    pub fn max_bytes(&self) -> usize {
+        self.config.max_bytes
+    }
+
+    /// Stores the image under a random key, next to a thumbnail, both stripped of the metadata
+    /// of the upload.
+    pub async fn upload_image(&self, bytes: Vec<u8>) -> Result<UploadedImage, AppError> {
+        if bytes.len() > self.config.max_bytes {
+            return Err(AppError::BadData(format!(
+                "Image cannot be larger than {} bytes",
+                self.config.max_bytes
+            )));
+        }
+
+        let (max_dimension, thumbnail_size) =
+            (self.config.max_dimension, self.config.thumbnail_size);
+
+        // Decoding, resizing and encoding are CPU bound, keep them off the async workers.
+        let processed =
+            tokio::task::spawn_blocking(move || process(&bytes, max_dimension, thumbnail_size))
+                .await
+                .context("Image processing panicked")?;
+        let processed = processed.map_err(AppError::BadData)?;
+
+        let id = Uuid::new_v4().simple();
+        let key = format!("{id}.{}", processed.format.extensions_str()[0]);
+        let thumbnail_key = format!(
+            "{id}-thumb.{}",
+            processed.thumbnail_format.extensions_str()[0]
+        );
+        let size = processed.image.len();
+
+        self.storage
+            .put(
+                &thumbnail_key,
+                processed.thumbnail_format.to_mime_type(),
+                processed.thumbnail,
+            )
+            .await?;
+        self.storage
+            .put(&key, processed.format.to_mime_type(), processed.image)
+            .await?;
+
+        Ok(UploadedImage {
+            url: self.image_url(&key)?,
+            thumbnail_url: self.image_url(&thumbnail_key)?,
+            content_type: processed.format.to_mime_type().to_string(),
+            size,
+            width: processed.width,
+            height: processed.height,
+        })
+    }
+
+    pub async fn get(&self, key: &str) -> Result<StoredObject, AppError> {
+        self.storage.get(key).await?.ok_or(AppError::NotFound)
+    }
+
+    fn image_url(&self, key: &str) -> Result<Image, AppError> {
+        Image::try_from(self.config.url(key))
+            .map_err(|e| anyhow!("Upload URL is not a valid image URL: {e}").into())
+    }
+}
@@ -1,7 +1,9 @@
+use crate::app_config::UsernamesConfig;
 use crate::app_error::AppError;
 use crate::domain::commands::login_command::LoginCommand;
 use crate::domain::commands::register_command::RegisterCommand;
 use crate::domain::commands::update_user_command::UpdateUserCommand;
+use crate::domain::outbox::Outbox;
 use crate::model::indexed_user_field::IndexedUserField;
 use crate::model::persistence::user::User;
 use crate::model::values::user_id::UserId;
@@ -9,33 +11,76 @@ use crate::model::values::username::Username;
 use crate::persistence::user_repository::UserRepository;
 use crate::utils::hasher::Hasher;
 use anyhow::Result;
+use chrono::{Duration, Utc};
 use tracing::log::info;
 
 #[derive(Clone)]
 pub struct UserService {
     user_repo: UserRepository,
     hasher: Hasher,
+    outbox: Outbox,
+    config: UsernamesConfig,
 }
 
 impl UserService {
-    pub fn new(user_repo: UserRepository, hasher: Hasher) -> Self {
-        UserService { user_repo, hasher }
+    pub fn new(
+        user_repo: UserRepository,
+        hasher: Hasher,
+        outbox: Outbox,
+        config: UsernamesConfig,
+    ) -> Self {
+        UserService {
+            user_repo,
+            hasher,
+            outbox,
+            config,
+        }
     }
 
-    pub async fn register_user(&self, command: RegisterCommand) -> Result<User, AppError> {
-        let password_hash = self.hasher.hash_password(&command.password)?;
+    /// Rejects invalid and reserved usernames, those of other users, current or previous, and
+    /// those looking like the current ones.
+    async fn check_username_available(
+        &self,
+        username: &Username,
+        user_id: Option<UserId>,
+    ) -> Result<(), AppError> {
+        username.validate().map_err(AppError::BadData)?;
+
+        if self.config.is_reserved(username) {
+            return Err(AppError::BadData(format!(
+                "Username '{}' is reserved",
+                username
+            )));
+        }
+
+        if self.user_repo.is_username_taken(username, user_id).await? {
+            return Err(AppError::DataConflict(format!(
+                "Username '{}' is already taken",
+                username
+            )));
+        }
 
         if self
             .user_repo
-            .get_user_by(IndexedUserField::Username, command.username.clone())
+            .is_username_confusable(username, user_id)
             .await?
-            .is_some()
         {
             return Err(AppError::DataConflict(format!(
-                "Username '{}' is already taken",
-                command.username
+                "Username '{}' is too similar to an existing one",
+                username
             )));
-        } else if self
+        }
+
+        Ok(())
+    }
+
+    pub async fn register_user(&self, command: RegisterCommand) -> Result<User, AppError> {
+        let password_hash = self.hasher.hash_password(&command.password)?;
+
+        self.check_username_available(&command.username, None)
+            .await?;
+
+        if self
             .user_repo
             .get_user_by(IndexedUserField::Email, command.email.clone())
             .await?
@@ -97,18 +142,66 @@ impl UserService {
             .await
     }
 
+    /// Previous usernames are kept so that they still lead to the user, which can change their
+    /// username once per cooldown period.
     pub(crate) async fn update_user(&self, command: UpdateUserCommand) -> Result<User, AppError> {
+        let current = self
+            .get_user_by_id(command.user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let new_username = command
+            .username
+            .as_ref()
+            .filter(|username| **username != current.username);
+
+        if let Some(username) = new_username {
+            let cooldown = Duration::days(self.config.change_cooldown_days.into());
+
+            if current
+                .username_changed_at
+                .is_some_and(|changed_at| changed_at + cooldown > Utc::now())
+            {
+                return Err(AppError::BadData(format!(
+                    "Username can only be changed once every {} days",
+                    self.config.change_cooldown_days
+                )));
+            }
+
+            self.check_username_available(username, Some(current.id))
+                .await?;
+        }
+
         let password_hash = command
             .password
             .as_ref()
             .map(|pw| self.hasher.hash_password(pw))
             .transpose()?;
 
-        let params = command.to_params(password_hash);
-        let user = self.user_repo.update_user(params).await?;
+        let params = command.to_params(password_hash, new_username.map(|_| Utc::now()));
+
+        let mut uow = self.outbox.begin().await?;
+
+        let user = self.user_repo.update_user(uow.conn(), params).await?;
+
+        if let Some(username) = new_username {
+            self.user_repo
+                .record_username_change(uow.conn(), user.id, &current.username, username)
+                .await?;
+        }
+
+        uow.commit().await?;
 
         info!("Updated user with id: {}", user.id);
 
         Ok(user)
     }
+
+    /// The current username of whoever has, or used to have, the username, ignoring case.
+    pub async fn find_current_username(
+        &self,
+        username: &Username,
+    ) -> Result<Option<Username>, AppError> {
+        self.user_repo.find_current_username(username).await
+    }
 }
@@ -0,0 +1,44 @@
+use crate::app_config::ViewsConfig;
+use crate::http::dto::stats::MAX_STATS_DAYS;
+use crate::persistence::article_repository::ArticleRepository;
+use chrono::{TimeDelta, Utc};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, error};
+
+/// Background job deleting the article views that are no longer needed, neither to count
+/// repeated views once nor for the longest stats period.
+pub struct ViewCleanupJob {
+    article_repo: ArticleRepository,
+    config: ViewsConfig,
+}
+
+impl ViewCleanupJob {
+    pub fn new(article_repo: ArticleRepository, config: ViewsConfig) -> Self {
+        ViewCleanupJob {
+            article_repo,
+            config,
+        }
+    }
+
+    fn retention(&self) -> TimeDelta {
+        let dedup_window = TimeDelta::seconds(self.config.dedup_window_secs as i64);
+
+        TimeDelta::days(i64::from(MAX_STATS_DAYS)).max(dedup_window)
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(self.config.cleanup_interval_ms)).await;
+
+                let before = Utc::now() - self.retention();
+
+                match self.article_repo.delete_article_views_before(before).await {
+                    Ok(deleted) => debug!(deleted, "Deleted old article views"),
+                    Err(e) => error!(error = %e, "Failed to delete old article views"),
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,122 @@
+use crate::app_config::ViewsConfig;
+use crate::model::values::article_id::ArticleId;
+use crate::model::viewer::Viewer;
+use crate::persistence::article_repository::ArticleRepository;
+use crate::persistence::params::insert_article_view_params::InsertArticleViewParams;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, warn};
+
+type ViewKey = (ArticleId, String, DateTime<Utc>);
+
+/// Buffers article views in memory and writes them in batches, so that reading an article
+/// doesn't wait on a write. Repeated views by the same viewer in a window are only kept once,
+/// and at most `max_pending` views are buffered.
+#[derive(Clone)]
+pub struct ViewRecorder {
+    article_repo: ArticleRepository,
+    config: ViewsConfig,
+    salt: String,
+    pending: Arc<Mutex<HashMap<ViewKey, DateTime<Utc>>>>,
+}
+
+impl ViewRecorder {
+    pub fn new(article_repo: ArticleRepository, config: ViewsConfig, salt: String) -> Self {
+        ViewRecorder {
+            article_repo,
+            config,
+            salt,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn record(&self, article_id: ArticleId, viewer: Viewer) {
+        let viewed_at = Utc::now();
+        let key = (
+            article_id,
+            viewer.key(&self.salt),
+            self.window_start(viewed_at),
+        );
+
+        let mut pending = self
+            .pending
+            .lock()
+            .expect("Pending views lock is not poisoned");
+
+        if pending.len() < self.config.max_pending || pending.contains_key(&key) {
+            pending.entry(key).or_insert(viewed_at);
+        }
+    }
+
+    fn window_start(&self, viewed_at: DateTime<Utc>) -> DateTime<Utc> {
+        let window = self.config.dedup_window_secs.max(1) as i64;
+        let timestamp = viewed_at.timestamp();
+
+        DateTime::from_timestamp(timestamp - timestamp.rem_euclid(window), 0)
+            .expect("Window start is a valid timestamp")
+    }
+
+    async fn flush(&self) {
+        let pending = std::mem::take(
+            &mut *self
+                .pending
+                .lock()
+                .expect("Pending views lock is not poisoned"),
+        );
+
+        if pending.is_empty() {
+            return;
+        }
+
+        if pending.len() >= self.config.max_pending {
+            warn!(
+                max_pending = self.config.max_pending,
+                "Article views buffer was full, later views were dropped"
+            );
+        }
+
+        let views: Vec<_> = pending
+            .iter()
+            .map(
+                |((article_id, viewer, window_start), viewed_at)| InsertArticleViewParams {
+                    article_id: *article_id,
+                    viewer: viewer.clone(),
+                    window_start: *window_start,
+                    viewed_at: *viewed_at,
+                },
+            )
+            .collect();
+
+        match self.article_repo.record_article_views(&views).await {
+            Ok(articles) => debug!(views = views.len(), articles, "Recorded article views"),
+            Err(e) => {
+                error!(error = %e, views = views.len(), "Failed to record article views");
+
+                // Keep the views for the next flush, along with those recorded in the meantime,
+                // as long as they fit.
+                let mut current = self
+                    .pending
+                    .lock()
+                    .expect("Pending views lock is not poisoned");
+                for (key, viewed_at) in pending {
+                    if current.len() < self.config.max_pending || current.contains_key(&key) {
+                        current.entry(key).or_insert(viewed_at);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(self.config.flush_interval_ms)).await;
+
+                self.flush().await;
+            }
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use crate::app_config::WebhooksConfig;
+use crate::app_error::AppError;
+use crate::model::persistence::webhook_delivery::DueWebhookDelivery;
+use crate::persistence::webhook_repository::WebhookRepository;
+use crate::utils::public_address::PublicAddressResolver;
+use crate::utils::webhook_signer::sign;
+use futures_util::future::join_all;
+use reqwest::redirect::Policy;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, warn};
+
+/// Background worker sending queued webhook deliveries, retrying failed ones with
+/// exponential backoff until `max_attempts` is reached.
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    webhook_repo: WebhookRepository,
+    client: reqwest::Client,
+    config: WebhooksConfig,
+}
+
+impl WebhookDispatcher {
+    pub fn new(webhook_repo: WebhookRepository, config: WebhooksConfig) -> Self {
+        // Redirects aren't followed, they could lead anywhere once the URL has been checked.
+        let mut client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms))
+            .redirect(Policy::none());
+
+        if !config.allow_private_hosts {
+            client = client.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+
+        let client = client.build().expect("Failed to build webhook HTTP client");
+
+        WebhookDispatcher {
+            webhook_repo,
+            client,
+            config,
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match self.dispatch_due().await {
+                    // A full batch means more deliveries are probably due already.
+                    Ok(dispatched) if dispatched as u64 == self.config.batch_size => continue,
+                    Ok(_) => {}
+                    Err(e) => error!(error = %e, "Failed to dispatch webhook deliveries"),
+                }
+
+                tokio::time::sleep(Duration::from_millis(self.config.poll_interval_ms)).await;
+            }
+        })
+    }
+
+    pub async fn dispatch_due(&self) -> Result<usize, AppError> {
+        // Claimed deliveries are hidden from other workers until the request can't be running
+        // anymore, the whole batch being sent at once.
+        let lease = Duration::from_millis(self.config.request_timeout_ms * 2);
+
+        let deliveries = self
+            .webhook_repo
+            .claim_due_deliveries(self.config.batch_size, lease)
+            .await?;
+
+        let dispatched = deliveries.len();
+
+        let results = join_all(
+            deliveries
+                .into_iter()
+                .map(|delivery| self.deliver(delivery)),
+        )
+        .await;
+
+        results.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        Ok(dispatched)
+    }
+
+    async fn deliver(&self, delivery: DueWebhookDelivery) -> Result<(), AppError> {
+        // IP addresses aren't resolved, so the resolver doesn't get to check them.
+        if !self.config.allow_private_hosts && delivery.url.has_local_host() {
+            warn!(delivery_id = %delivery.id, url = %delivery.url, "Webhook targets a local address");
+
+            return self
+                .webhook_repo
+                .mark_delivery_failed(
+                    delivery.id,
+                    None,
+                    "Webhook URL targets a local or private address".to_string(),
+                    None,
+                )
+                .await;
+        }
+
+        let body = serde_json::to_vec(&delivery.payload).expect("Payload is valid JSON");
+
+        let response = self
+            .client
+            .post(delivery.url.value())
+            .header("content-type", "application/json")
+            .header("x-realworld-event", delivery.event.value())
+            .header("x-realworld-delivery", delivery.id.to_string())
+            .header("x-realworld-signature", sign(&delivery.secret, &body))
+            .body(body)
+            .send()
+            .await;
+
+        let (response_status, error) = match response {
+            Ok(response) if response.status().is_success() => {
+                debug!(delivery_id = %delivery.id, url = %delivery.url, "Webhook delivered");
+
+                return self
+                    .webhook_repo
+                    .mark_delivery_succeeded(delivery.id, response.status().as_u16() as i32)
+                    .await;
+            }
+            Ok(response) => (
+                Some(response.status().as_u16() as i32),
+                format!("Unexpected response status: {}", response.status()),
+            ),
+            Err(e) => (None, e.to_string()),
+        };
+
+        let retry_in = self.retry_delay(delivery.attempts + 1);
+
+        warn!(
+            delivery_id = %delivery.id,
+            url = %delivery.url,
+            attempt = delivery.attempts + 1,
+            retry_in = ?retry_in,
+            error = %error,
+            "Webhook delivery failed"
+        );
+
+        self.webhook_repo
+            .mark_delivery_failed(delivery.id, response_status, error, retry_in)
+            .await
+    }
+
+    fn retry_delay(&self, attempts: i32) -> Option<Duration> {
+        if attempts as u32 >= self.config.max_attempts {
+            return None;
+        }
+
+        let exponent = (attempts - 1).clamp(0, 31) as u32;
+        let delay = self
+            .config
+            .retry_base_delay_ms
+            .saturating_mul(2u64.pow(exponent))
+            .min(self.config.retry_max_delay_ms);
+
+        Some(Duration::from_millis(delay))
+    }
+}
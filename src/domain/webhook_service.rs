@@ -0,0 +1,345 @@
+use crate::app_config::WebhooksConfig;
+use crate::app_error::AppError;
+use crate::domain::commands::create_webhook_command::CreateWebhookCommand;
+use crate::domain::commands::list_webhook_deliveries_query::ListWebhookDeliveriesQuery;
+use crate::domain::outbox::UnitOfWork;
+use crate::events::app_event::AppEvent;
+use crate::events::event_sink::EventSink;
+use crate::model::indexed_article_field::IndexedArticleField;
+use crate::model::indexed_user_field::IndexedUserField;
+use crate::model::persistence::article_view::ArticleView;
+use crate::model::persistence::outbox_event::OutboxEvent;
+use crate::model::persistence::user::User;
+use crate::model::persistence::webhook::Webhook;
+use crate::model::persistence::webhook_delivery::WebhookDelivery;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::user_id::UserId;
+use crate::model::values::webhook_event::WebhookEvent;
+use crate::model::values::webhook_id::WebhookId;
+use crate::persistence::article_repository::ArticleRepository;
+use crate::persistence::comment_repository::CommentRepository;
+use crate::persistence::user_repository::UserRepository;
+use crate::persistence::webhook_repository::WebhookRepository;
+use crate::utils::webhook_signer::generate_secret;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+
+fn user_payload(user: &User) -> Value {
+    json!({
+        "username": user.username,
+        "bio": user.bio,
+        "image": user.image,
+    })
+}
+
+fn article_payload(article: &ArticleView) -> Value {
+    json!({
+        "slug": article.slug,
+        "title": article.title,
+        "description": article.description,
+        "body": article.body,
+        "tagList": article.tag_list,
+        "createdAt": article.created_at,
+        "updatedAt": article.updated_at,
+        "author": {
+            "username": article.author,
+            "bio": article.author_bio,
+            "image": article.author_image,
+        },
+    })
+}
+
+/// What to send about an event, and to whose webhooks.
+struct Delivery {
+    data: Value,
+    user_ids: Vec<UserId>,
+    /// Followers of this user are told too.
+    followed_id: Option<UserId>,
+}
+
+impl Delivery {
+    /// Events about an author's articles go to them and their followers, who can all read them.
+    fn about_author(author_id: UserId, data: Value) -> Self {
+        Delivery {
+            data,
+            user_ids: vec![author_id],
+            followed_id: Some(author_id),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct WebhookService {
+    webhook_repo: WebhookRepository,
+    article_repo: ArticleRepository,
+    comment_repo: CommentRepository,
+    user_repo: UserRepository,
+    config: WebhooksConfig,
+}
+
+impl WebhookService {
+    pub fn new(
+        webhook_repo: WebhookRepository,
+        article_repo: ArticleRepository,
+        comment_repo: CommentRepository,
+        user_repo: UserRepository,
+        config: WebhooksConfig,
+    ) -> Self {
+        WebhookService {
+            webhook_repo,
+            article_repo,
+            comment_repo,
+            user_repo,
+            config,
+        }
+    }
+
+    pub async fn create_webhook(&self, command: CreateWebhookCommand) -> Result<Webhook, AppError> {
+        if command.events.is_empty() {
+            return Err(AppError::BadData(
+                "Webhook must subscribe to at least one event".to_string(),
+            ));
+        }
+
+        // Names resolving to private addresses are refused when delivering.
+        if !self.config.allow_private_hosts && command.url.has_local_host() {
+            return Err(AppError::BadData(
+                "Webhook URL cannot target a local or private address".to_string(),
+            ));
+        }
+
+        self.webhook_repo
+            .insert_webhook(command.to_insert_params(generate_secret()))
+            .await
+    }
+
+    pub async fn list_webhooks(&self, user_id: UserId) -> Result<Vec<Webhook>, AppError> {
+        self.webhook_repo.list_webhooks(user_id).await
+    }
+
+    pub async fn delete_webhook(
+        &self,
+        user_id: UserId,
+        webhook_id: WebhookId,
+    ) -> Result<(), AppError> {
+        if self
+            .webhook_repo
+            .delete_webhook(user_id, webhook_id)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(AppError::NotFound)
+        }
+    }
+
+    async fn verify_owner(&self, user_id: UserId, webhook_id: WebhookId) -> Result<(), AppError> {
+        self.webhook_repo
+            .get_webhook(user_id, webhook_id)
+            .await?
+            .map(|_| ())
+            .ok_or(AppError::NotFound)
+    }
+
+    pub async fn list_deliveries(
+        &self,
+        query: ListWebhookDeliveriesQuery,
+    ) -> Result<Vec<WebhookDelivery>, AppError> {
+        self.verify_owner(query.user_id, query.webhook_id).await?;
+
+        self.webhook_repo
+            .list_deliveries(query.webhook_id, query.limit, query.offset)
+            .await
+    }
+
+    pub async fn count_deliveries(
+        &self,
+        user_id: UserId,
+        webhook_id: WebhookId,
+    ) -> Result<u64, AppError> {
+        self.verify_owner(user_id, webhook_id).await?;
+
+        self.webhook_repo.count_deliveries(webhook_id).await
+    }
+
+    async fn enqueue(
+        &self,
+        uow: &mut UnitOfWork,
+        event: WebhookEvent,
+        occurred_at: DateTime<Utc>,
+        delivery: Delivery,
+    ) -> Result<(), AppError> {
+        let payload = json!({
+            "event": event,
+            "createdAt": occurred_at,
+            "data": delivery.data,
+        });
+
+        self.webhook_repo
+            .enqueue_deliveries(
+                uow.conn(),
+                event,
+                payload,
+                &delivery.user_ids,
+                delivery.followed_id,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Articles and comments are looked up when the event is published, nothing is sent for
+    /// ones that are gone by then.
+    ///
+    /// They're looked up as their author sees them, only the author and their followers being
+    /// told about them, who can read them even when the author is private.
+    async fn find_article(
+        &self,
+        uow: &mut UnitOfWork,
+        article_id: ArticleId,
+    ) -> Result<Option<ArticleView>, AppError> {
+        let Some(article) = self
+            .article_repo
+            .get_article_by(uow.conn(), IndexedArticleField::Id, article_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        self.article_repo
+            .find_article_view(uow.conn(), article_id, Some(article.author_id))
+            .await
+    }
+
+    async fn article_payload(
+        &self,
+        uow: &mut UnitOfWork,
+        article_id: ArticleId,
+    ) -> Result<Option<Delivery>, AppError> {
+        Ok(self.find_article(uow, article_id).await?.map(|article| {
+            Delivery::about_author(
+                article.author_id,
+                json!({ "article": article_payload(&article) }),
+            )
+        }))
+    }
+
+    async fn comment_payload(
+        &self,
+        uow: &mut UnitOfWork,
+        article_id: ArticleId,
+        comment_id: CommentId,
+    ) -> Result<Option<Delivery>, AppError> {
+        let Some(article) = self.find_article(uow, article_id).await? else {
+            return Ok(None);
+        };
+
+        let Some(comment) = self
+            .comment_repo
+            .find_comment(uow.conn(), comment_id, None)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Delivery::about_author(
+            article.author_id,
+            json!({
+                "article": { "slug": article.slug, "title": article.title },
+                "comment": {
+                    "id": comment.id,
+                    "body": comment.body,
+                    "createdAt": comment.created_at,
+                    "author": {
+                        "username": comment.author,
+                        "bio": comment.author_bio,
+                        "image": comment.author_image,
+                    },
+                },
+            }),
+        )))
+    }
+
+    async fn follow_payload(
+        &self,
+        uow: &mut UnitOfWork,
+        follower_id: UserId,
+        followee_id: UserId,
+    ) -> Result<Option<Delivery>, AppError> {
+        let follower = self
+            .user_repo
+            .find_user_by(uow.conn(), IndexedUserField::Id, follower_id)
+            .await?;
+        let followee = self
+            .user_repo
+            .find_user_by(uow.conn(), IndexedUserField::Id, followee_id)
+            .await?;
+
+        Ok(follower.zip(followee).map(|(follower, followee)| Delivery {
+            data: json!({
+                "follower": user_payload(&follower),
+                "followee": user_payload(&followee),
+            }),
+            user_ids: vec![follower_id, followee_id],
+            followed_id: None,
+        }))
+    }
+}
+
+#[async_trait]
+impl EventSink for WebhookService {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn publish(&self, uow: &mut UnitOfWork, event: &OutboxEvent) -> Result<(), AppError> {
+        let (webhook_event, delivery) = match &event.event {
+            AppEvent::ArticleCreated { article_id } => (
+                WebhookEvent::ArticleCreated,
+                self.article_payload(uow, *article_id).await?,
+            ),
+            AppEvent::ArticleUpdated { article_id } => (
+                WebhookEvent::ArticleUpdated,
+                self.article_payload(uow, *article_id).await?,
+            ),
+            AppEvent::ArticleDeleted {
+                author_id,
+                slug,
+                title,
+                ..
+            } => (
+                WebhookEvent::ArticleDeleted,
+                Some(Delivery::about_author(
+                    *author_id,
+                    json!({ "article": { "slug": slug, "title": title } }),
+                )),
+            ),
+            AppEvent::CommentCreated {
+                article_id,
+                comment_id,
+            } => (
+                WebhookEvent::CommentCreated,
+                self.comment_payload(uow, *article_id, *comment_id).await?,
+            ),
+            AppEvent::UserFollowed {
+                follower_id,
+                followee_id,
+            } => (
+                WebhookEvent::UserFollowed,
+                self.follow_payload(uow, *follower_id, *followee_id).await?,
+            ),
+            _ => return Ok(()),
+        };
+
+        match delivery {
+            Some(delivery) => {
+                self.enqueue(uow, webhook_event, event.created_at, delivery)
+                    .await
+            }
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::notification_id::NotificationId;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AppEvent {
+    ArticleCreated {
+        article_id: ArticleId,
+    },
+    ArticleUpdated {
+        article_id: ArticleId,
+    },
+    /// Keeps what's left to tell about the article once it's gone.
+    ArticleDeleted {
+        article_id: ArticleId,
+        author_id: UserId,
+        slug: Slug,
+        title: ArticleTitle,
+    },
+    CommentCreated {
+        article_id: ArticleId,
+        comment_id: CommentId,
+    },
+    CommentDeleted {
+        article_id: ArticleId,
+        comment_id: CommentId,
+    },
+    FavoritesChanged {
+        article_id: ArticleId,
+        favorites_count: i64,
+    },
+    UserFollowed {
+        follower_id: UserId,
+        followee_id: UserId,
+    },
+    NotificationCreated {
+        recipient_id: UserId,
+        notification_id: NotificationId,
+    },
+}
+
+impl AppEvent {
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            AppEvent::ArticleCreated { .. } => "articleCreated",
+            AppEvent::ArticleUpdated { .. } => "articleUpdated",
+            AppEvent::ArticleDeleted { .. } => "articleDeleted",
+            AppEvent::CommentCreated { .. } => "commentCreated",
+            AppEvent::CommentDeleted { .. } => "commentDeleted",
+            AppEvent::FavoritesChanged { .. } => "favoritesChanged",
+            AppEvent::UserFollowed { .. } => "userFollowed",
+            AppEvent::NotificationCreated { .. } => "notificationCreated",
+        }
+    }
+
+    pub fn article_id(&self) -> Option<ArticleId> {
+        match self {
+            AppEvent::ArticleCreated { article_id }
+            | AppEvent::ArticleUpdated { article_id }
+            | AppEvent::ArticleDeleted { article_id, .. }
+            | AppEvent::CommentCreated { article_id, .. }
+            | AppEvent::CommentDeleted { article_id, .. }
+            | AppEvent::FavoritesChanged { article_id, .. } => Some(*article_id),
+            AppEvent::UserFollowed { .. } | AppEvent::NotificationCreated { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::app_config::{EventsBackend, EventsConfig};
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::domain::outbox::UnitOfWork;
+use crate::events::app_event::AppEvent;
+use crate::events::event_sink::EventSink;
+use crate::model::persistence::outbox_event::OutboxEvent;
+use async_trait::async_trait;
+use sea_query::{Func, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+use sqlx::postgres::PgListener;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tracing::{error, info, warn};
+
+const CHANNEL: &str = "realworld_events";
+
+#[derive(Clone)]
+enum Backend {
+    Memory,
+    Postgres(Database),
+}
+
+/// Fans out [`AppEvent`]s to all subscribers of this instance.
+///
+/// With the Postgres backend events are published through `NOTIFY` and every instance
+/// (including the publishing one) forwards them to its local subscribers from a `LISTEN` task.
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<AppEvent>,
+    backend: Backend,
+}
+
+impl EventBus {
+    pub fn in_memory(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+
+        EventBus {
+            sender,
+            backend: Backend::Memory,
+        }
+    }
+
+    pub async fn postgres(database: Database, capacity: usize) -> Result<Self, sqlx::Error> {
+        let (sender, _) = broadcast::channel(capacity);
+
+        let mut listener = PgListener::connect_with(database.pool()).await?;
+        listener.listen(CHANNEL).await?;
+
+        let forward_to = sender.downgrade();
+
+        tokio::spawn(async move {
+            loop {
+                let notification = match listener.recv().await {
+                    Ok(notification) => notification,
+                    Err(e) => {
+                        error!(error = %e, "Failed to receive event notification");
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        continue;
+                    }
+                };
+
+                let Some(sender) = forward_to.upgrade() else {
+                    info!("Event bus dropped, stopping event listener");
+                    break;
+                };
+
+                match serde_json::from_str::<AppEvent>(notification.payload()) {
+                    Ok(event) => {
+                        let _ = sender.send(event);
+                    }
+                    Err(e) => warn!(error = %e, "Ignoring malformed event notification"),
+                }
+            }
+        });
+
+        Ok(EventBus {
+            sender,
+            backend: Backend::Postgres(database),
+        })
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<AppEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Delivery is best effort: failures are logged and never fail the operation that
+    /// produced the event.
+    pub async fn publish(&self, event: AppEvent) {
+        match &self.backend {
+            Backend::Memory => {
+                // An error only means nobody is subscribed right now.
+                let _ = self.sender.send(event);
+            }
+            Backend::Postgres(database) => {
+                let payload = serde_json::to_string(&event).expect("Events are serializable");
+
+                let (sql, values) = Query::select()
+                    .expr(Func::cust("pg_notify").args([CHANNEL.into(), payload.into()]))
+                    .build_sqlx(PostgresQueryBuilder);
+
+                if let Err(e) = sqlx::query_with(&sql, values)
+                    .execute(database.pool())
+                    .await
+                {
+                    warn!(error = %e, event = ?event, "Failed to publish event");
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventSink for EventBus {
+    fn name(&self) -> &'static str {
+        "event_bus"
+    }
+
+    fn is_transactional(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _uow: &mut UnitOfWork, event: &OutboxEvent) -> Result<(), AppError> {
+        EventBus::publish(self, event.event.clone()).await;
+
+        Ok(())
+    }
+}
+
+pub async fn create_event_bus(
+    config: &EventsConfig,
+    database: Database,
+) -> Result<EventBus, sqlx::Error> {
+    match config.backend {
+        EventsBackend::Memory => Ok(EventBus::in_memory(config.channel_capacity)),
+        EventsBackend::Postgres => EventBus::postgres(database, config.channel_capacity).await,
+    }
+}
@@ -0,0 +1,24 @@
+use crate::app_error::AppError;
+use crate::domain::outbox::UnitOfWork;
+use crate::model::persistence::outbox_event::OutboxEvent;
+use async_trait::async_trait;
+
+/// A destination for committed events, fed by the outbox dispatcher.
+///
+/// Events are delivered in commit order. Transactional sinks get them at least once; a sink
+/// failing an event makes the dispatcher retry it on every transactional sink later, so they
+/// should tolerate duplicates. What they write through the unit of work commits along with the
+/// event being marked as processed, and is undone if any of them fails it.
+///
+/// Other sinks, e.g. ones broadcasting to connected clients, can't take back what they did.
+/// They only get an event once every transactional sink handled it, and aren't retried.
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn is_transactional(&self) -> bool {
+        true
+    }
+
+    async fn publish(&self, uow: &mut UnitOfWork, event: &OutboxEvent) -> Result<(), AppError>;
+}
@@ -0,0 +1,3 @@
+pub mod app_event;
+pub mod event_bus;
+pub mod event_sink;
@@ -1,13 +1,22 @@
 use crate::http::dto::profile::Profile;
+use crate::http::dto::reaction::ReactionItem;
+use crate::http::dto::series::ArticleSeriesItem;
+use crate::model::article_sort::{ArticleSort, SortOrder, TagMatch};
+use crate::model::body_format::BodyFormat;
+use crate::model::comma_separated::CommaSeparated;
+use crate::model::feed_source::FeedSource;
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
 use crate::model::persistence::article_view::{ArticleListView, ArticleView};
+use crate::model::persistence::series_navigation::SeriesNavigation;
+use crate::model::trending_window::TrendingWindow;
 use crate::model::values::article_body::ArticleBody;
 use crate::model::values::article_description::ArticleDescription;
 use crate::model::values::article_title::ArticleTitle;
 use crate::model::values::slug::Slug;
 use crate::model::values::tag_name::TagName;
 use crate::model::values::username::Username;
+use crate::utils::markdown::{RenderedMarkdown, TocEntry};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -37,9 +46,53 @@ pub struct ArticleItem {
     #[serde(rename = "updatedAt")]
     pub updated_at: DateTime<Utc>,
     pub favorited: bool,
+    /// Same as the count of `like` reactions.
     #[serde(rename = "favoritesCount")]
     pub favorites_count: i64,
+    /// Only the kinds of reactions the article received.
+    pub reactions: Vec<ReactionItem>,
+    #[serde(rename = "commentsCount")]
+    pub comments_count: i64,
+    /// New comments are rejected while the author or a moderator keeps them locked.
+    #[serde(rename = "commentsLocked")]
+    pub comments_locked: bool,
+    pub bookmarked: bool,
+    #[serde(rename = "viewsCount")]
+    pub views_count: i64,
     pub author: Profile,
+    /// Users allowed to update the article along with its author.
+    #[serde(rename = "coAuthors")]
+    pub co_authors: Vec<Profile>,
+    /// The body rendered to sanitized HTML, only when reading the article with `format=html`.
+    #[serde(rename = "bodyHtml", skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    /// Only when reading the article.
+    #[serde(rename = "readingTimeMinutes", skip_serializing_if = "Option::is_none")]
+    pub reading_time_minutes: Option<u32>,
+    /// Headings of the body, only when reading the article.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub toc: Option<Vec<TocItem>>,
+    /// Only when reading an article that belongs to a series.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<ArticleSeriesItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TocItem {
+    pub level: u8,
+    pub text: String,
+    /// Id of the heading in `bodyHtml`.
+    pub anchor: String,
+}
+
+impl TocItem {
+    fn from_toc_entry(entry: &TocEntry) -> TocItem {
+        TocItem {
+            level: entry.level,
+            text: entry.text.clone(),
+            anchor: entry.anchor.clone(),
+        }
+    }
 }
 
 impl ArticleItem {
@@ -54,13 +107,58 @@ impl ArticleItem {
             updated_at: view.updated_at,
             favorited: view.favorited,
             favorites_count: view.favorites_count,
-            author: Profile {
-                username: view.author.clone(),
-                bio: view.author_bio.clone(),
-                image: view.author_image.clone(),
-                following: view.following,
-            },
+            reactions: view
+                .reactions
+                .iter()
+                .map(ReactionItem::from_reaction_count)
+                .collect(),
+            comments_count: view.comments_count,
+            comments_locked: view.comments_locked,
+            bookmarked: view.bookmarked,
+            views_count: view.views_count,
+            author: Profile::new(
+                view.author.clone(),
+                view.author_bio.clone(),
+                view.author_image.clone(),
+                view.following,
+            ),
+            co_authors: view
+                .co_authors
+                .iter()
+                .map(|co_author| {
+                    Profile::new(
+                        co_author.username.clone(),
+                        co_author.bio.clone(),
+                        co_author.image.clone(),
+                        co_author.following,
+                    )
+                })
+                .collect(),
+            body_html: None,
+            reading_time_minutes: None,
+            toc: None,
+            series: None,
+        }
+    }
+
+    pub(crate) fn with_rendered_body(
+        mut self,
+        rendered: &RenderedMarkdown,
+        format: BodyFormat,
+    ) -> ArticleItem {
+        if format == BodyFormat::Html {
+            self.body_html = Some(rendered.html.clone());
         }
+        self.reading_time_minutes = Some(rendered.reading_time_minutes);
+        self.toc = Some(rendered.toc.iter().map(TocItem::from_toc_entry).collect());
+
+        self
+    }
+
+    pub(crate) fn with_series(mut self, navigation: Option<&SeriesNavigation>) -> ArticleItem {
+        self.series = navigation.map(ArticleSeriesItem::from_navigation);
+
+        self
     }
 }
 
@@ -78,6 +176,9 @@ pub struct ArticleListItem {
     pub favorited: bool,
     #[serde(rename = "favoritesCount")]
     pub favorites_count: i64,
+    #[serde(rename = "commentsCount")]
+    pub comments_count: i64,
+    pub bookmarked: bool,
     pub author: Profile,
 }
 
@@ -92,12 +193,14 @@ impl ArticleListItem {
             updated_at: view.updated_at,
             favorited: view.favorited,
             favorites_count: view.favorites_count,
-            author: Profile {
-                username: view.author.clone(),
-                bio: view.author_bio.clone(),
-                image: view.author_image.clone(),
-                following: view.following,
-            },
+            comments_count: view.comments_count,
+            bookmarked: view.bookmarked,
+            author: Profile::new(
+                view.author.clone(),
+                view.author_bio.clone(),
+                view.author_image.clone(),
+                view.following,
+            ),
         }
     }
 }
@@ -129,19 +232,104 @@ pub struct UpdateArticleQuery {
     pub description: Option<ArticleDescription>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<ArticleBody>,
+    /// Replaces the whole tag list, can't be combined with `addTags` or `removeTags`.
+    #[serde(rename = "tagList", skip_serializing_if = "Option::is_none")]
+    pub tag_list: Option<Vec<TagName>>,
+    #[serde(rename = "addTags", skip_serializing_if = "Option::is_none")]
+    pub add_tags: Option<Vec<TagName>>,
+    #[serde(rename = "removeTags", skip_serializing_if = "Option::is_none")]
+    pub remove_tags: Option<Vec<TagName>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+#[serde(rename_all = "camelCase")]
 pub struct ArticleListQuery {
     pub tag: Option<TagName>,
+    /// Comma separated tags, matched according to `tagMode`.
+    #[schema(value_type = Option<String>)]
+    #[param(value_type = Option<String>)]
+    pub tags: Option<CommaSeparated<TagName>>,
+    pub tag_mode: Option<TagMatch>,
+    /// Comma separated tags, articles having any of them are left out.
+    #[schema(value_type = Option<String>)]
+    #[param(value_type = Option<String>)]
+    pub exclude_tags: Option<CommaSeparated<TagName>>,
     pub author: Option<Username>,
     pub favorited: Option<Username>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub sort: Option<ArticleSort>,
+    pub order: Option<SortOrder>,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl ArticleListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if let Some(limit) = &self.limit {
+            limit.validate()?;
+        }
+
+        if let (Some(after), Some(before)) = (self.created_after, self.created_before)
+            && after >= before
+        {
+            return Err("createdAfter must be earlier than createdBefore".to_string());
+        }
+
+        if let Some(excluded) = &self.exclude_tags {
+            let excluded = excluded.as_slice();
+            let mut included = self
+                .tag
+                .iter()
+                .chain(self.tags.iter().flat_map(|t| t.as_slice()));
+
+            if let Some(tag) = included.find(|tag| excluded.contains(tag)) {
+                return Err(format!("Tag '{}' can't be both included and excluded", tag));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct ArticleQuery {
+    pub format: Option<BodyFormat>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct ArticleTrendingListQuery {
+    pub window: Option<TrendingWindow>,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
 }
 
+impl ArticleTrendingListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.limit.as_ref().map_or(Ok(()), Limit::validate)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct ArticleRelatedListQuery {
+    pub limit: Option<Limit>,
+}
+
+impl ArticleRelatedListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.limit.as_ref().map_or(Ok(()), Limit::validate)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
 pub struct ArticleFeedListQuery {
+    pub source: Option<FeedSource>,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
 }
+
+impl ArticleFeedListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.limit.as_ref().map_or(Ok(()), Limit::validate)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::http::dto::article::ArticleListItem;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::bookmark_view::BookmarkView;
+use crate::model::values::bookmark_folder::BookmarkFolder;
+use crate::model::values::bookmark_note::BookmarkNote;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct BookmarkRequest {
+    #[serde(default)]
+    pub bookmark: BookmarkData,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct BookmarkData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder: Option<BookmarkFolder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<BookmarkNote>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BookmarksResponse {
+    pub bookmarks: Vec<BookmarkItem>,
+    #[serde(rename = "bookmarksCount")]
+    pub bookmarks_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BookmarkItem {
+    pub folder: Option<BookmarkFolder>,
+    pub note: Option<BookmarkNote>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    pub article: ArticleListItem,
+}
+
+impl BookmarkItem {
+    pub fn from_bookmark_view(view: &BookmarkView) -> BookmarkItem {
+        BookmarkItem {
+            folder: view.folder.clone(),
+            note: view.note.clone(),
+            created_at: view.bookmarked_at,
+            article: ArticleListItem::from_article_view(&view.article),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct BookmarkListQuery {
+    pub folder: Option<BookmarkFolder>,
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl BookmarkListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.limit.as_ref().map_or(Ok(()), Limit::validate)
+    }
+}
@@ -1,4 +1,7 @@
 use crate::http::dto::profile::Profile;
+use crate::http::dto::reaction::ReactionItem;
+use crate::model::comment_sort::CommentSort;
+use crate::model::limit::Limit;
 use crate::model::persistence::comment_view::CommentView;
 use crate::model::values::comment_body::CommentBody;
 use crate::model::values::comment_id::CommentId;
@@ -14,6 +17,9 @@ pub struct CommentResponse {
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CommentsResponse {
     pub comments: Vec<CommentItem>,
+    /// Pass as `cursor` to get the next page, null on the last page.
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<CommentId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -25,21 +31,30 @@ pub struct CommentItem {
     pub updated_at: DateTime<Utc>,
     pub body: CommentBody,
     pub author: Profile,
+    /// Only the kinds of reactions the comment received.
+    pub reactions: Vec<ReactionItem>,
 }
 
 impl CommentItem {
     pub fn from_comment_view(view: CommentView) -> CommentItem {
+        let reactions = view
+            .reactions
+            .iter()
+            .map(ReactionItem::from_reaction_count)
+            .collect();
+
         CommentItem {
             id: view.id,
             created_at: view.created_at,
             updated_at: view.updated_at,
             body: view.body,
-            author: Profile {
-                username: view.author,
-                bio: view.author_bio,
-                image: view.author_image,
-                following: view.following,
-            },
+            author: Profile::new(
+                view.author,
+                view.author_bio,
+                view.author_image,
+                view.following,
+            ),
+            reactions,
         }
     }
 }
@@ -53,3 +68,17 @@ pub struct CreateCommentRequest {
 pub struct CreateComment {
     pub body: CommentBody,
 }
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct CommentListQuery {
+    pub sort: Option<CommentSort>,
+    /// The `nextCursor` of the previous page.
+    pub cursor: Option<CommentId>,
+    pub limit: Option<Limit>,
+}
+
+impl CommentListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.limit.as_ref().map_or(Ok(()), Limit::validate)
+    }
+}
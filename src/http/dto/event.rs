@@ -0,0 +1,14 @@
+use crate::model::values::comment_id::CommentId;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CommentDeletedEvent {
+    pub id: CommentId,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FavoritesChangedEvent {
+    #[serde(rename = "favoritesCount")]
+    pub favorites_count: i64,
+}
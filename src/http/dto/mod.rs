@@ -1,8 +1,16 @@
 pub mod article;
+pub mod bookmark;
 pub mod comment;
 pub mod error;
+pub mod event;
 pub mod login;
+pub mod notification;
 pub mod profile;
+pub mod reaction;
 pub mod register;
+pub mod series;
+pub mod stats;
 pub mod tag;
+pub mod upload;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,119 @@
+use crate::http::dto::profile::Profile;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::notification_preferences::NotificationPreferences;
+use crate::model::persistence::notification_view::NotificationView;
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::notification_id::NotificationId;
+use crate::model::values::notification_kind::NotificationKind;
+use crate::model::values::slug::Slug;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationsResponse {
+    pub notifications: Vec<NotificationItem>,
+    #[serde(rename = "notificationsCount")]
+    pub notifications_count: u64,
+    #[serde(rename = "unreadCount")]
+    pub unread_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationResponse {
+    pub notification: NotificationItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationItem {
+    pub id: NotificationId,
+    #[serde(rename = "type")]
+    pub kind: NotificationKind,
+    pub read: bool,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    pub actor: Profile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub article: Option<NotificationArticle>,
+    #[serde(rename = "commentId", skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<CommentId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationArticle {
+    pub slug: Slug,
+    pub title: ArticleTitle,
+}
+
+impl NotificationItem {
+    pub fn from_notification_view(view: NotificationView) -> NotificationItem {
+        let article = match (view.article_slug, view.article_title) {
+            (Some(slug), Some(title)) => Some(NotificationArticle { slug, title }),
+            _ => None,
+        };
+
+        NotificationItem {
+            id: view.id,
+            kind: view.kind,
+            read: view.read_at.is_some(),
+            created_at: view.created_at,
+            actor: Profile::new(view.actor, view.actor_bio, view.actor_image, view.following),
+            article,
+            comment_id: view.comment_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct NotificationListQuery {
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl NotificationListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.limit.as_ref().map_or(Ok(()), Limit::validate)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferencesResponse {
+    pub preferences: NotificationPreferencesItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferencesItem {
+    #[serde(rename = "articleCommented")]
+    pub article_commented: bool,
+    #[serde(rename = "articleFavorited")]
+    pub article_favorited: bool,
+    #[serde(rename = "userFollowed")]
+    pub user_followed: bool,
+}
+
+impl NotificationPreferencesItem {
+    pub fn from_preferences(preferences: NotificationPreferences) -> NotificationPreferencesItem {
+        NotificationPreferencesItem {
+            article_commented: preferences.article_commented,
+            article_favorited: preferences.article_favorited,
+            user_followed: preferences.user_followed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateNotificationPreferencesRequest {
+    pub preferences: UpdateNotificationPreferences,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateNotificationPreferences {
+    #[serde(rename = "articleCommented", skip_serializing_if = "Option::is_none")]
+    pub article_commented: Option<bool>,
+    #[serde(rename = "articleFavorited", skip_serializing_if = "Option::is_none")]
+    pub article_favorited: Option<bool>,
+    #[serde(rename = "userFollowed", skip_serializing_if = "Option::is_none")]
+    pub user_followed: Option<bool>,
+}
@@ -1,4 +1,6 @@
-use crate::model::persistence::user::User;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::profile_view::ProfileView;
 use crate::model::values::bio::Bio;
 use crate::model::values::image::Image;
 use crate::model::values::username::Username;
@@ -16,15 +18,81 @@ pub struct Profile {
     pub bio: Option<Bio>,
     pub image: Option<Image>,
     pub following: bool,
+    /// Counters are only included when the profile is the subject of the response, not when
+    /// it's embedded as the author of an article or comment
+    #[serde(rename = "followersCount", skip_serializing_if = "Option::is_none")]
+    pub followers_count: Option<i64>,
+    #[serde(rename = "followingCount", skip_serializing_if = "Option::is_none")]
+    pub following_count: Option<i64>,
+    #[serde(rename = "articlesCount", skip_serializing_if = "Option::is_none")]
+    pub articles_count: Option<i64>,
+    /// Whether the current user blocks this profile, included along with the counters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocking: Option<bool>,
+    /// Whether the current user mutes this profile, included along with the counters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub muting: Option<bool>,
+    /// Whether following this profile requires its approval, included along with the counters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    /// Whether the current user waits for this profile to approve their follow, included
+    /// along with the counters
+    #[serde(rename = "followRequested", skip_serializing_if = "Option::is_none")]
+    pub follow_requested: Option<bool>,
 }
 
 impl Profile {
-    pub fn from_user(user: User, following: bool) -> Profile {
+    /// A profile without counters, as embedded in articles, comments and notifications.
+    pub fn new(
+        username: Username,
+        bio: Option<Bio>,
+        image: Option<Image>,
+        following: bool,
+    ) -> Profile {
         Profile {
-            username: user.username,
-            bio: user.bio,
-            image: user.image,
+            username,
+            bio,
+            image,
             following,
+            followers_count: None,
+            following_count: None,
+            articles_count: None,
+            blocking: None,
+            muting: None,
+            private: None,
+            follow_requested: None,
         }
     }
+
+    pub fn from_profile_view(view: ProfileView) -> Profile {
+        Profile {
+            followers_count: Some(view.followers_count),
+            following_count: Some(view.following_count),
+            articles_count: Some(view.articles_count),
+            blocking: Some(view.blocking),
+            muting: Some(view.muting),
+            private: Some(view.private),
+            follow_requested: Some(view.follow_requested),
+            ..Profile::new(view.username, view.bio, view.image, view.following)
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProfilesResponse {
+    pub profiles: Vec<Profile>,
+    #[serde(rename = "profilesCount")]
+    pub profiles_count: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct FollowListQuery {
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl FollowListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.limit.as_ref().map_or(Ok(()), Limit::validate)
+    }
 }
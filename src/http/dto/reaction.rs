@@ -0,0 +1,22 @@
+use crate::model::persistence::reaction_count::ReactionCount;
+use crate::model::values::reaction_kind::ReactionKind;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReactionItem {
+    pub kind: ReactionKind,
+    pub count: i64,
+    /// Whether the current user reacted so.
+    pub reacted: bool,
+}
+
+impl ReactionItem {
+    pub(crate) fn from_reaction_count(reaction: &ReactionCount) -> ReactionItem {
+        ReactionItem {
+            kind: reaction.kind,
+            count: reaction.count,
+            reacted: reaction.reacted,
+        }
+    }
+}
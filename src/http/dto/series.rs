@@ -0,0 +1,132 @@
+use crate::http::dto::article::ArticleListItem;
+use crate::http::dto::profile::Profile;
+use crate::model::persistence::article_view::ArticleListView;
+use crate::model::persistence::series_navigation::{SeriesNavigation, SeriesNeighbor};
+use crate::model::persistence::series_view::SeriesView;
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::series_description::SeriesDescription;
+use crate::model::values::series_title::SeriesTitle;
+use crate::model::values::slug::Slug;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeriesResponse {
+    pub series: SeriesItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeriesItem {
+    pub slug: Slug,
+    pub title: SeriesTitle,
+    pub description: Option<SeriesDescription>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+    pub author: Profile,
+    /// In reading order.
+    pub articles: Vec<ArticleListItem>,
+    #[serde(rename = "articlesCount")]
+    pub articles_count: u64,
+}
+
+impl SeriesItem {
+    pub(crate) fn from_series_view(view: &SeriesView, articles: &[ArticleListView]) -> SeriesItem {
+        SeriesItem {
+            slug: view.slug.clone(),
+            title: view.title.clone(),
+            description: view.description.clone(),
+            created_at: view.created_at,
+            updated_at: view.updated_at,
+            author: Profile::new(
+                view.owner.clone(),
+                view.owner_bio.clone(),
+                view.owner_image.clone(),
+                view.following,
+            ),
+            articles: articles
+                .iter()
+                .map(ArticleListItem::from_article_view)
+                .collect(),
+            articles_count: articles.len() as u64,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateSeriesRequest {
+    pub series: CreateSeries,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateSeries {
+    pub title: SeriesTitle,
+    pub description: Option<SeriesDescription>,
+    /// Slugs of your articles, in reading order.
+    pub articles: Option<Vec<Slug>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateSeriesRequest {
+    pub series: UpdateSeries,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateSeries {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<SeriesTitle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<SeriesDescription>,
+    /// Replaces the articles of the series, in reading order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub articles: Option<Vec<Slug>>,
+}
+
+/// The series an article belongs to, with links to the articles around it.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleSeriesItem {
+    pub slug: Slug,
+    pub title: SeriesTitle,
+    /// Position of the article in the series, starting at 1.
+    pub position: i64,
+    #[serde(rename = "articlesCount")]
+    pub articles_count: i64,
+    pub previous: Option<SeriesArticleLink>,
+    pub next: Option<SeriesArticleLink>,
+}
+
+impl ArticleSeriesItem {
+    pub(crate) fn from_navigation(navigation: &SeriesNavigation) -> ArticleSeriesItem {
+        ArticleSeriesItem {
+            slug: navigation.slug.clone(),
+            title: navigation.title.clone(),
+            position: navigation.position,
+            articles_count: navigation.articles_count,
+            previous: navigation
+                .previous
+                .as_ref()
+                .map(SeriesArticleLink::from_neighbor),
+            next: navigation
+                .next
+                .as_ref()
+                .map(SeriesArticleLink::from_neighbor),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeriesArticleLink {
+    pub slug: Slug,
+    pub title: ArticleTitle,
+}
+
+impl SeriesArticleLink {
+    fn from_neighbor(neighbor: &SeriesNeighbor) -> SeriesArticleLink {
+        SeriesArticleLink {
+            slug: neighbor.slug.clone(),
+            title: neighbor.title.clone(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::model::persistence::article_stats_view::{ArticleStatsView, DailyArticleStatsView};
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::slug::Slug;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+const DEFAULT_STATS_DAYS: u32 = 30;
+pub(crate) const MAX_STATS_DAYS: u32 = 365;
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct StatsQuery {
+    /// Number of days covered by the daily stats, today included.
+    pub days: Option<u32>,
+}
+
+impl StatsQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        match self.days {
+            Some(days) if !(1..=MAX_STATS_DAYS).contains(&days) => {
+                Err(format!("days must be between 1 and {MAX_STATS_DAYS}"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn days(&self) -> u32 {
+        self.days.unwrap_or(DEFAULT_STATS_DAYS)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct StatsResponse {
+    pub stats: AuthorStats,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuthorStats {
+    /// Start of the period covered by the daily stats and the totals below.
+    pub since: DateTime<Utc>,
+    pub views: i64,
+    pub favorites: i64,
+    pub comments: i64,
+    pub articles: Vec<ArticleStatsItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ArticleStatsItem {
+    pub slug: Slug,
+    pub title: ArticleTitle,
+    #[serde(rename = "viewsCount")]
+    pub views_count: i64,
+    #[serde(rename = "favoritesCount")]
+    pub favorites_count: i64,
+    #[serde(rename = "commentsCount")]
+    pub comments_count: i64,
+    /// Days of the period with any activity, oldest first.
+    pub daily: Vec<DailyStatsItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DailyStatsItem {
+    pub date: NaiveDate,
+    pub views: i64,
+    pub favorites: i64,
+    pub comments: i64,
+}
+
+impl StatsResponse {
+    pub fn from_views(
+        since: DateTime<Utc>,
+        articles: Vec<ArticleStatsView>,
+        daily: Vec<DailyArticleStatsView>,
+    ) -> StatsResponse {
+        let articles: Vec<_> = articles
+            .into_iter()
+            .map(|article| ArticleStatsItem {
+                daily: daily
+                    .iter()
+                    .filter(|day| day.article_id == article.id)
+                    .map(|day| DailyStatsItem {
+                        date: day.day,
+                        views: day.views,
+                        favorites: day.favorites,
+                        comments: day.comments,
+                    })
+                    .collect(),
+                slug: article.slug,
+                title: article.title,
+                views_count: article.views_count,
+                favorites_count: article.favorites_count,
+                comments_count: article.comments_count,
+            })
+            .collect();
+
+        StatsResponse {
+            stats: AuthorStats {
+                since,
+                views: daily.iter().map(|day| day.views).sum(),
+                favorites: daily.iter().map(|day| day.favorites).sum(),
+                comments: daily.iter().map(|day| day.comments).sum(),
+                articles,
+            },
+        }
+    }
+}
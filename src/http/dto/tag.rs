@@ -1,3 +1,7 @@
+use crate::model::limit::Limit;
+use crate::model::persistence::tag_view::TagView;
+use crate::model::tag_sort::TagSort;
+use crate::model::values::tag_description::TagDescription;
 use crate::model::values::tag_name::TagName;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -5,4 +9,80 @@ use utoipa::ToSchema;
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TagsResponse {
     pub tags: Vec<TagName>,
+    /// Same tags as `tags`, with their description and usage.
+    #[serde(rename = "tagDetails")]
+    pub tag_details: Vec<TagItem>,
+}
+
+impl TagsResponse {
+    pub(crate) fn from_tag_views(views: Vec<TagView>) -> Self {
+        let tag_details: Vec<TagItem> = views.into_iter().map(TagItem::from_tag_view).collect();
+
+        TagsResponse {
+            tags: tag_details.iter().map(|tag| tag.name.clone()).collect(),
+            tag_details,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TagResponse {
+    pub tag: TagItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TagItem {
+    pub name: TagName,
+    pub description: Option<TagDescription>,
+    #[serde(rename = "articlesCount")]
+    pub articles_count: i64,
+    /// Whether the current user follows the tag.
+    pub following: bool,
+}
+
+impl TagItem {
+    pub(crate) fn from_tag_view(view: TagView) -> Self {
+        TagItem {
+            name: view.name,
+            description: view.description,
+            articles_count: view.articles_count,
+            following: view.following,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct TagListQuery {
+    pub sort: Option<TagSort>,
+    pub limit: Option<Limit>,
+}
+
+impl TagListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.limit.as_ref().map_or(Ok(()), Limit::validate)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateTagRequest {
+    pub tag: UpdateTag,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateTag {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<TagName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<TagDescription>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MergeTagRequest {
+    pub tag: MergeTag,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MergeTag {
+    /// Tag receiving the articles of the merged one.
+    pub into: TagName,
 }
@@ -0,0 +1,44 @@
+use crate::model::uploaded_image::UploadedImage;
+use crate::model::values::image::Image;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Only documents the multipart body, which is read field by field.
+#[allow(dead_code)]
+#[derive(Debug, ToSchema)]
+pub struct UploadForm {
+    /// PNG, JPEG, GIF or WebP image
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UploadResponse {
+    pub upload: UploadItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UploadItem {
+    pub url: Image,
+    #[serde(rename = "thumbnailUrl")]
+    pub thumbnail_url: Image,
+    #[serde(rename = "contentType")]
+    pub content_type: String,
+    /// Size of the original image, in bytes
+    pub size: usize,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl UploadItem {
+    pub fn from_image(image: UploadedImage) -> UploadItem {
+        UploadItem {
+            url: image.url,
+            thumbnail_url: image.thumbnail_url,
+            content_type: image.content_type,
+            size: image.size,
+            width: image.width,
+            height: image.height,
+        }
+    }
+}
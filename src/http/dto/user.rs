@@ -2,6 +2,7 @@ use crate::model::persistence::user::User;
 use crate::model::values::bio::Bio;
 use crate::model::values::email::Email;
 use crate::model::values::image::Image;
+use crate::model::values::tag_name::TagName;
 use crate::model::values::username::Username;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -18,16 +19,22 @@ pub struct UserData {
     pub username: Username,
     pub bio: Option<Bio>,
     pub image: Option<Image>,
+    /// Whether follows need approval, and articles are only listed to followers
+    pub private: bool,
+    #[serde(rename = "followedTags")]
+    pub followed_tags: Vec<TagName>,
 }
 
 impl UserData {
-    pub(crate) fn new(user: User, token: String) -> Self {
+    pub(crate) fn new(user: User, token: String, followed_tags: Vec<TagName>) -> Self {
         UserData {
             email: user.email,
             token,
             username: user.username,
             bio: user.bio,
             image: user.image,
+            private: user.private,
+            followed_tags,
         }
     }
 }
@@ -49,4 +56,6 @@ pub struct UpdateUser {
     pub bio: Option<Bio>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<Image>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
 }
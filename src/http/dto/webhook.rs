@@ -0,0 +1,124 @@
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::webhook::Webhook;
+use crate::model::persistence::webhook_delivery::WebhookDelivery;
+use crate::model::values::webhook_delivery_id::WebhookDeliveryId;
+use crate::model::values::webhook_delivery_status::WebhookDeliveryStatus;
+use crate::model::values::webhook_event::WebhookEvent;
+use crate::model::values::webhook_id::WebhookId;
+use crate::model::values::webhook_url::WebhookUrl;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookResponse {
+    pub webhook: WebhookItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhooksResponse {
+    pub webhooks: Vec<WebhookItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookItem {
+    pub id: WebhookId,
+    pub url: WebhookUrl,
+    pub events: Vec<WebhookEvent>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    /// Key of the `X-Realworld-Signature` HMAC, only returned when the webhook is created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+impl WebhookItem {
+    pub fn from_webhook(webhook: Webhook) -> WebhookItem {
+        WebhookItem {
+            id: webhook.id,
+            url: webhook.url,
+            events: webhook.events,
+            created_at: webhook.created_at,
+            secret: None,
+        }
+    }
+
+    pub fn with_secret(webhook: Webhook) -> WebhookItem {
+        let secret = webhook.secret.clone();
+
+        WebhookItem {
+            secret: Some(secret),
+            ..WebhookItem::from_webhook(webhook)
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateWebhookRequest {
+    pub webhook: CreateWebhook,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateWebhook {
+    pub url: WebhookUrl,
+    pub events: Vec<WebhookEvent>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveriesResponse {
+    pub deliveries: Vec<WebhookDeliveryItem>,
+    #[serde(rename = "deliveriesCount")]
+    pub deliveries_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryItem {
+    pub id: WebhookDeliveryId,
+    pub event: WebhookEvent,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    #[serde(rename = "lastResponseStatus")]
+    pub last_response_status: Option<i32>,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+    #[serde(rename = "nextAttemptAt", skip_serializing_if = "Option::is_none")]
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "deliveredAt")]
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDeliveryItem {
+    pub fn from_delivery(delivery: WebhookDelivery) -> WebhookDeliveryItem {
+        let next_attempt_at = match delivery.status {
+            WebhookDeliveryStatus::Pending => Some(delivery.next_attempt_at),
+            WebhookDeliveryStatus::Succeeded | WebhookDeliveryStatus::Failed => None,
+        };
+
+        WebhookDeliveryItem {
+            id: delivery.id,
+            event: delivery.event,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            last_response_status: delivery.last_response_status,
+            last_error: delivery.last_error,
+            next_attempt_at,
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, utoipa::IntoParams)]
+pub struct WebhookDeliveryListQuery {
+    pub limit: Option<Limit>,
+    pub offset: Option<Offset>,
+}
+
+impl WebhookDeliveryListQuery {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        self.limit.as_ref().map_or(Ok(()), Limit::validate)
+    }
+}
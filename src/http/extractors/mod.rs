@@ -1 +1,2 @@
 pub mod auth_token;
+pub mod client_ip;
@@ -8,9 +8,15 @@ use routes::*;
 use crate::app_config::AppConfig;
 use crate::domain::article_service::ArticleService;
 use crate::domain::comment_service::CommentService;
+use crate::domain::notification_service::NotificationService;
 use crate::domain::profile_service::ProfileService;
+use crate::domain::series_service::SeriesService;
+use crate::domain::stats_service::StatsService;
 use crate::domain::tag_service::TagService;
+use crate::domain::upload_service::UploadService;
 use crate::domain::user_service::UserService;
+use crate::domain::webhook_service::WebhookService;
+use crate::events::event_bus::EventBus;
 use crate::openapi::ApiDoc;
 use crate::utils::jwt::JwtHandler;
 use axum::Router;
@@ -48,10 +54,17 @@ pub fn router(state: AppState) -> Router {
     let api_routes = Router::new()
         .merge(auth::auth_routes())
         .merge(users::user_routes())
+        .merge(stats::stats_routes())
         .merge(profiles::profile_routes())
         .merge(articles::article_routes())
+        .merge(series::series_routes())
+        .merge(bookmarks::bookmark_routes())
         .merge(comments::comment_routes())
         .merge(tags::tag_routes())
+        .merge(uploads::upload_routes())
+        .merge(notifications::notification_routes())
+        .merge(events::event_routes())
+        .merge(webhooks::webhook_routes())
         .layer(
             TraceLayer::new_for_http()
                 .make_span_with(FilteringMakeSpan::except_routes(vec!["/api/health"]))
@@ -70,8 +83,14 @@ pub struct AppState {
     pub config: AppConfig,
     pub user_service: UserService,
     pub article_service: ArticleService,
+    pub series_service: SeriesService,
     pub comment_service: CommentService,
     pub tag_service: TagService,
     pub profile_service: ProfileService,
+    pub stats_service: StatsService,
+    pub upload_service: UploadService,
+    pub notification_service: NotificationService,
+    pub event_bus: EventBus,
+    pub webhook_service: WebhookService,
     pub jwt: JwtHandler,
 }
@@ -1,17 +1,24 @@
 use crate::app_error::AppError;
 use crate::domain::commands::create_article_command::CreateArticleCommand;
 use crate::domain::commands::get_feed_query::GetFeedQuery;
+use crate::domain::commands::get_related_query::GetRelatedQuery;
+use crate::domain::commands::get_trending_query::GetTrendingQuery;
 use crate::domain::commands::list_articles_query::ListArticlesQuery;
 use crate::domain::commands::update_article_command::UpdateArticleCommand;
 use crate::http::AppState;
 use crate::http::dto::article::{
-    ArticleFeedListQuery, ArticleItem, ArticleListItem, ArticleListQuery, ArticleResponse,
-    ArticlesResponse, CreateArticleRequest, UpdateArticleRequest,
+    ArticleFeedListQuery, ArticleItem, ArticleListItem, ArticleListQuery, ArticleQuery,
+    ArticleRelatedListQuery, ArticleResponse, ArticleTrendingListQuery, ArticlesResponse,
+    CreateArticleRequest, UpdateArticleRequest,
 };
 use crate::http::extractors::auth_token::AuthToken;
+use crate::http::extractors::client_ip::ClientIp;
+use crate::model::values::reaction_kind::ReactionKind;
 use crate::model::values::slug::Slug;
+use crate::model::values::username::Username;
+use crate::model::viewer::Viewer;
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderValue, StatusCode, header};
 use axum::routing::{delete, get, post, put};
 use axum::{Json, Router};
 use tracing::info;
@@ -20,12 +27,36 @@ pub(crate) fn article_routes() -> Router<AppState> {
     Router::new()
         .route("/articles", get(list_articles))
         .route("/articles/feed", get(feed_articles))
+        .route("/articles/trending", get(trending_articles))
         .route("/articles/{slug}", get(get_article))
+        .route("/articles/{slug}/related", get(related_articles))
         .route("/articles", post(create_article))
         .route("/articles/{slug}", put(update_article))
         .route("/articles/{slug}", delete(delete_article))
         .route("/articles/{slug}/favorite", post(favorite_article))
         .route("/articles/{slug}/favorite", delete(unfavorite_article))
+        .route(
+            "/articles/{slug}/reactions/{kind}",
+            post(add_article_reaction),
+        )
+        .route(
+            "/articles/{slug}/reactions/{kind}",
+            delete(remove_article_reaction),
+        )
+        .route("/articles/{slug}/comments/lock", post(lock_comments))
+        .route("/articles/{slug}/comments/lock", delete(unlock_comments))
+        .route(
+            "/articles/{slug}/co-authors/{username}",
+            post(invite_co_author),
+        )
+        .route(
+            "/articles/{slug}/co-authors/{username}/accept",
+            post(accept_co_author_invitation),
+        )
+        .route(
+            "/articles/{slug}/co-authors/{username}",
+            delete(remove_co_author),
+        )
 }
 
 #[utoipa::path(
@@ -44,6 +75,8 @@ pub(crate) async fn list_articles(
 ) -> Result<Json<ArticlesResponse>, AppError> {
     info!(params = ?params, "List articles with filters");
 
+    params.validate().map_err(AppError::BadData)?;
+
     let query = ListArticlesQuery::from_request(params);
     let user_id = auth.as_ref().map(|u| u.user_id);
 
@@ -81,7 +114,10 @@ pub(crate) async fn feed_articles(
 ) -> Result<Json<ArticlesResponse>, AppError> {
     info!(params = ?params, "Get article feed");
 
+    params.validate().map_err(AppError::BadData)?;
+
     let query = GetFeedQuery::from_request(params, auth.user_id);
+    let source = query.source;
 
     let articles = state.article_service.get_feed(query).await?;
 
@@ -92,7 +128,7 @@ pub(crate) async fn feed_articles(
 
     let articles_count = state
         .article_service
-        .count_feed_articles(auth.user_id)
+        .count_feed_articles(auth.user_id, source)
         .await?;
     Ok(Json(ArticlesResponse {
         articles: views,
@@ -100,36 +136,151 @@ pub(crate) async fn feed_articles(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/articles/trending",
+    tag = "Articles",
+    params(ArticleTrendingListQuery),
+    responses(
+        (status = 200, description = "Articles ranked by recent favorites and comments, recomputed periodically", body = ArticlesResponse)
+    )
+)]
+pub(crate) async fn trending_articles(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Query(params): Query<ArticleTrendingListQuery>,
+) -> Result<
+    (
+        [(header::HeaderName, HeaderValue); 2],
+        Json<ArticlesResponse>,
+    ),
+    AppError,
+> {
+    info!(params = ?params, "Get trending articles");
+
+    params.validate().map_err(AppError::BadData)?;
+
+    let user_id = auth.as_ref().map(|u| u.user_id);
+    let query = GetTrendingQuery::from_request(params, user_id);
+    let window = query.window;
+
+    let articles = state.article_service.get_trending(query).await?;
+
+    let views: Vec<_> = articles
+        .iter()
+        .map(ArticleListItem::from_article_view)
+        .collect();
+
+    let articles_count = state
+        .article_service
+        .count_trending_articles(user_id, window)
+        .await?;
+
+    // The ranking only changes on refresh, but favorited and following are per user, so shared
+    // caches must not hand the anonymous response to a signed-in user.
+    let max_age = state.config.trending.refresh_interval_ms / 1000;
+    let visibility = if user_id.is_some() {
+        "private"
+    } else {
+        "public"
+    };
+    let cache_control = HeaderValue::from_str(&format!("{}, max-age={}", visibility, max_age))
+        .expect("Cache-Control value is valid");
+
+    Ok((
+        [
+            (header::CACHE_CONTROL, cache_control),
+            (header::VARY, HeaderValue::from_static("Authorization")),
+        ],
+        Json(ArticlesResponse {
+            articles: views,
+            articles_count,
+        }),
+    ))
+}
+
 #[utoipa::path(
     get,
     path = "/api/articles/{slug}",
     tag = "Articles",
     params(
-        ("slug" = Slug, Path, description = "Slug of the article to retrieve")
+        ("slug" = Slug, Path, description = "Slug of the article to retrieve"),
+        ArticleQuery
     ),
     responses(
-        (status = 200, description = "Article retrieved successfully", body = ArticleResponse),
+        (status = 200, description = "Article retrieved successfully, with its reading time, table of contents and series", body = ArticleResponse),
         (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse)
     )
 )]
 pub(crate) async fn get_article(
     State(state): State<AppState>,
     auth: Option<AuthToken>,
+    ClientIp(ip): ClientIp,
     Path(slug): Path<Slug>,
+    Query(params): Query<ArticleQuery>,
 ) -> Result<Json<ArticleResponse>, AppError> {
-    info!(slug = %slug, "Get article: {}", slug);
+    info!(slug = %slug, params = ?params, "Get article: {}", slug);
+
+    let user_id = auth.map(|u| u.user_id);
+    let viewer = user_id.map(Viewer::User).or(ip.map(Viewer::Anonymous));
 
     let article = state
         .article_service
-        .get_article(&slug, auth.map(|u| u.user_id))
+        .view_article(&slug, user_id, viewer)
         .await?
         .ok_or_else(|| AppError::NotFound)?;
 
-    let article = ArticleItem::from_article_view(&article);
+    let rendered = state.article_service.render_body(&article);
+    let series = state
+        .series_service
+        .get_navigation(article.id, user_id)
+        .await?;
+    let article = ArticleItem::from_article_view(&article)
+        .with_rendered_body(&rendered, params.format.unwrap_or_default())
+        .with_series(series.as_ref());
 
     Ok(Json(ArticleResponse { article }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/articles/{slug}/related",
+    tag = "Articles",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article to find related articles for"),
+        ArticleRelatedListQuery
+    ),
+    responses(
+        (status = 200, description = "Articles sharing rare tags or favoriting users with the article, most related first", body = ArticlesResponse),
+        (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn related_articles(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Path(slug): Path<Slug>,
+    Query(params): Query<ArticleRelatedListQuery>,
+) -> Result<Json<ArticlesResponse>, AppError> {
+    info!(slug = %slug, params = ?params, "Get related articles: {}", slug);
+
+    params.validate().map_err(AppError::BadData)?;
+
+    let query = GetRelatedQuery::from_request(params, slug, auth.map(|u| u.user_id));
+
+    let articles = state.article_service.get_related(query).await?;
+
+    let views: Vec<_> = articles
+        .iter()
+        .map(ArticleListItem::from_article_view)
+        .collect();
+    let articles_count = views.len() as u64;
+
+    Ok(Json(ArticlesResponse {
+        articles: views,
+        articles_count,
+    }))
+}
+
 #[utoipa::path(
     post,
     path = "/api/articles",
@@ -293,3 +444,288 @@ pub(crate) async fn unfavorite_article(
 
     Ok(Json(ArticleResponse { article }))
 }
+
+#[utoipa::path(
+    post,
+    path = "/api/articles/{slug}/reactions/{kind}",
+    tag = "Articles",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article to react to"),
+        ("kind" = ReactionKind, Path, description = "Kind of reaction, `like` is the same as favoriting")
+    ),
+    responses(
+        (status = 200, description = "Reaction added, adding it twice is a no-op", body = ArticleResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn add_article_reaction(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, kind)): Path<(Slug, ReactionKind)>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    info!(slug = %slug, "React {} to article: {}", kind, slug);
+
+    state
+        .article_service
+        .add_reaction(auth.user_id, &slug, kind)
+        .await?;
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/articles/{slug}/reactions/{kind}",
+    tag = "Articles",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article"),
+        ("kind" = ReactionKind, Path, description = "Kind of reaction to remove")
+    ),
+    responses(
+        (status = 200, description = "Reaction removed", body = ArticleResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn remove_article_reaction(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, kind)): Path<(Slug, ReactionKind)>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    info!(slug = %slug, "Remove {} reaction from article: {}", kind, slug);
+
+    state
+        .article_service
+        .remove_reaction(auth.user_id, &slug, kind)
+        .await?;
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/articles/{slug}/comments/lock",
+    tag = "Articles",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article to lock the comments of")
+    ),
+    responses(
+        (status = 200, description = "Comments locked, existing comments stay visible", body = ArticleResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - neither the author nor a moderator", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn lock_comments(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(slug): Path<Slug>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, slug = %slug, "Lock comments of article: {}", slug);
+
+    state
+        .comment_service
+        .set_comments_locked(auth.user_id, &slug, true)
+        .await?;
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/articles/{slug}/comments/lock",
+    tag = "Articles",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article to unlock the comments of")
+    ),
+    responses(
+        (status = 200, description = "Comments unlocked", body = ArticleResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - neither the author nor a moderator", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn unlock_comments(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(slug): Path<Slug>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, slug = %slug, "Unlock comments of article: {}", slug);
+
+    state
+        .comment_service
+        .set_comments_locked(auth.user_id, &slug, false)
+        .await?;
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/articles/{slug}/co-authors/{username}",
+    tag = "Articles",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article"),
+        ("username" = Username, Path, description = "Username of the co-author to invite")
+    ),
+    responses(
+        (status = 200, description = "Co-author invited, they can update the article once they accept", body = ArticleResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - not the article author", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Article or user not found", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "The author cannot be a co-author", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn invite_co_author(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, username)): Path<(Slug, Username)>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    info!(slug = %slug, username = %username, "Invite co-author {} to article: {}", username, slug);
+
+    let co_author = state
+        .user_service
+        .get_user_by_username(username)
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    state
+        .article_service
+        .invite_co_author(auth.user_id, &slug, co_author.id)
+        .await?;
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/articles/{slug}/co-authors/{username}/accept",
+    tag = "Articles",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article"),
+        ("username" = Username, Path, description = "Username of the invited co-author, the current user")
+    ),
+    responses(
+        (status = 200, description = "Invitation accepted, the user can now update the article", body = ArticleResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - not the invited co-author", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Article or invitation not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn accept_co_author_invitation(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, username)): Path<(Slug, Username)>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    info!(slug = %slug, username = %username, "Accept co-author invitation of {} to article: {}", username, slug);
+
+    let co_author = state
+        .user_service
+        .get_user_by_username(username)
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    if co_author.id != auth.user_id {
+        return Err(AppError::Forbidden);
+    }
+
+    state
+        .article_service
+        .accept_co_author_invitation(auth.user_id, &slug)
+        .await?;
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/articles/{slug}/co-authors/{username}",
+    tag = "Articles",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article"),
+        ("username" = Username, Path, description = "Username of the co-author to remove")
+    ),
+    responses(
+        (status = 200, description = "Co-author removed", body = ArticleResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - neither the article author nor the co-author", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Article or co-author not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn remove_co_author(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, username)): Path<(Slug, Username)>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    info!(slug = %slug, username = %username, "Remove co-author {} from article: {}", username, slug);
+
+    let co_author = state
+        .user_service
+        .get_user_by_username(username)
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    state
+        .article_service
+        .remove_co_author(auth.user_id, &slug, co_author.id)
+        .await?;
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
@@ -40,7 +40,9 @@ pub(crate) async fn login(
 
     let token = app_state.jwt.generate_token(user.id)?;
 
-    let user = UserData::new(user, token);
+    let followed_tags = app_state.tag_service.get_followed_tags(user.id).await?;
+
+    let user = UserData::new(user, token, followed_tags);
 
     Ok(Json(UserResponse { user }))
 }
@@ -76,6 +78,8 @@ pub(crate) async fn register(
         username: user.username,
         bio: user.bio,
         image: user.image,
+        private: user.private,
+        followed_tags: Vec::new(),
     };
 
     Ok((StatusCode::CREATED, Json(UserResponse { user })))
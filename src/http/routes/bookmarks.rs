@@ -0,0 +1,136 @@
+use crate::app_error::AppError;
+use crate::domain::commands::bookmark_article_command::BookmarkArticleCommand;
+use crate::domain::commands::list_bookmarks_query::ListBookmarksQuery;
+use crate::http::AppState;
+use crate::http::dto::article::{ArticleItem, ArticleResponse};
+use crate::http::dto::bookmark::{
+    BookmarkItem, BookmarkListQuery, BookmarkRequest, BookmarksResponse,
+};
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::slug::Slug;
+use axum::extract::{Path, Query, State};
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use tracing::info;
+
+pub(crate) fn bookmark_routes() -> Router<AppState> {
+    Router::new()
+        .route("/articles/{slug}/bookmark", post(bookmark_article))
+        .route("/articles/{slug}/bookmark", delete(unbookmark_article))
+        .route("/user/bookmarks", get(list_bookmarks))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/articles/{slug}/bookmark",
+    tag = "Bookmarks",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article to bookmark")
+    ),
+    request_body(content = Option<BookmarkRequest>, description = "Optional folder and note, replacing those of an existing bookmark"),
+    responses(
+        (status = 200, description = "Article bookmarked successfully", body = ArticleResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Validation error", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn bookmark_article(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(slug): Path<Slug>,
+    payload: Option<Json<BookmarkRequest>>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, slug = %slug, "Bookmark article: {}", slug);
+
+    let payload = payload.map(|Json(payload)| payload).unwrap_or_default();
+    let command = BookmarkArticleCommand::from_request(payload, slug.clone(), auth.user_id);
+
+    state.article_service.bookmark_article(command).await?;
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/articles/{slug}/bookmark",
+    tag = "Bookmarks",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article to remove from the bookmarks")
+    ),
+    responses(
+        (status = 200, description = "Bookmark removed successfully", body = ArticleResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn unbookmark_article(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(slug): Path<Slug>,
+) -> Result<Json<ArticleResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, slug = %slug, "Unbookmark article: {}", slug);
+
+    state
+        .article_service
+        .unbookmark_article(auth.user_id, &slug)
+        .await?;
+
+    let article = state
+        .article_service
+        .get_article(&slug, Some(auth.user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article = ArticleItem::from_article_view(&article);
+
+    Ok(Json(ArticleResponse { article }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/user/bookmarks",
+    tag = "Bookmarks",
+    params(BookmarkListQuery),
+    responses(
+        (status = 200, description = "Bookmarks of the current user retrieved successfully, newest first", body = BookmarksResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn list_bookmarks(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Query(params): Query<BookmarkListQuery>,
+) -> Result<Json<BookmarksResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, params = ?params, "List bookmarks");
+
+    params.validate().map_err(AppError::BadData)?;
+
+    let query = ListBookmarksQuery::from_request(params, auth.user_id);
+
+    let bookmarks = state
+        .article_service
+        .list_bookmarks(&query)
+        .await?
+        .iter()
+        .map(BookmarkItem::from_bookmark_view)
+        .collect();
+
+    let bookmarks_count = state
+        .article_service
+        .count_bookmarks(query.user_id, query.folder.as_ref())
+        .await?;
+
+    Ok(Json(BookmarksResponse {
+        bookmarks,
+        bookmarks_count,
+    }))
+}
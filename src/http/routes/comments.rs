@@ -1,13 +1,15 @@
 use crate::app_error::AppError;
 use crate::domain::commands::add_comment_command::AddCommentCommand;
+use crate::domain::commands::list_comments_query::ListCommentsQuery;
 use crate::http::AppState;
 use crate::http::dto::comment::{
-    CommentItem, CommentResponse, CommentsResponse, CreateCommentRequest,
+    CommentItem, CommentListQuery, CommentResponse, CommentsResponse, CreateCommentRequest,
 };
 use crate::http::extractors::auth_token::AuthToken;
 use crate::model::values::comment_id::CommentId;
+use crate::model::values::reaction_kind::ReactionKind;
 use crate::model::values::slug::Slug;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get, post};
 use axum::{Json, Router};
@@ -18,6 +20,14 @@ pub(crate) fn comment_routes() -> Router<AppState> {
         .route("/articles/{slug}/comments", post(create_comment))
         .route("/articles/{slug}/comments", get(get_comments))
         .route("/articles/{slug}/comments/{id}", delete(delete_comment))
+        .route(
+            "/articles/{slug}/comments/{id}/reactions/{kind}",
+            post(add_comment_reaction),
+        )
+        .route(
+            "/articles/{slug}/comments/{id}/reactions/{kind}",
+            delete(remove_comment_reaction),
+        )
 }
 
 #[utoipa::path(
@@ -31,7 +41,9 @@ pub(crate) fn comment_routes() -> Router<AppState> {
     responses(
         (status = 201, description = "Comment created successfully", body = CommentResponse),
         (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "The article's author blocks the current user", body = crate::http::dto::error::ErrorResponse),
         (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse),
+        (status = 409, description = "Comments are locked on the article", body = crate::http::dto::error::ErrorResponse),
         (status = 422, description = "Validation error", body = crate::http::dto::error::ErrorResponse)
     )
 )]
@@ -43,13 +55,14 @@ pub(crate) async fn create_comment(
 ) -> Result<(StatusCode, Json<CommentResponse>), AppError> {
     info!(user_id=%{auth.user_id}, payload=?payload, "Add comment to article: {}", slug);
 
-    let article = state
+    // Resolved whoever asks, the service tells a blocked user apart from one the article is hidden from.
+    let article_id = state
         .article_service
-        .get_article(&slug, Some(auth.user_id))
+        .get_article_id(&slug)
         .await?
         .ok_or_else(|| AppError::NotFound)?;
 
-    let command = AddCommentCommand::from_request(payload, article.id, auth.user_id);
+    let command = AddCommentCommand::from_request(payload, article_id, auth.user_id);
 
     let comment_view = state
         .comment_service
@@ -66,10 +79,11 @@ pub(crate) async fn create_comment(
     path = "/api/articles/{slug}/comments",
     tag = "Comments",
     params(
-        ("slug" = Slug, Path, description = "Slug of the article to get comments for")
+        ("slug" = Slug, Path, description = "Slug of the article to get comments for"),
+        CommentListQuery
     ),
     responses(
-        (status = 200, description = "Comments retrieved successfully", body = CommentsResponse),
+        (status = 200, description = "Page of comments retrieved successfully, newest first by default", body = CommentsResponse),
         (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse)
     )
 )]
@@ -77,19 +91,24 @@ pub(crate) async fn get_comments(
     State(state): State<AppState>,
     auth: Option<AuthToken>,
     Path(slug): Path<Slug>,
+    Query(params): Query<CommentListQuery>,
 ) -> Result<Json<CommentsResponse>, AppError> {
     let maybe_user_id = auth.as_ref().map(|a| a.user_id);
-    info!(slug = %slug, user_id = ?maybe_user_id, "Get comments for article: {}", slug);
+    info!(slug = %slug, user_id = ?maybe_user_id, params = ?params, "Get comments for article: {}", slug);
+
+    params.validate().map_err(AppError::BadData)?;
 
     let article = state
         .article_service
-        .get_article(&slug, None)
+        .get_article(&slug, maybe_user_id)
         .await?
         .ok_or_else(|| AppError::NotFound)?;
 
-    let comment_views = state
+    let query = ListCommentsQuery::from_request(params, article.id);
+
+    let (comment_views, next_cursor) = state
         .comment_service
-        .get_comments(article.id, maybe_user_id)
+        .get_comments(&query, maybe_user_id)
         .await?;
 
     let comments = comment_views
@@ -97,7 +116,10 @@ pub(crate) async fn get_comments(
         .map(CommentItem::from_comment_view)
         .collect();
 
-    Ok(Json(CommentsResponse { comments }))
+    Ok(Json(CommentsResponse {
+        comments,
+        next_cursor,
+    }))
 }
 
 #[utoipa::path(
@@ -129,3 +151,68 @@ pub(crate) async fn delete_comment(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    post,
+    path = "/api/articles/{slug}/comments/{id}/reactions/{kind}",
+    tag = "Comments",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article"),
+        ("id" = CommentId, Path, description = "ID of the comment to react to"),
+        ("kind" = ReactionKind, Path, description = "Kind of reaction")
+    ),
+    responses(
+        (status = 200, description = "Reaction added, adding it twice is a no-op", body = CommentResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - blocked by the author of the comment", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Comment not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn add_comment_reaction(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, comment_id, kind)): Path<(Slug, CommentId, ReactionKind)>,
+) -> Result<Json<CommentResponse>, AppError> {
+    info!(user_id=%{auth.user_id}, slug = %slug, comment_id = %comment_id, "React {} to comment {} of article: {}", kind, comment_id, slug);
+
+    let comment_view = state
+        .comment_service
+        .add_reaction(comment_id, auth.user_id, kind)
+        .await?;
+
+    let comment = CommentItem::from_comment_view(comment_view);
+
+    Ok(Json(CommentResponse { comment }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/articles/{slug}/comments/{id}/reactions/{kind}",
+    tag = "Comments",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article"),
+        ("id" = CommentId, Path, description = "ID of the comment"),
+        ("kind" = ReactionKind, Path, description = "Kind of reaction to remove")
+    ),
+    responses(
+        (status = 200, description = "Reaction removed", body = CommentResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Comment not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn remove_comment_reaction(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path((slug, comment_id, kind)): Path<(Slug, CommentId, ReactionKind)>,
+) -> Result<Json<CommentResponse>, AppError> {
+    info!(user_id=%{auth.user_id}, slug = %slug, comment_id = %comment_id, "Remove {} reaction from comment {} of article: {}", kind, comment_id, slug);
+
+    let comment_view = state
+        .comment_service
+        .remove_reaction(comment_id, auth.user_id, kind)
+        .await?;
+
+    let comment = CommentItem::from_comment_view(comment_view);
+
+    Ok(Json(CommentResponse { comment }))
+}
@@ -0,0 +1,144 @@
+use crate::app_error::AppError;
+use crate::events::app_event::AppEvent;
+use crate::http::AppState;
+use crate::http::dto::comment::{CommentItem, CommentResponse};
+use crate::http::dto::event::{CommentDeletedEvent, FavoritesChangedEvent};
+use crate::http::dto::notification::{NotificationItem, NotificationResponse};
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::slug::Slug;
+use axum::Router;
+use axum::extract::{Path, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::routing::get;
+use futures_util::{Stream, StreamExt};
+use std::convert::Infallible;
+use tokio_stream::wrappers::BroadcastStream;
+use tracing::info;
+
+pub(crate) fn event_routes() -> Router<AppState> {
+    Router::new()
+        .route("/articles/{slug}/events", get(article_events))
+        .route("/notifications/events", get(notification_events))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/articles/{slug}/events",
+    tag = "Events",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the article to subscribe to")
+    ),
+    responses(
+        (status = 200, description = "Server-sent events stream: `commentCreated` (CommentResponse), `commentDeleted` (CommentDeletedEvent) and `favoritesChanged` (FavoritesChangedEvent)", content_type = "text/event-stream", body = String),
+        (status = 404, description = "Article not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn article_events(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Path(slug): Path<Slug>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let maybe_user_id = auth.as_ref().map(|a| a.user_id);
+    info!(slug = %slug, user_id = ?maybe_user_id, "Subscribe to article events: {}", slug);
+
+    let article = state
+        .article_service
+        .get_article(&slug, maybe_user_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let article_id = article.id;
+    let comment_service = state.comment_service.clone();
+
+    let stream = BroadcastStream::new(state.event_bus.subscribe())
+        // Lagging subscribers just miss the overflowed events.
+        .filter_map(|event| async move { event.ok() })
+        .filter(move |event| std::future::ready(event.article_id() == Some(article_id)))
+        .filter_map(move |event| {
+            let comment_service = comment_service.clone();
+
+            async move {
+                match event {
+                    AppEvent::CommentCreated { comment_id, .. } => {
+                        // The comment may already be gone by the time it's fetched.
+                        let comment = comment_service
+                            .get_comment(comment_id, maybe_user_id)
+                            .await
+                            .ok()?;
+
+                        Event::default()
+                            .event("commentCreated")
+                            .json_data(CommentResponse {
+                                comment: CommentItem::from_comment_view(comment),
+                            })
+                            .ok()
+                    }
+                    AppEvent::CommentDeleted { comment_id, .. } => Event::default()
+                        .event("commentDeleted")
+                        .json_data(CommentDeletedEvent { id: comment_id })
+                        .ok(),
+                    AppEvent::FavoritesChanged {
+                        favorites_count, ..
+                    } => Event::default()
+                        .event("favoritesChanged")
+                        .json_data(FavoritesChangedEvent { favorites_count })
+                        .ok(),
+                    _ => None,
+                }
+            }
+        })
+        .map(Ok);
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/notifications/events",
+    tag = "Events",
+    responses(
+        (status = 200, description = "Server-sent events stream: `notificationCreated` (NotificationResponse)", content_type = "text/event-stream", body = String),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn notification_events(
+    State(state): State<AppState>,
+    auth: AuthToken,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    info!(user_id = %{auth.user_id}, "Subscribe to notification events");
+
+    let user_id = auth.user_id;
+    let notification_service = state.notification_service.clone();
+
+    let stream = BroadcastStream::new(state.event_bus.subscribe())
+        .filter_map(move |event| {
+            let notification_service = notification_service.clone();
+
+            async move {
+                match event.ok()? {
+                    AppEvent::NotificationCreated {
+                        recipient_id,
+                        notification_id,
+                    } if recipient_id == user_id => {
+                        let notification = notification_service
+                            .get_notification(user_id, notification_id)
+                            .await
+                            .ok()??;
+
+                        Event::default()
+                            .event("notificationCreated")
+                            .json_data(NotificationResponse {
+                                notification: NotificationItem::from_notification_view(
+                                    notification,
+                                ),
+                            })
+                            .ok()
+                    }
+                    _ => None,
+                }
+            }
+        })
+        .map(Ok);
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
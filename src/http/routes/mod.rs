@@ -1,7 +1,14 @@
 pub(crate) mod articles;
 pub(crate) mod auth;
+pub(crate) mod bookmarks;
 pub(crate) mod comments;
+pub(crate) mod events;
 pub(crate) mod health;
+pub(crate) mod notifications;
 pub(crate) mod profiles;
+pub(crate) mod series;
+pub(crate) mod stats;
 pub(crate) mod tags;
+pub(crate) mod uploads;
 pub(crate) mod users;
+pub(crate) mod webhooks;
@@ -0,0 +1,181 @@
+use crate::app_error::AppError;
+use crate::domain::commands::list_notifications_query::ListNotificationsQuery;
+use crate::domain::commands::update_notification_preferences_command::UpdateNotificationPreferencesCommand;
+use crate::http::AppState;
+use crate::http::dto::notification::{
+    NotificationItem, NotificationListQuery, NotificationPreferencesItem,
+    NotificationPreferencesResponse, NotificationsResponse, UpdateNotificationPreferencesRequest,
+};
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::notification_id::NotificationId;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post, put};
+use axum::{Json, Router};
+use tracing::info;
+
+pub(crate) fn notification_routes() -> Router<AppState> {
+    Router::new()
+        .route("/notifications", get(list_notifications))
+        .route("/notifications/read", post(mark_all_notifications_as_read))
+        .route("/notifications/{id}/read", post(mark_notification_as_read))
+        .route(
+            "/notifications/preferences",
+            get(get_notification_preferences),
+        )
+        .route(
+            "/notifications/preferences",
+            put(update_notification_preferences),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/notifications",
+    tag = "Notifications",
+    params(NotificationListQuery),
+    responses(
+        (status = 200, description = "Notifications retrieved successfully", body = NotificationsResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn list_notifications(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Query(params): Query<NotificationListQuery>,
+) -> Result<Json<NotificationsResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, params = ?params, "List notifications");
+
+    params.validate().map_err(AppError::BadData)?;
+
+    let query = ListNotificationsQuery::from_request(params, auth.user_id);
+
+    let notifications = state
+        .notification_service
+        .list_notifications(query)
+        .await?
+        .into_iter()
+        .map(NotificationItem::from_notification_view)
+        .collect();
+
+    let notifications_count = state
+        .notification_service
+        .count_notifications(auth.user_id)
+        .await?;
+    let unread_count = state
+        .notification_service
+        .count_unread(auth.user_id)
+        .await?;
+
+    Ok(Json(NotificationsResponse {
+        notifications,
+        notifications_count,
+        unread_count,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/notifications/{id}/read",
+    tag = "Notifications",
+    params(
+        ("id" = NotificationId, Path, description = "ID of the notification to mark as read")
+    ),
+    responses(
+        (status = 204, description = "Notification marked as read"),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Notification not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn mark_notification_as_read(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(notification_id): Path<NotificationId>,
+) -> Result<StatusCode, AppError> {
+    info!(user_id = %{auth.user_id}, notification_id = %notification_id, "Mark notification as read: {}", notification_id);
+
+    state
+        .notification_service
+        .mark_as_read(auth.user_id, notification_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/notifications/read",
+    tag = "Notifications",
+    responses(
+        (status = 204, description = "All notifications marked as read"),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn mark_all_notifications_as_read(
+    State(state): State<AppState>,
+    auth: AuthToken,
+) -> Result<StatusCode, AppError> {
+    info!(user_id = %{auth.user_id}, "Mark all notifications as read");
+
+    state
+        .notification_service
+        .mark_all_as_read(auth.user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/notifications/preferences",
+    tag = "Notifications",
+    responses(
+        (status = 200, description = "Notification preferences retrieved successfully", body = NotificationPreferencesResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn get_notification_preferences(
+    State(state): State<AppState>,
+    auth: AuthToken,
+) -> Result<Json<NotificationPreferencesResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, "Get notification preferences");
+
+    let preferences = state
+        .notification_service
+        .get_preferences(auth.user_id)
+        .await?;
+
+    Ok(Json(NotificationPreferencesResponse {
+        preferences: NotificationPreferencesItem::from_preferences(preferences),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/notifications/preferences",
+    tag = "Notifications",
+    request_body = UpdateNotificationPreferencesRequest,
+    responses(
+        (status = 200, description = "Notification preferences updated successfully", body = NotificationPreferencesResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Validation error", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn update_notification_preferences(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Json(payload): Json<UpdateNotificationPreferencesRequest>,
+) -> Result<Json<NotificationPreferencesResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, payload = ?payload, "Update notification preferences");
+
+    let command = UpdateNotificationPreferencesCommand::from_request(payload, auth.user_id);
+
+    let preferences = state
+        .notification_service
+        .update_preferences(command)
+        .await?;
+
+    Ok(Json(NotificationPreferencesResponse {
+        preferences: NotificationPreferencesItem::from_preferences(preferences),
+    }))
+}
@@ -1,11 +1,16 @@
 use crate::app_error::AppError;
+use crate::domain::commands::list_follows_query::ListFollowsQuery;
 use crate::http::AppState;
-use crate::http::dto::profile::{Profile, ProfileResponse};
+use crate::http::dto::profile::{FollowListQuery, Profile, ProfileResponse, ProfilesResponse};
 use crate::http::extractors::auth_token::AuthToken;
+use crate::model::follow_direction::FollowDirection;
+use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
+use axum::response::{IntoResponse, Redirect, Response};
 use axum::routing::{delete, get, post};
 use axum::{Json, Router};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
 use tracing::info;
 
 pub(crate) fn profile_routes() -> Router<AppState> {
@@ -13,6 +18,21 @@ pub(crate) fn profile_routes() -> Router<AppState> {
         .route("/profiles/{username}", get(get_profile))
         .route("/profiles/{username}/follow", post(follow_user))
         .route("/profiles/{username}/follow", delete(unfollow_user))
+        .route("/profiles/{username}/block", post(block_user))
+        .route("/profiles/{username}/block", delete(unblock_user))
+        .route("/profiles/{username}/mute", post(mute_user))
+        .route("/profiles/{username}/mute", delete(unmute_user))
+        .route("/profiles/{username}/followers", get(list_followers))
+        .route("/profiles/{username}/following", get(list_following))
+        .route("/user/follow-requests", get(list_follow_requests))
+        .route(
+            "/user/follow-requests/{username}/approve",
+            post(approve_follow_request),
+        )
+        .route(
+            "/user/follow-requests/{username}",
+            delete(reject_follow_request),
+        )
 }
 
 #[utoipa::path(
@@ -24,6 +44,7 @@ pub(crate) fn profile_routes() -> Router<AppState> {
     ),
     responses(
         (status = 200, description = "Profile retrieved successfully", body = ProfileResponse),
+        (status = 307, description = "Previous username or different case, redirects to the profile under its current username"),
         (status = 404, description = "Profile not found", body = crate::http::dto::error::ErrorResponse)
     )
 )]
@@ -31,26 +52,33 @@ pub(crate) async fn get_profile(
     State(state): State<AppState>,
     auth: Option<AuthToken>,
     Path(username): Path<Username>,
-) -> Result<Json<ProfileResponse>, AppError> {
+) -> Result<Response, AppError> {
     let maybe_user_id = auth.as_ref().map(|u| u.user_id);
 
     info!(user_id = ?maybe_user_id, username = %username, "Get profile for username: {}", username);
 
-    let user = state
-        .user_service
-        .get_user_by_username(username.clone())
+    if let Some(profile) = state
+        .profile_service
+        .get_profile(&username, maybe_user_id)
         .await?
-        .ok_or_else(|| AppError::NotFound)?;
-
-    let following = if let Some(user_id) = maybe_user_id {
-        state.profile_service.is_following(user_id, user.id).await?
-    } else {
-        false
-    };
+    {
+        return Ok(Json(ProfileResponse {
+            profile: Profile::from_profile_view(profile),
+        })
+        .into_response());
+    }
 
-    let profile = Profile::from_user(user, following);
-
-    Ok(Json(ProfileResponse { profile }))
+    // Not permanent, the user could take the username back.
+    match state.user_service.find_current_username(&username).await? {
+        Some(current) if current != username => {
+            let path = format!(
+                "/api/profiles/{}",
+                utf8_percent_encode(current.value(), NON_ALPHANUMERIC)
+            );
+            Ok(Redirect::temporary(&path).into_response())
+        }
+        _ => Err(AppError::NotFound),
+    }
 }
 
 #[utoipa::path(
@@ -63,6 +91,7 @@ pub(crate) async fn get_profile(
     responses(
         (status = 200, description = "User followed successfully", body = ProfileResponse),
         (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "The user blocks the current user", body = crate::http::dto::error::ErrorResponse),
         (status = 404, description = "Profile not found", body = crate::http::dto::error::ErrorResponse)
     )
 )]
@@ -75,7 +104,7 @@ pub(crate) async fn follow_user(
 
     let user = state
         .user_service
-        .get_user_by_username(username)
+        .get_user_by_username(username.clone())
         .await?
         .ok_or_else(|| AppError::NotFound)?;
 
@@ -84,9 +113,7 @@ pub(crate) async fn follow_user(
         .follow_user(auth.user_id, user.id)
         .await?;
 
-    let profile = Profile::from_user(user, true);
-
-    Ok(Json(ProfileResponse { profile }))
+    profile_response(&state, &username, auth.user_id).await
 }
 
 #[utoipa::path(
@@ -111,7 +138,7 @@ pub(crate) async fn unfollow_user(
 
     let user = state
         .user_service
-        .get_user_by_username(username)
+        .get_user_by_username(username.clone())
         .await?
         .ok_or_else(|| AppError::NotFound)?;
 
@@ -120,7 +147,350 @@ pub(crate) async fn unfollow_user(
         .unfollow_user(auth.user_id, user.id)
         .await?;
 
-    let profile = Profile::from_user(user, false);
+    profile_response(&state, &username, auth.user_id).await
+}
+
+/// The profile as seen by the user who just acted on it, even if it blocks them.
+async fn profile_response(
+    state: &AppState,
+    username: &Username,
+    user_id: UserId,
+) -> Result<Json<ProfileResponse>, AppError> {
+    let profile = state
+        .profile_service
+        .find_profile(username, Some(user_id))
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    Ok(Json(ProfileResponse {
+        profile: Profile::from_profile_view(profile),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/profiles/{username}/block",
+    tag = "Profiles",
+    params(
+        ("username" = Username, Path, description = "Username of the profile to block")
+    ),
+    responses(
+        (status = 200, description = "User blocked, they can no longer follow the current user, comment on their articles or see their profile", body = ProfileResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Profile not found", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Cannot block yourself", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn block_user(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+) -> Result<Json<ProfileResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, username = %username, "Block user: {}", username);
+
+    let user = state
+        .user_service
+        .get_user_by_username(username.clone())
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    state
+        .profile_service
+        .block_user(auth.user_id, user.id)
+        .await?;
+
+    profile_response(&state, &username, auth.user_id).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/profiles/{username}/block",
+    tag = "Profiles",
+    params(
+        ("username" = Username, Path, description = "Username of the profile to unblock")
+    ),
+    responses(
+        (status = 200, description = "User unblocked successfully", body = ProfileResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Profile not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn unblock_user(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+) -> Result<Json<ProfileResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, username = %username, "Unblock user: {}", username);
+
+    let user = state
+        .user_service
+        .get_user_by_username(username.clone())
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    state
+        .profile_service
+        .unblock_user(auth.user_id, user.id)
+        .await?;
+
+    profile_response(&state, &username, auth.user_id).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/profiles/{username}/mute",
+    tag = "Profiles",
+    params(
+        ("username" = Username, Path, description = "Username of the profile to mute")
+    ),
+    responses(
+        (status = 200, description = "User muted, their articles and comments are hidden from the current user", body = ProfileResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Profile not found", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Cannot mute yourself", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn mute_user(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+) -> Result<Json<ProfileResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, username = %username, "Mute user: {}", username);
+
+    let user = state
+        .user_service
+        .get_user_by_username(username.clone())
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    state
+        .profile_service
+        .mute_user(auth.user_id, user.id)
+        .await?;
+
+    profile_response(&state, &username, auth.user_id).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/profiles/{username}/mute",
+    tag = "Profiles",
+    params(
+        ("username" = Username, Path, description = "Username of the profile to unmute")
+    ),
+    responses(
+        (status = 200, description = "User unmuted successfully", body = ProfileResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Profile not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn unmute_user(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+) -> Result<Json<ProfileResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, username = %username, "Unmute user: {}", username);
+
+    let user = state
+        .user_service
+        .get_user_by_username(username.clone())
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    state
+        .profile_service
+        .unmute_user(auth.user_id, user.id)
+        .await?;
+
+    profile_response(&state, &username, auth.user_id).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/profiles/{username}/followers",
+    tag = "Profiles",
+    params(
+        ("username" = Username, Path, description = "Username of the followed profile"),
+        FollowListQuery
+    ),
+    responses(
+        (status = 200, description = "Followers of the profile, most recent first", body = ProfilesResponse),
+        (status = 404, description = "Profile not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn list_followers(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Path(username): Path<Username>,
+    Query(params): Query<FollowListQuery>,
+) -> Result<Json<ProfilesResponse>, AppError> {
+    list_follows(state, auth, username, params, FollowDirection::Followers).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/profiles/{username}/following",
+    tag = "Profiles",
+    params(
+        ("username" = Username, Path, description = "Username of the following profile"),
+        FollowListQuery
+    ),
+    responses(
+        (status = 200, description = "Profiles followed by the profile, most recently followed first", body = ProfilesResponse),
+        (status = 404, description = "Profile not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn list_following(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Path(username): Path<Username>,
+    Query(params): Query<FollowListQuery>,
+) -> Result<Json<ProfilesResponse>, AppError> {
+    list_follows(state, auth, username, params, FollowDirection::Following).await
+}
+
+async fn list_follows(
+    state: AppState,
+    auth: Option<AuthToken>,
+    username: Username,
+    params: FollowListQuery,
+    direction: FollowDirection,
+) -> Result<Json<ProfilesResponse>, AppError> {
+    let maybe_user_id = auth.as_ref().map(|u| u.user_id);
+
+    info!(user_id = ?maybe_user_id, username = %username, direction = ?direction, params = ?params, "List follows");
+
+    params.validate().map_err(AppError::BadData)?;
+
+    let profile = state
+        .profile_service
+        .get_profile(&username, maybe_user_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let profiles_count = match direction {
+        FollowDirection::Followers => profile.followers_count,
+        FollowDirection::Following => profile.following_count,
+    };
+
+    let query = ListFollowsQuery::from_request(params, profile.id, direction, maybe_user_id);
+
+    let profiles = state
+        .profile_service
+        .list_follows(&query)
+        .await?
+        .into_iter()
+        .map(Profile::from_profile_view)
+        .collect();
+
+    Ok(Json(ProfilesResponse {
+        profiles,
+        profiles_count,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/user/follow-requests",
+    tag = "Profiles",
+    params(FollowListQuery),
+    responses(
+        (status = 200, description = "Pending requests to follow the current user, most recent first", body = ProfilesResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn list_follow_requests(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Query(params): Query<FollowListQuery>,
+) -> Result<Json<ProfilesResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, params = ?params, "List follow requests");
+
+    params.validate().map_err(AppError::BadData)?;
+
+    let profiles = state
+        .profile_service
+        .list_follow_requests(auth.user_id, params.limit, params.offset)
+        .await?
+        .into_iter()
+        .map(Profile::from_profile_view)
+        .collect();
+
+    let profiles_count = state
+        .profile_service
+        .count_follow_requests(auth.user_id)
+        .await?;
+
+    Ok(Json(ProfilesResponse {
+        profiles,
+        profiles_count,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/user/follow-requests/{username}/approve",
+    tag = "Profiles",
+    params(
+        ("username" = Username, Path, description = "Username of the profile requesting to follow")
+    ),
+    responses(
+        (status = 200, description = "Follow request approved, the profile now follows the current user", body = ProfileResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Follow request not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn approve_follow_request(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+) -> Result<Json<ProfileResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, username = %username, "Approve follow request of: {}", username);
+
+    let user = state
+        .user_service
+        .get_user_by_username(username.clone())
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    state
+        .profile_service
+        .approve_follow_request(auth.user_id, user.id)
+        .await?;
+
+    profile_response(&state, &username, auth.user_id).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/user/follow-requests/{username}",
+    tag = "Profiles",
+    params(
+        ("username" = Username, Path, description = "Username of the profile requesting to follow")
+    ),
+    responses(
+        (status = 200, description = "Follow request rejected", body = ProfileResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Follow request not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn reject_follow_request(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(username): Path<Username>,
+) -> Result<Json<ProfileResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, username = %username, "Reject follow request of: {}", username);
+
+    let user = state
+        .user_service
+        .get_user_by_username(username.clone())
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    state
+        .profile_service
+        .reject_follow_request(auth.user_id, user.id)
+        .await?;
 
-    Ok(Json(ProfileResponse { profile }))
+    profile_response(&state, &username, auth.user_id).await
 }
@@ -0,0 +1,161 @@
+use crate::app_error::AppError;
+use crate::domain::commands::create_series_command::CreateSeriesCommand;
+use crate::domain::commands::update_series_command::UpdateSeriesCommand;
+use crate::http::AppState;
+use crate::http::dto::series::{
+    CreateSeriesRequest, SeriesItem, SeriesResponse, UpdateSeriesRequest,
+};
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::persistence::series_view::SeriesView;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{delete, get, post, put};
+use axum::{Json, Router};
+use tracing::info;
+
+pub(crate) fn series_routes() -> Router<AppState> {
+    Router::new()
+        .route("/series", post(create_series))
+        .route("/series/{slug}", get(get_series))
+        .route("/series/{slug}", put(update_series))
+        .route("/series/{slug}", delete(delete_series))
+}
+
+async fn series_item(
+    state: &AppState,
+    series: &SeriesView,
+    user_id: Option<UserId>,
+) -> Result<SeriesItem, AppError> {
+    let articles = state
+        .series_service
+        .list_series_articles(series.id, user_id)
+        .await?;
+
+    Ok(SeriesItem::from_series_view(series, &articles))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/series",
+    tag = "Series",
+    request_body = CreateSeriesRequest,
+    responses(
+        (status = 201, description = "Series created successfully", body = SeriesResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - an article of someone else is listed", body = crate::http::dto::error::ErrorResponse),
+        (status = 409, description = "A series with the same slug exists, or an article already belongs to another series", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Validation error", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn create_series(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Json(payload): Json<CreateSeriesRequest>,
+) -> Result<(StatusCode, Json<SeriesResponse>), AppError> {
+    info!(user_id = %{auth.user_id}, payload = ?payload, "Create series");
+
+    let command = CreateSeriesCommand::from_request(payload, auth.user_id);
+
+    let series = state.series_service.create_series(command).await?;
+    let series = series_item(&state, &series, Some(auth.user_id)).await?;
+
+    Ok((StatusCode::CREATED, Json(SeriesResponse { series })))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/series/{slug}",
+    tag = "Series",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the series to retrieve")
+    ),
+    responses(
+        (status = 200, description = "Series retrieved successfully, with its articles in reading order", body = SeriesResponse),
+        (status = 404, description = "Series not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn get_series(
+    State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Path(slug): Path<Slug>,
+) -> Result<Json<SeriesResponse>, AppError> {
+    info!(slug = %slug, "Get series: {}", slug);
+
+    let user_id = auth.map(|u| u.user_id);
+
+    let series = state
+        .series_service
+        .get_series(&slug, user_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound)?;
+    let series = series_item(&state, &series, user_id).await?;
+
+    Ok(Json(SeriesResponse { series }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/series/{slug}",
+    tag = "Series",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the series to update")
+    ),
+    request_body = UpdateSeriesRequest,
+    responses(
+        (status = 200, description = "Series updated successfully", body = SeriesResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - not the series author, or an article of someone else is listed", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Series not found", body = crate::http::dto::error::ErrorResponse),
+        (status = 409, description = "A series with the same slug exists, or an article already belongs to another series", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Validation error", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn update_series(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(slug): Path<Slug>,
+    Json(payload): Json<UpdateSeriesRequest>,
+) -> Result<Json<SeriesResponse>, AppError> {
+    info!(slug = %slug, payload = ?payload, "Update series: {}", slug);
+
+    let command = UpdateSeriesCommand::from_request(payload, slug);
+
+    let series = state
+        .series_service
+        .update_series(command, auth.user_id)
+        .await?;
+    let series = series_item(&state, &series, Some(auth.user_id)).await?;
+
+    Ok(Json(SeriesResponse { series }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/series/{slug}",
+    tag = "Series",
+    params(
+        ("slug" = Slug, Path, description = "Slug of the series to delete, its articles are kept")
+    ),
+    responses(
+        (status = 204, description = "Series deleted successfully"),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - not the series author", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Series not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn delete_series(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(slug): Path<Slug>,
+) -> Result<StatusCode, AppError> {
+    info!(slug = %slug, "Delete series: {}", slug);
+
+    state
+        .series_service
+        .delete_series(&slug, auth.user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,45 @@
+use crate::app_error::AppError;
+use crate::domain::commands::get_stats_query::GetStatsQuery;
+use crate::http::AppState;
+use crate::http::dto::stats::{StatsQuery, StatsResponse};
+use crate::http::extractors::auth_token::AuthToken;
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use tracing::info;
+
+pub(crate) fn stats_routes() -> Router<AppState> {
+    Router::new().route("/user/stats", get(get_stats))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/user/stats",
+    tag = "User",
+    params(StatsQuery),
+    responses(
+        (status = 200, description = "Views, favorites and comments of the current user's articles, in total and per day", body = StatsResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Validation error", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn get_stats(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Query(params): Query<StatsQuery>,
+) -> Result<Json<StatsResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, params = ?params, "Get stats");
+
+    params.validate().map_err(AppError::BadData)?;
+
+    let query = GetStatsQuery::from_request(params, auth.user_id);
+
+    let articles = state.stats_service.get_article_stats(query.user_id).await?;
+    let daily = state.stats_service.get_daily_article_stats(&query).await?;
+
+    Ok(Json(StatsResponse::from_views(
+        query.since,
+        articles,
+        daily,
+    )))
+}
@@ -1,29 +1,171 @@
 use crate::app_error::AppError;
+use crate::domain::commands::list_tags_query::ListTagsQuery;
+use crate::domain::commands::merge_tag_command::MergeTagCommand;
+use crate::domain::commands::update_tag_command::UpdateTagCommand;
 use crate::http::AppState;
-use crate::http::dto::tag::TagsResponse;
-use axum::extract::State;
-use axum::routing::get;
+use crate::http::dto::tag::{
+    MergeTagRequest, TagItem, TagListQuery, TagResponse, TagsResponse, UpdateTagRequest,
+};
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::tag_name::TagName;
+use axum::extract::{Path, Query, State};
+use axum::routing::{delete, get, post, put};
 use axum::{Json, Router};
 use tracing::info;
 
 pub(crate) fn tag_routes() -> Router<AppState> {
-    Router::new().route("/tags", get(get_tags))
+    Router::new()
+        .route("/tags", get(get_tags))
+        .route("/tags/{name}", put(update_tag))
+        .route("/tags/{name}/merge", post(merge_tag))
+        .route("/tags/{name}/follow", post(follow_tag))
+        .route("/tags/{name}/follow", delete(unfollow_tag))
 }
 
 #[utoipa::path(
     get,
     path = "/api/tags",
     tag = "Tags",
+    params(TagListQuery),
     responses(
-        (status = 200, description = "Tags retrieved successfully", body = TagsResponse)
+        (status = 200, description = "Tags used by at least one article retrieved successfully", body = TagsResponse)
     )
 )]
 pub(crate) async fn get_tags(
     State(state): State<AppState>,
+    auth: Option<AuthToken>,
+    Query(params): Query<TagListQuery>,
 ) -> Result<Json<TagsResponse>, AppError> {
-    info!("Get tags");
+    let maybe_user_id = auth.as_ref().map(|u| u.user_id);
 
-    let tags = state.tag_service.get_all_tags().await?;
+    info!(user_id = ?maybe_user_id, sort = ?params.sort, limit = ?params.limit, "Get tags");
 
-    Ok(Json(TagsResponse { tags }))
+    params.validate().map_err(AppError::BadData)?;
+
+    let query = ListTagsQuery::from_request(params, maybe_user_id);
+
+    let tags = state.tag_service.list_tags(query).await?;
+
+    Ok(Json(TagsResponse::from_tag_views(tags)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/tags/{name}",
+    tag = "Tags",
+    params(
+        ("name" = TagName, Path, description = "Name of the tag to rename or describe")
+    ),
+    request_body = UpdateTagRequest,
+    responses(
+        (status = 200, description = "Tag updated successfully", body = TagResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - only admins can manage tags", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Tag not found", body = crate::http::dto::error::ErrorResponse),
+        (status = 409, description = "A tag with the new name already exists", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Validation error", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn update_tag(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(name): Path<TagName>,
+    Json(payload): Json<UpdateTagRequest>,
+) -> Result<Json<TagResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, tag = %name, "Update tag");
+
+    let command = UpdateTagCommand::from_request(payload, name, auth.user_id);
+
+    let tag = state.tag_service.update_tag(command).await?;
+
+    Ok(Json(TagResponse {
+        tag: TagItem::from_tag_view(tag),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tags/{name}/merge",
+    tag = "Tags",
+    params(
+        ("name" = TagName, Path, description = "Name of the tag merged away")
+    ),
+    request_body = MergeTagRequest,
+    responses(
+        (status = 200, description = "Tags merged successfully, the response is the remaining tag", body = TagResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 403, description = "Forbidden - only admins can manage tags", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Tag not found", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Validation error", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn merge_tag(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(name): Path<TagName>,
+    Json(payload): Json<MergeTagRequest>,
+) -> Result<Json<TagResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, tag = %name, into = %payload.tag.into, "Merge tag");
+
+    let command = MergeTagCommand::from_request(payload, name, auth.user_id);
+
+    let tag = state.tag_service.merge_tag(command).await?;
+
+    Ok(Json(TagResponse {
+        tag: TagItem::from_tag_view(tag),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tags/{name}/follow",
+    tag = "Tags",
+    params(
+        ("name" = TagName, Path, description = "Name of the tag to follow")
+    ),
+    responses(
+        (status = 200, description = "Tag followed successfully", body = TagResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Tag not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn follow_tag(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(name): Path<TagName>,
+) -> Result<Json<TagResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, tag = %name, "Follow tag: {}", name);
+
+    let tag = state.tag_service.follow_tag(auth.user_id, &name).await?;
+
+    Ok(Json(TagResponse {
+        tag: TagItem::from_tag_view(tag),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/tags/{name}/follow",
+    tag = "Tags",
+    params(
+        ("name" = TagName, Path, description = "Name of the tag to unfollow")
+    ),
+    responses(
+        (status = 200, description = "Tag unfollowed successfully", body = TagResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Tag not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn unfollow_tag(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(name): Path<TagName>,
+) -> Result<Json<TagResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, tag = %name, "Unfollow tag: {}", name);
+
+    let tag = state.tag_service.unfollow_tag(auth.user_id, &name).await?;
+
+    Ok(Json(TagResponse {
+        tag: TagItem::from_tag_view(tag),
+    }))
 }
@@ -0,0 +1,113 @@
+use crate::app_error::AppError;
+use crate::http::AppState;
+use crate::http::dto::upload::{UploadItem, UploadResponse};
+use crate::http::extractors::auth_token::AuthToken;
+use axum::extract::{DefaultBodyLimit, Multipart, Path, State};
+use axum::http::StatusCode;
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, X_CONTENT_TYPE_OPTIONS};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use tracing::info;
+
+pub(crate) fn upload_routes() -> Router<AppState> {
+    Router::new()
+        // The size limit is enforced while reading the file, it's configurable.
+        .route(
+            "/uploads",
+            post(upload_image).layer(DefaultBodyLimit::disable()),
+        )
+        .route("/uploads/{key}", get(get_upload))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/uploads",
+    tag = "Uploads",
+    request_body(content = crate::http::dto::upload::UploadForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Image stored, its URLs can be used as avatar or in article bodies", body = UploadResponse),
+        (status = 400, description = "Malformed multipart body", body = String),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Missing, too large or unsupported image", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn upload_image(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<UploadResponse>), AppError> {
+    let max_bytes = state.upload_service.max_bytes();
+    let mut file = None;
+
+    while let Some(mut field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadData(e.body_text()))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = field
+            .chunk()
+            .await
+            .map_err(|e| AppError::BadData(e.body_text()))?
+        {
+            if bytes.len() + chunk.len() > max_bytes {
+                return Err(AppError::BadData(format!(
+                    "Image cannot be larger than {} bytes",
+                    max_bytes
+                )));
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+        file = Some(bytes);
+    }
+
+    let bytes = file.ok_or(AppError::BadData("Missing file field".to_string()))?;
+
+    info!(user_id = %{auth.user_id}, size = bytes.len(), "Upload image");
+
+    let image = state.upload_service.upload_image(bytes).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(UploadResponse {
+            upload: UploadItem::from_image(image),
+        }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/uploads/{key}",
+    tag = "Uploads",
+    params(
+        ("key" = String, Path, description = "Key of the uploaded image or thumbnail")
+    ),
+    responses(
+        (status = 200, description = "Image content, cacheable forever since keys are never reused", content_type = "image/*", body = Vec<u8>),
+        (status = 404, description = "Upload not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn get_upload(
+    State(state): State<AppState>,
+    Path(key): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let object = state.upload_service.get(&key).await?;
+
+    Ok((
+        [
+            (CONTENT_TYPE, object.content_type),
+            (
+                CACHE_CONTROL,
+                "public, max-age=31536000, immutable".to_string(),
+            ),
+            // Browsers stick to the stored type rather than guessing one from the content.
+            (X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+        ],
+        object.bytes,
+    ))
+}
@@ -35,12 +35,19 @@ pub(crate) async fn get_current_user(
         .await?
         .ok_or(AppError::Unauthorized)?;
 
+    let followed_tags = app_state
+        .tag_service
+        .get_followed_tags(auth_user.user_id)
+        .await?;
+
     let user = UserData {
         email: user.email,
         token: auth_user.raw_token,
         username: user.username,
         bio: user.bio,
         image: user.image,
+        private: user.private,
+        followed_tags,
     };
 
     Ok(Json(UserResponse { user }))
@@ -68,7 +75,9 @@ pub(crate) async fn update_user(
 
     let user = app_state.user_service.update_user(command).await?;
 
-    let user_date = UserData::new(user, auth_user.raw_token);
+    let followed_tags = app_state.tag_service.get_followed_tags(user.id).await?;
+
+    let user_date = UserData::new(user, auth_user.raw_token, followed_tags);
 
     Ok(Json(UserResponse { user: user_date }))
 }
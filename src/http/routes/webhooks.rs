@@ -0,0 +1,152 @@
+use crate::app_error::AppError;
+use crate::domain::commands::create_webhook_command::CreateWebhookCommand;
+use crate::domain::commands::list_webhook_deliveries_query::ListWebhookDeliveriesQuery;
+use crate::http::AppState;
+use crate::http::dto::webhook::{
+    CreateWebhookRequest, WebhookDeliveriesResponse, WebhookDeliveryItem, WebhookDeliveryListQuery,
+    WebhookItem, WebhookResponse, WebhooksResponse,
+};
+use crate::http::extractors::auth_token::AuthToken;
+use crate::model::values::webhook_id::WebhookId;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use tracing::info;
+
+pub(crate) fn webhook_routes() -> Router<AppState> {
+    Router::new()
+        .route("/webhooks", post(create_webhook))
+        .route("/webhooks", get(list_webhooks))
+        .route("/webhooks/{id}", delete(delete_webhook))
+        .route("/webhooks/{id}/deliveries", get(list_webhook_deliveries))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/webhooks",
+    tag = "Webhooks",
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = 201, description = "Webhook created successfully, the response includes the signing secret", body = WebhookResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 422, description = "Validation error", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn create_webhook(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Json(payload): Json<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<WebhookResponse>), AppError> {
+    info!(user_id = %{auth.user_id}, url = %payload.webhook.url, events = ?payload.webhook.events, "Create webhook");
+
+    let command = CreateWebhookCommand::from_request(payload, auth.user_id);
+
+    let webhook = state.webhook_service.create_webhook(command).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(WebhookResponse {
+            webhook: WebhookItem::with_secret(webhook),
+        }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/webhooks",
+    tag = "Webhooks",
+    responses(
+        (status = 200, description = "Webhooks retrieved successfully", body = WebhooksResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn list_webhooks(
+    State(state): State<AppState>,
+    auth: AuthToken,
+) -> Result<Json<WebhooksResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, "List webhooks");
+
+    let webhooks = state
+        .webhook_service
+        .list_webhooks(auth.user_id)
+        .await?
+        .into_iter()
+        .map(WebhookItem::from_webhook)
+        .collect();
+
+    Ok(Json(WebhooksResponse { webhooks }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/webhooks/{id}",
+    tag = "Webhooks",
+    params(
+        ("id" = WebhookId, Path, description = "ID of the webhook to delete")
+    ),
+    responses(
+        (status = 204, description = "Webhook deleted successfully"),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Webhook not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn delete_webhook(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(webhook_id): Path<WebhookId>,
+) -> Result<StatusCode, AppError> {
+    info!(user_id = %{auth.user_id}, webhook_id = %webhook_id, "Delete webhook: {}", webhook_id);
+
+    state
+        .webhook_service
+        .delete_webhook(auth.user_id, webhook_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/{id}/deliveries",
+    tag = "Webhooks",
+    params(
+        ("id" = WebhookId, Path, description = "ID of the webhook"),
+        WebhookDeliveryListQuery
+    ),
+    responses(
+        (status = 200, description = "Deliveries retrieved successfully, newest first", body = WebhookDeliveriesResponse),
+        (status = 401, description = "Unauthorized - token missing or invalid", body = crate::http::dto::error::ErrorResponse),
+        (status = 404, description = "Webhook not found", body = crate::http::dto::error::ErrorResponse)
+    )
+)]
+pub(crate) async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    auth: AuthToken,
+    Path(webhook_id): Path<WebhookId>,
+    Query(params): Query<WebhookDeliveryListQuery>,
+) -> Result<Json<WebhookDeliveriesResponse>, AppError> {
+    info!(user_id = %{auth.user_id}, webhook_id = %webhook_id, params = ?params, "List webhook deliveries: {}", webhook_id);
+
+    params.validate().map_err(AppError::BadData)?;
+
+    let query = ListWebhookDeliveriesQuery::from_request(params, webhook_id, auth.user_id);
+
+    let deliveries = state
+        .webhook_service
+        .list_deliveries(query)
+        .await?
+        .into_iter()
+        .map(WebhookDeliveryItem::from_delivery)
+        .collect();
+
+    let deliveries_count = state
+        .webhook_service
+        .count_deliveries(auth.user_id, webhook_id)
+        .await?;
+
+    Ok(Json(WebhookDeliveriesResponse {
+        deliveries,
+        deliveries_count,
+    }))
+}
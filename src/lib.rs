@@ -3,10 +3,12 @@ mod app_error;
 pub mod application;
 pub mod database;
 mod domain;
+mod events;
 pub mod http;
 mod model;
 pub mod openapi;
 mod persistence;
 mod server;
+mod storage;
 pub mod tracing;
 mod utils;
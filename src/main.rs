@@ -5,11 +5,13 @@ mod app_error;
 mod application;
 mod database;
 mod domain;
+mod events;
 mod http;
 mod model;
 mod openapi;
 mod persistence;
 mod server;
+mod storage;
 mod tracing;
 mod utils;
 
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum BodyFormat {
+    /// The markdown body only.
+    #[default]
+    Markdown,
+    /// The markdown body along with its rendering as sanitized HTML.
+    Html,
+}
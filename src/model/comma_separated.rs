@@ -0,0 +1,49 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Display;
+
+/// A list passed as a single comma separated query parameter, e.g. `?tags=rust,web`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommaSeparated<T>(Vec<T>);
+
+impl<T> CommaSeparated<T> {
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> TryFrom<String> for CommaSeparated<T>
+where
+    T: TryFrom<String, Error = String>,
+{
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value
+            .split(',')
+            .filter(|item| !item.trim().is_empty())
+            .map(|item| T::try_from(item.to_string()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(CommaSeparated)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for CommaSeparated<T>
+where
+    T: TryFrom<String, Error = String>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        CommaSeparated::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<T: Display> Serialize for CommaSeparated<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let items: Vec<String> = self.0.iter().map(|item| item.to_string()).collect();
+        serializer.serialize_str(&items.join(","))
+    }
+}
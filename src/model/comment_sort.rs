@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentSort {
+    /// Oldest comments first, in the order of the conversation.
+    Oldest,
+    /// Most recent comments first.
+    #[default]
+    Newest,
+    /// Comments with the most reactions first.
+    Top,
+}
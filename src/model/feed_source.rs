@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum FeedSource {
+    /// Articles written by followed users.
+    #[default]
+    Authors,
+    /// Articles tagged with followed tags.
+    Tags,
+    /// Articles from both followed users and followed tags.
+    All,
+}
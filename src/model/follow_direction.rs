@@ -0,0 +1,8 @@
+/// Which side of follow relationships a list of profiles is taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FollowDirection {
+    /// Users following the given user.
+    Followers,
+    /// Users the given user follows.
+    Following,
+}
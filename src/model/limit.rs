@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+/// Most items a single page can be asked for.
+pub const MAX_LIMIT: u64 = 100;
+
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, ToSchema)]
 pub struct Limit(u64);
 
@@ -12,6 +15,15 @@ impl Limit {
     pub(crate) fn value(&self) -> u64 {
         self.0
     }
+
+    /// Rejects pages larger than [`MAX_LIMIT`] rather than quietly serving fewer items.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if self.0 > MAX_LIMIT {
+            Err(format!("limit cannot be greater than {MAX_LIMIT}"))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl Default for Limit {
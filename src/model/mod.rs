@@ -1,6 +1,16 @@
+pub(crate) mod article_sort;
+pub(crate) mod body_format;
+pub(crate) mod comma_separated;
+pub(crate) mod comment_sort;
+pub(crate) mod feed_source;
+pub(crate) mod follow_direction;
 pub(crate) mod indexed_article_field;
 pub(crate) mod indexed_user_field;
 pub(crate) mod limit;
 pub(crate) mod offset;
 pub(crate) mod persistence;
+pub(crate) mod tag_sort;
+pub(crate) mod trending_window;
+pub(crate) mod uploaded_image;
 pub(crate) mod values;
+pub(crate) mod viewer;
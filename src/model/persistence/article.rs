@@ -17,6 +17,7 @@ pub struct Article {
     pub author_id: UserId,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub comments_locked: bool,
 }
 
 impl Article {
@@ -30,6 +31,7 @@ impl Article {
             author_id: row.get("author_id"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
+            comments_locked: row.get("comments_locked"),
         }
     }
 }
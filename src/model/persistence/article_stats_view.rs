@@ -0,0 +1,50 @@
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::slug::Slug;
+use chrono::NaiveDate;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+/// All time counts of an article.
+pub struct ArticleStatsView {
+    pub id: ArticleId,
+    pub slug: Slug,
+    pub title: ArticleTitle,
+    pub views_count: i64,
+    pub favorites_count: i64,
+    pub comments_count: i64,
+}
+
+impl ArticleStatsView {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            slug: row.get("slug"),
+            title: row.get("title"),
+            views_count: row.get("views_count"),
+            favorites_count: row.get("favorites_count"),
+            comments_count: row.get("comments_count"),
+        }
+    }
+}
+
+/// Views, favorites and comments an article got on a given day (UTC).
+pub struct DailyArticleStatsView {
+    pub article_id: ArticleId,
+    pub day: NaiveDate,
+    pub views: i64,
+    pub favorites: i64,
+    pub comments: i64,
+}
+
+impl DailyArticleStatsView {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            article_id: row.get("article_id"),
+            day: row.get("day"),
+            views: row.get("views"),
+            favorites: row.get("favorites"),
+            comments: row.get("comments"),
+        }
+    }
+}
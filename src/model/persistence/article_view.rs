@@ -1,3 +1,5 @@
+use crate::model::persistence::co_author_view::CoAuthorView;
+use crate::model::persistence::reaction_count::ReactionCount;
 use crate::model::values::article_body::ArticleBody;
 use crate::model::values::article_description::ArticleDescription;
 use crate::model::values::article_id::ArticleId;
@@ -21,12 +23,20 @@ pub struct ArticleView {
     pub updated_at: DateTime<Utc>,
     pub favorited: bool,
     pub favorites_count: i64,
+    pub comments_count: i64,
+    pub comments_locked: bool,
+    pub bookmarked: bool,
+    pub views_count: i64,
     pub author_id: UserId,
     pub author: Username,
     pub author_bio: Option<Bio>,
     pub author_image: Option<Image>,
     pub following: bool,
     pub body: ArticleBody,
+    /// Loaded separately from the article, in the order they were added.
+    pub co_authors: Vec<CoAuthorView>,
+    /// Loaded separately from the article, only the reactions it received.
+    pub reactions: Vec<ReactionCount>,
 }
 
 impl ArticleView {
@@ -41,12 +51,18 @@ impl ArticleView {
             updated_at: row.get("updated_at"),
             favorited: row.get("favorited"),
             favorites_count: row.get("favorites_count"),
+            comments_count: row.get("comments_count"),
+            comments_locked: row.get("comments_locked"),
+            bookmarked: row.get("bookmarked"),
+            views_count: row.get("views_count"),
             author_id: row.get("author_id"),
             author: row.get("author_username"),
             author_bio: row.get("author_bio"),
             author_image: row.get("author_image"),
             following: row.get("following"),
             body: row.get("body"),
+            co_authors: Vec::new(),
+            reactions: Vec::new(),
         }
     }
 }
@@ -60,6 +76,8 @@ pub struct ArticleListView {
     pub updated_at: DateTime<Utc>,
     pub favorited: bool,
     pub favorites_count: i64,
+    pub comments_count: i64,
+    pub bookmarked: bool,
     pub author: Username,
     pub author_bio: Option<Bio>,
     pub author_image: Option<Image>,
@@ -77,6 +95,8 @@ impl ArticleListView {
             updated_at: row.get("updated_at"),
             favorited: row.get("favorited"),
             favorites_count: row.get("favorites_count"),
+            comments_count: row.get("comments_count"),
+            bookmarked: row.get("bookmarked"),
             author: row.get("author_username"),
             author_bio: row.get("author_bio"),
             author_image: row.get("author_image"),
@@ -0,0 +1,24 @@
+use crate::model::persistence::article_view::ArticleListView;
+use crate::model::values::bookmark_folder::BookmarkFolder;
+use crate::model::values::bookmark_note::BookmarkNote;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct BookmarkView {
+    pub folder: Option<BookmarkFolder>,
+    pub note: Option<BookmarkNote>,
+    pub bookmarked_at: DateTime<Utc>,
+    pub article: ArticleListView,
+}
+
+impl BookmarkView {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            folder: row.get("bookmark_folder"),
+            note: row.get("bookmark_note"),
+            bookmarked_at: row.get("bookmarked_at"),
+            article: ArticleListView::from_row(row),
+        }
+    }
+}
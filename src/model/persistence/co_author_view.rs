@@ -0,0 +1,23 @@
+use crate::model::values::bio::Bio;
+use crate::model::values::image::Image;
+use crate::model::values::username::Username;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct CoAuthorView {
+    pub username: Username,
+    pub bio: Option<Bio>,
+    pub image: Option<Image>,
+    pub following: bool,
+}
+
+impl CoAuthorView {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            username: row.get("username"),
+            bio: row.get("bio"),
+            image: row.get("image"),
+            following: row.get("following"),
+        }
+    }
+}
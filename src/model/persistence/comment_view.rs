@@ -1,3 +1,4 @@
+use crate::model::persistence::reaction_count::ReactionCount;
 use crate::model::values::bio::Bio;
 use crate::model::values::comment_body::CommentBody;
 use crate::model::values::comment_id::CommentId;
@@ -15,6 +16,8 @@ pub struct CommentView {
     pub author_bio: Option<Bio>,
     pub author_image: Option<Image>,
     pub following: bool,
+    /// Loaded separately from the comment, only the reactions it received.
+    pub reactions: Vec<ReactionCount>,
 }
 
 impl CommentView {
@@ -28,6 +31,7 @@ impl CommentView {
             author_bio: row.get("author_bio"),
             author_image: row.get("author_image"),
             following: row.get("following"),
+            reactions: Vec::new(),
         }
     }
 }
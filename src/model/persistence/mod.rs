@@ -1,6 +1,20 @@
 pub mod article;
+pub mod article_stats_view;
 pub mod article_view;
+pub mod bookmark_view;
+pub mod co_author_view;
 pub mod comment;
 pub mod comment_view;
+pub mod notification_preferences;
+pub mod notification_view;
+pub mod outbox_event;
+pub mod profile_view;
+pub mod reaction_count;
+pub mod series;
+pub mod series_navigation;
+pub mod series_view;
 pub mod tag;
+pub mod tag_view;
 pub mod user;
+pub mod webhook;
+pub mod webhook_delivery;
@@ -0,0 +1,42 @@
+use crate::model::values::notification_kind::NotificationKind;
+use crate::model::values::user_id::UserId;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct NotificationPreferences {
+    pub user_id: UserId,
+    pub article_commented: bool,
+    pub article_favorited: bool,
+    pub user_followed: bool,
+}
+
+impl NotificationPreferences {
+    pub fn default_for(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            article_commented: true,
+            article_favorited: true,
+            user_followed: true,
+        }
+    }
+
+    pub fn is_enabled(&self, kind: NotificationKind) -> bool {
+        match kind {
+            NotificationKind::ArticleCommented => self.article_commented,
+            NotificationKind::ArticleFavorited => self.article_favorited,
+            // Follow requests share the preference of follows.
+            NotificationKind::UserFollowed | NotificationKind::FollowRequested => {
+                self.user_followed
+            }
+        }
+    }
+
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            user_id: row.get("user_id"),
+            article_commented: row.get("article_commented"),
+            article_favorited: row.get("article_favorited"),
+            user_followed: row.get("user_followed"),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::bio::Bio;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::image::Image;
+use crate::model::values::notification_id::NotificationId;
+use crate::model::values::notification_kind::NotificationKind;
+use crate::model::values::slug::Slug;
+use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+
+pub struct NotificationView {
+    pub id: NotificationId,
+    pub kind: NotificationKind,
+    pub article_slug: Option<Slug>,
+    pub article_title: Option<ArticleTitle>,
+    pub comment_id: Option<CommentId>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub actor: Username,
+    pub actor_bio: Option<Bio>,
+    pub actor_image: Option<Image>,
+    pub following: bool,
+}
+
+impl NotificationView {
+    pub fn from_row(row: sqlx::postgres::PgRow) -> NotificationView {
+        NotificationView {
+            id: row.get("id"),
+            kind: NotificationKind::try_from(row.get::<String, _>("kind"))
+                .expect("Notification kind is constrained by the database"),
+            article_slug: row.get("article_slug"),
+            article_title: row.get("article_title"),
+            comment_id: row.get("comment_id"),
+            read_at: row.get("read_at"),
+            created_at: row.get("created_at"),
+            actor: row.get("actor_username"),
+            actor_bio: row.get("actor_bio"),
+            actor_image: row.get("actor_image"),
+            following: row.get("following"),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::events::app_event::AppEvent;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct OutboxEvent {
+    pub id: i64,
+    pub event: AppEvent,
+    pub attempts: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OutboxEvent {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            event: serde_json::from_value(row.get("payload"))
+                .expect("Outbox events are written by the application"),
+            attempts: row.get("attempts"),
+            created_at: row.get("created_at"),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::model::values::bio::Bio;
+use crate::model::values::image::Image;
+use crate::model::values::user_id::UserId;
+use crate::model::values::username::Username;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct ProfileView {
+    pub id: UserId,
+    pub username: Username,
+    pub bio: Option<Bio>,
+    pub image: Option<Image>,
+    pub private: bool,
+    pub following: bool,
+    /// Whether the viewer has a pending request to follow this user.
+    pub follow_requested: bool,
+    pub blocking: bool,
+    pub muting: bool,
+    /// Whether this user blocks the viewer.
+    pub blocked_by: bool,
+    pub followers_count: i64,
+    pub following_count: i64,
+    pub articles_count: i64,
+}
+
+impl ProfileView {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            username: row.get("username"),
+            bio: row.get("bio"),
+            image: row.get("image"),
+            private: row.get("private"),
+            following: row.get("following"),
+            follow_requested: row.get("follow_requested"),
+            blocking: row.get("blocking"),
+            muting: row.get("muting"),
+            blocked_by: row.get("blocked_by"),
+            followers_count: row.get("followers_count"),
+            following_count: row.get("following_count"),
+            articles_count: row.get("articles_count"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::model::values::reaction_kind::ReactionKind;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct ReactionCount {
+    pub kind: ReactionKind,
+    pub count: i64,
+    /// Whether the current user is one of those who reacted.
+    pub reacted: bool,
+}
+
+impl ReactionCount {
+    pub fn from_row(row: &PgRow) -> Self {
+        Self {
+            kind: ReactionKind::try_from(row.get::<String, _>("kind"))
+                .expect("Reaction kinds are constrained by the database"),
+            count: row.get("count"),
+            reacted: row.get("reacted"),
+        }
+    }
+}
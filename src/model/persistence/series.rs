@@ -0,0 +1,32 @@
+use crate::model::values::series_description::SeriesDescription;
+use crate::model::values::series_id::SeriesId;
+use crate::model::values::series_title::SeriesTitle;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct Series {
+    pub id: SeriesId,
+    pub slug: Slug,
+    pub title: SeriesTitle,
+    pub description: Option<SeriesDescription>,
+    pub owner_id: UserId,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Series {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            slug: row.get("slug"),
+            title: row.get("title"),
+            description: row.get("description"),
+            owner_id: row.get("owner_id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+}
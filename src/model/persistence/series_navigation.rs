@@ -0,0 +1,42 @@
+use crate::model::values::article_title::ArticleTitle;
+use crate::model::values::series_title::SeriesTitle;
+use crate::model::values::slug::Slug;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+/// Where an article stands in its series.
+pub struct SeriesNavigation {
+    pub slug: Slug,
+    pub title: SeriesTitle,
+    /// Starts at 1.
+    pub position: i64,
+    pub articles_count: i64,
+    pub previous: Option<SeriesNeighbor>,
+    pub next: Option<SeriesNeighbor>,
+}
+
+pub struct SeriesNeighbor {
+    pub slug: Slug,
+    pub title: ArticleTitle,
+}
+
+impl SeriesNavigation {
+    pub fn from_row(row: PgRow) -> Self {
+        let neighbor = |prefix: &str| {
+            row.get::<Option<Slug>, _>(format!("{prefix}_slug").as_str())
+                .map(|slug| SeriesNeighbor {
+                    slug,
+                    title: row.get(format!("{prefix}_title").as_str()),
+                })
+        };
+
+        Self {
+            slug: row.get("series_slug"),
+            title: row.get("series_title"),
+            position: row.get("position"),
+            articles_count: row.get("articles_count"),
+            previous: neighbor("previous"),
+            next: neighbor("next"),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::model::values::bio::Bio;
+use crate::model::values::image::Image;
+use crate::model::values::series_description::SeriesDescription;
+use crate::model::values::series_id::SeriesId;
+use crate::model::values::series_title::SeriesTitle;
+use crate::model::values::slug::Slug;
+use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct SeriesView {
+    pub id: SeriesId,
+    pub slug: Slug,
+    pub title: SeriesTitle,
+    pub description: Option<SeriesDescription>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub owner: Username,
+    pub owner_bio: Option<Bio>,
+    pub owner_image: Option<Image>,
+    pub following: bool,
+}
+
+impl SeriesView {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            slug: row.get("slug"),
+            title: row.get("title"),
+            description: row.get("description"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            owner: row.get("owner_username"),
+            owner_bio: row.get("owner_bio"),
+            owner_image: row.get("owner_image"),
+            following: row.get("following"),
+        }
+    }
+}
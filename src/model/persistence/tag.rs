@@ -1,3 +1,4 @@
+use crate::model::values::tag_description::TagDescription;
 use crate::model::values::tag_id::TagId;
 use crate::model::values::tag_name::TagName;
 use chrono::{DateTime, Utc};
@@ -7,6 +8,7 @@ use sqlx::postgres::PgRow;
 pub struct Tag {
     pub id: TagId,
     pub name: TagName,
+    pub description: Option<TagDescription>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -15,6 +17,7 @@ impl Tag {
         Self {
             id: row.get("id"),
             name: row.get("name"),
+            description: row.get("description"),
             created_at: row.get("created_at"),
         }
     }
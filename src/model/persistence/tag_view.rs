@@ -0,0 +1,22 @@
+use crate::model::values::tag_description::TagDescription;
+use crate::model::values::tag_name::TagName;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct TagView {
+    pub name: TagName,
+    pub description: Option<TagDescription>,
+    pub articles_count: i64,
+    pub following: bool,
+}
+
+impl TagView {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            name: row.get("name"),
+            description: row.get("description"),
+            articles_count: row.get("articles_count"),
+            following: row.get("following"),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::model::values::image::Image;
 use crate::model::values::password_hash::PasswordHash;
 use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
 use sqlx::Row;
 use sqlx::postgres::PgRow;
 
@@ -14,6 +15,8 @@ pub struct User {
     pub username: Username,
     pub bio: Option<Bio>,
     pub image: Option<Image>,
+    pub private: bool,
+    pub username_changed_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -25,6 +28,8 @@ impl User {
             password_hash: row.get("password_hash"),
             bio: row.get("bio"),
             image: row.get("image"),
+            private: row.get("private"),
+            username_changed_at: row.get("username_changed_at"),
         }
     }
 }
@@ -0,0 +1,36 @@
+use crate::model::values::user_id::UserId;
+use crate::model::values::webhook_event::WebhookEvent;
+use crate::model::values::webhook_id::WebhookId;
+use crate::model::values::webhook_url::WebhookUrl;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+pub struct Webhook {
+    pub id: WebhookId,
+    pub owner_id: UserId,
+    pub url: WebhookUrl,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            owner_id: row.get("owner_id"),
+            url: row.get("url"),
+            secret: row.get("secret"),
+            events: row
+                .get::<Vec<String>, _>("events")
+                .into_iter()
+                .map(|event| {
+                    WebhookEvent::try_from(event)
+                        .expect("Webhook events are constrained by the database")
+                })
+                .collect(),
+            created_at: row.get("created_at"),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::model::values::webhook_delivery_id::WebhookDeliveryId;
+use crate::model::values::webhook_delivery_status::WebhookDeliveryStatus;
+use crate::model::values::webhook_event::WebhookEvent;
+use crate::model::values::webhook_url::WebhookUrl;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+
+fn event_from_row(row: &PgRow) -> WebhookEvent {
+    WebhookEvent::try_from(row.get::<String, _>("event"))
+        .expect("Webhook delivery event is written by the application")
+}
+
+pub struct WebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub event: WebhookEvent,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub last_response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDelivery {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            event: event_from_row(&row),
+            status: WebhookDeliveryStatus::try_from(row.get::<String, _>("status"))
+                .expect("Webhook delivery status is constrained by the database"),
+            attempts: row.get("attempts"),
+            last_response_status: row.get("last_response_status"),
+            last_error: row.get("last_error"),
+            next_attempt_at: row.get("next_attempt_at"),
+            created_at: row.get("created_at"),
+            delivered_at: row.get("delivered_at"),
+        }
+    }
+}
+
+/// A delivery claimed by the dispatcher, together with what's needed to send it.
+pub struct DueWebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub event: WebhookEvent,
+    pub payload: serde_json::Value,
+    pub attempts: i32,
+    pub url: WebhookUrl,
+    pub secret: String,
+}
+
+impl DueWebhookDelivery {
+    pub fn from_row(row: PgRow) -> Self {
+        Self {
+            id: row.get("id"),
+            event: event_from_row(&row),
+            payload: row.get("payload"),
+            attempts: row.get("attempts"),
+            url: row.get("url"),
+            secret: row.get("secret"),
+        }
+    }
+}
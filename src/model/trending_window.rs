@@ -0,0 +1,27 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+pub enum TrendingWindow {
+    #[default]
+    #[serde(rename = "24h")]
+    Day,
+    #[serde(rename = "7d")]
+    Week,
+}
+
+impl TrendingWindow {
+    pub fn value(&self) -> &'static str {
+        match self {
+            TrendingWindow::Day => "24h",
+            TrendingWindow::Week => "7d",
+        }
+    }
+}
+
+impl From<TrendingWindow> for Value {
+    fn from(window: TrendingWindow) -> Self {
+        Value::String(Some(Box::new(window.value().to_string())))
+    }
+}
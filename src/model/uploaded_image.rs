@@ -0,0 +1,13 @@
+use crate::model::values::image::Image;
+
+/// An image stored along with its thumbnail, both reachable at URLs usable as avatars or in
+/// article bodies.
+#[derive(Debug, Clone)]
+pub struct UploadedImage {
+    pub url: Image,
+    pub thumbnail_url: Image,
+    pub content_type: String,
+    pub size: usize,
+    pub width: u32,
+    pub height: u32,
+}
@@ -0,0 +1,74 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use utoipa::ToSchema;
+
+const MAX_BOOKMARK_FOLDER_LENGTH: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "Read later")]
+pub struct BookmarkFolder(String);
+
+impl BookmarkFolder {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for BookmarkFolder {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return Err("Bookmark folder cannot be empty".to_string());
+        }
+
+        if trimmed.len() > MAX_BOOKMARK_FOLDER_LENGTH {
+            return Err(format!(
+                "Bookmark folder cannot be longer than {MAX_BOOKMARK_FOLDER_LENGTH} characters"
+            ));
+        }
+
+        Ok(BookmarkFolder(trimmed.to_string()))
+    }
+}
+
+impl TryFrom<&str> for BookmarkFolder {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.to_string().try_into()
+    }
+}
+
+impl From<BookmarkFolder> for String {
+    fn from(folder: BookmarkFolder) -> String {
+        folder.0
+    }
+}
+
+impl Display for BookmarkFolder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for BookmarkFolder {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<BookmarkFolder> for Value {
+    fn from(d: BookmarkFolder) -> Self {
+        Value::String(Some(Box::new(d.value().to_string())))
+    }
+}
@@ -0,0 +1,70 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use utoipa::ToSchema;
+
+const MAX_BOOKMARK_NOTE_LENGTH: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "Come back to the benchmarks section")]
+pub struct BookmarkNote(String);
+
+impl BookmarkNote {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for BookmarkNote {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let trimmed = value.trim();
+
+        if trimmed.len() > MAX_BOOKMARK_NOTE_LENGTH {
+            return Err(format!(
+                "Bookmark note cannot be longer than {MAX_BOOKMARK_NOTE_LENGTH} characters"
+            ));
+        }
+
+        Ok(BookmarkNote(trimmed.to_string()))
+    }
+}
+
+impl TryFrom<&str> for BookmarkNote {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.to_string().try_into()
+    }
+}
+
+impl From<BookmarkNote> for String {
+    fn from(note: BookmarkNote) -> String {
+        note.0
+    }
+}
+
+impl Display for BookmarkNote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for BookmarkNote {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<BookmarkNote> for Value {
+    fn from(d: BookmarkNote) -> Self {
+        Value::String(Some(Box::new(d.value().to_string())))
+    }
+}
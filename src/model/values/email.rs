@@ -1,3 +1,4 @@
+use crate::utils::unicode_text::normalize;
 use sea_query::Value;
 use serde::{Deserialize, Serialize};
 use sqlx::Type;
@@ -21,9 +22,12 @@ impl Email {
 impl TryFrom<String> for Email {
     type Error = String;
 
+    /// Emails are compared ignoring case, they're stored lowercased.
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        if value.validate_email() {
-            Ok(Email(value))
+        let normalized = normalize(value.trim()).to_lowercase();
+
+        if normalized.validate_email() {
+            Ok(Email(normalized))
         } else {
             Err(format!("Invalid email format: {}", value))
         }
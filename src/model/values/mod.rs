@@ -3,14 +3,28 @@ pub mod article_description;
 pub mod article_id;
 pub mod article_title;
 pub mod bio;
+pub mod bookmark_folder;
+pub mod bookmark_note;
 pub mod comment_body;
 pub mod comment_id;
 pub mod email;
 pub mod image;
+pub mod notification_id;
+pub mod notification_kind;
 pub mod password;
 pub mod password_hash;
+pub mod reaction_kind;
+pub mod series_description;
+pub mod series_id;
+pub mod series_title;
 pub mod slug;
+pub mod tag_description;
 pub mod tag_id;
 pub mod tag_name;
 pub mod user_id;
 pub mod username;
+pub mod webhook_delivery_id;
+pub mod webhook_delivery_status;
+pub mod webhook_event;
+pub mod webhook_id;
+pub mod webhook_url;
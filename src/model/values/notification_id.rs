@@ -0,0 +1,41 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[schema(value_type = String, format = "uuid")]
+pub struct NotificationId(Uuid);
+
+impl NotificationId {
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for NotificationId {
+    fn from(id: Uuid) -> Self {
+        NotificationId(id)
+    }
+}
+
+impl From<NotificationId> for Uuid {
+    fn from(id: NotificationId) -> Uuid {
+        id.0
+    }
+}
+
+impl Display for NotificationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<NotificationId> for Value {
+    fn from(id: NotificationId) -> Self {
+        Value::Uuid(Some(Box::new(id.value())))
+    }
+}
@@ -0,0 +1,51 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum NotificationKind {
+    ArticleCommented,
+    ArticleFavorited,
+    UserFollowed,
+    /// Someone asked to follow a private account.
+    FollowRequested,
+}
+
+impl NotificationKind {
+    pub fn value(&self) -> &'static str {
+        match self {
+            NotificationKind::ArticleCommented => "article_commented",
+            NotificationKind::ArticleFavorited => "article_favorited",
+            NotificationKind::UserFollowed => "user_followed",
+            NotificationKind::FollowRequested => "follow_requested",
+        }
+    }
+}
+
+impl TryFrom<String> for NotificationKind {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "article_commented" => Ok(NotificationKind::ArticleCommented),
+            "article_favorited" => Ok(NotificationKind::ArticleFavorited),
+            "user_followed" => Ok(NotificationKind::UserFollowed),
+            "follow_requested" => Ok(NotificationKind::FollowRequested),
+            _ => Err(format!("Unknown notification kind: {}", value)),
+        }
+    }
+}
+
+impl Display for NotificationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl From<NotificationKind> for Value {
+    fn from(kind: NotificationKind) -> Self {
+        Value::String(Some(Box::new(kind.value().to_string())))
+    }
+}
@@ -0,0 +1,48 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+
+/// Reactions to articles and comments, favoriting an article is the `like` reaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ReactionKind {
+    Like,
+    Insightful,
+    Funny,
+}
+
+impl ReactionKind {
+    pub fn value(&self) -> &'static str {
+        match self {
+            ReactionKind::Like => "like",
+            ReactionKind::Insightful => "insightful",
+            ReactionKind::Funny => "funny",
+        }
+    }
+}
+
+impl TryFrom<String> for ReactionKind {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "like" => Ok(ReactionKind::Like),
+            "insightful" => Ok(ReactionKind::Insightful),
+            "funny" => Ok(ReactionKind::Funny),
+            _ => Err(format!("Unknown reaction kind: {}", value)),
+        }
+    }
+}
+
+impl Display for ReactionKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl From<ReactionKind> for Value {
+    fn from(kind: ReactionKind) -> Self {
+        Value::String(Some(Box::new(kind.value().to_string())))
+    }
+}
@@ -0,0 +1,70 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use utoipa::ToSchema;
+
+const MAX_SERIES_DESCRIPTION_LENGTH: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "A step by step tutorial, from an empty project to production")]
+pub struct SeriesDescription(String);
+
+impl SeriesDescription {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for SeriesDescription {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let trimmed = value.trim();
+
+        if trimmed.len() > MAX_SERIES_DESCRIPTION_LENGTH {
+            return Err(format!(
+                "Series description cannot be longer than {MAX_SERIES_DESCRIPTION_LENGTH} characters"
+            ));
+        }
+
+        Ok(SeriesDescription(trimmed.to_string()))
+    }
+}
+
+impl TryFrom<&str> for SeriesDescription {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.to_string().try_into()
+    }
+}
+
+impl From<SeriesDescription> for String {
+    fn from(description: SeriesDescription) -> String {
+        description.0
+    }
+}
+
+impl Display for SeriesDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for SeriesDescription {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<SeriesDescription> for Value {
+    fn from(d: SeriesDescription) -> Self {
+        Value::String(Some(Box::new(d.value().to_string())))
+    }
+}
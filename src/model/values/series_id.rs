@@ -0,0 +1,41 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[schema(value_type = String, format = "uuid")]
+pub struct SeriesId(Uuid);
+
+impl SeriesId {
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for SeriesId {
+    fn from(id: Uuid) -> Self {
+        SeriesId(id)
+    }
+}
+
+impl From<SeriesId> for Uuid {
+    fn from(id: SeriesId) -> Uuid {
+        id.0
+    }
+}
+
+impl Display for SeriesId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<SeriesId> for Value {
+    fn from(id: SeriesId) -> Self {
+        Value::Uuid(Some(Box::new(id.value())))
+    }
+}
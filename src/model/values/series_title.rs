@@ -0,0 +1,70 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "Building a Web Application with Rust")]
+pub struct SeriesTitle(String);
+
+impl SeriesTitle {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for SeriesTitle {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return Err("Series title cannot be blank".to_string());
+        }
+
+        if trimmed.len() > 255 {
+            return Err("Series title cannot be longer than 255 characters".to_string());
+        }
+
+        Ok(SeriesTitle(trimmed.to_string()))
+    }
+}
+
+impl TryFrom<&str> for SeriesTitle {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.to_string().try_into()
+    }
+}
+
+impl From<SeriesTitle> for String {
+    fn from(title: SeriesTitle) -> String {
+        title.0
+    }
+}
+
+impl Display for SeriesTitle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for SeriesTitle {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<SeriesTitle> for Value {
+    fn from(t: SeriesTitle) -> Self {
+        Value::String(Some(Box::new(t.value().to_string())))
+    }
+}
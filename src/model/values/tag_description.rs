@@ -0,0 +1,70 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use utoipa::ToSchema;
+
+const MAX_TAG_DESCRIPTION_LENGTH: usize = 500;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "Posts about the Rust programming language")]
+pub struct TagDescription(String);
+
+impl TagDescription {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TagDescription {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let trimmed = value.trim();
+
+        if trimmed.len() > MAX_TAG_DESCRIPTION_LENGTH {
+            return Err(format!(
+                "Tag description cannot be longer than {MAX_TAG_DESCRIPTION_LENGTH} characters"
+            ));
+        }
+
+        Ok(TagDescription(trimmed.to_string()))
+    }
+}
+
+impl TryFrom<&str> for TagDescription {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.to_string().try_into()
+    }
+}
+
+impl From<TagDescription> for String {
+    fn from(description: TagDescription) -> String {
+        description.0
+    }
+}
+
+impl Display for TagDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for TagDescription {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<TagDescription> for Value {
+    fn from(d: TagDescription) -> Self {
+        Value::String(Some(Box::new(d.value().to_string())))
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::unicode_text::{check_confusable, normalize, skeleton};
 use sea_query::Value;
 use serde::{Deserialize, Serialize};
 use sqlx::Type;
@@ -15,26 +16,41 @@ impl Username {
     pub fn value(&self) -> &str {
         &self.0
     }
+
+    /// Checks the rules new usernames follow, when registering or renaming.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.0.len() < 2 {
+            return Err("Username must be at least 2 characters long".to_string());
+        }
+
+        if self.0.len() > 50 {
+            return Err("Username cannot be longer than 50 characters".to_string());
+        }
+
+        check_confusable(&self.0).map_err(|reason| format!("Username {}", reason))
+    }
+
+    /// The form usernames looking alike share, see `unicode_text::skeleton`.
+    pub fn skeleton(&self) -> String {
+        skeleton(&self.0)
+    }
 }
 
 impl TryFrom<String> for Username {
     type Error = String;
 
+    /// Usernames keep their case, but are unique regardless of it.
+    ///
+    /// Only blank usernames are refused here, so that users registered before the rules of
+    /// `validate` can still be looked up.
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let trimmed = value.trim();
+        let normalized = normalize(&value);
+        let trimmed = normalized.trim();
 
         if trimmed.is_empty() {
             return Err("Username cannot be blank".to_string());
         }
 
-        if trimmed.len() < 2 {
-            return Err("Username must be at least 2 characters long".to_string());
-        }
-
-        if trimmed.len() > 50 {
-            return Err("Username cannot be longer than 50 characters".to_string());
-        }
-
         Ok(Username(trimmed.to_string()))
     }
 }
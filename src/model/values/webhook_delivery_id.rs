@@ -0,0 +1,41 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[schema(value_type = String, format = "uuid")]
+pub struct WebhookDeliveryId(Uuid);
+
+impl WebhookDeliveryId {
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for WebhookDeliveryId {
+    fn from(id: Uuid) -> Self {
+        WebhookDeliveryId(id)
+    }
+}
+
+impl From<WebhookDeliveryId> for Uuid {
+    fn from(id: WebhookDeliveryId) -> Uuid {
+        id.0
+    }
+}
+
+impl Display for WebhookDeliveryId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<WebhookDeliveryId> for Value {
+    fn from(id: WebhookDeliveryId) -> Self {
+        Value::Uuid(Some(Box::new(id.value())))
+    }
+}
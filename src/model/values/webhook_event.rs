@@ -0,0 +1,57 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub enum WebhookEvent {
+    #[serde(rename = "article.created")]
+    ArticleCreated,
+    #[serde(rename = "article.updated")]
+    ArticleUpdated,
+    #[serde(rename = "article.deleted")]
+    ArticleDeleted,
+    #[serde(rename = "comment.created")]
+    CommentCreated,
+    #[serde(rename = "user.followed")]
+    UserFollowed,
+}
+
+impl WebhookEvent {
+    pub fn value(&self) -> &'static str {
+        match self {
+            WebhookEvent::ArticleCreated => "article.created",
+            WebhookEvent::ArticleUpdated => "article.updated",
+            WebhookEvent::ArticleDeleted => "article.deleted",
+            WebhookEvent::CommentCreated => "comment.created",
+            WebhookEvent::UserFollowed => "user.followed",
+        }
+    }
+}
+
+impl TryFrom<String> for WebhookEvent {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "article.created" => Ok(WebhookEvent::ArticleCreated),
+            "article.updated" => Ok(WebhookEvent::ArticleUpdated),
+            "article.deleted" => Ok(WebhookEvent::ArticleDeleted),
+            "comment.created" => Ok(WebhookEvent::CommentCreated),
+            "user.followed" => Ok(WebhookEvent::UserFollowed),
+            _ => Err(format!("Unknown webhook event: {}", value)),
+        }
+    }
+}
+
+impl Display for WebhookEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl From<WebhookEvent> for Value {
+    fn from(event: WebhookEvent) -> Self {
+        Value::String(Some(Box::new(event.value().to_string())))
+    }
+}
@@ -0,0 +1,41 @@
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[schema(value_type = String, format = "uuid")]
+pub struct WebhookId(Uuid);
+
+impl WebhookId {
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for WebhookId {
+    fn from(id: Uuid) -> Self {
+        WebhookId(id)
+    }
+}
+
+impl From<WebhookId> for Uuid {
+    fn from(id: WebhookId) -> Uuid {
+        id.0
+    }
+}
+
+impl Display for WebhookId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<WebhookId> for Value {
+    fn from(id: WebhookId) -> Self {
+        Value::Uuid(Some(Box::new(id.value())))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::utils::public_address::is_local_host;
+use sea_query::Value;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "https://example.com/hooks/realworld")]
+pub struct WebhookUrl(String);
+
+impl WebhookUrl {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the URL targets localhost or a non-public IP address as written.
+    pub fn has_local_host(&self) -> bool {
+        reqwest::Url::parse(&self.0)
+            .ok()
+            .and_then(|url| url.host_str().map(is_local_host))
+            .unwrap_or(false)
+    }
+}
+
+impl TryFrom<String> for WebhookUrl {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return Err("Webhook URL cannot be empty".to_string());
+        }
+
+        if trimmed.len() > 2048 {
+            return Err("Webhook URL cannot be longer than 2048 characters".to_string());
+        }
+
+        let url =
+            reqwest::Url::parse(trimmed).map_err(|e| format!("Webhook URL is invalid: {}", e))?;
+
+        if url.scheme() != "http" && url.scheme() != "https" {
+            return Err("Webhook URL must start with http:// or https://".to_string());
+        }
+
+        if url.host_str().is_none() {
+            return Err("Webhook URL must have a host".to_string());
+        }
+
+        Ok(WebhookUrl(trimmed.to_string()))
+    }
+}
+
+impl TryFrom<&str> for WebhookUrl {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.to_string().try_into()
+    }
+}
+
+impl From<WebhookUrl> for String {
+    fn from(url: WebhookUrl) -> String {
+        url.0
+    }
+}
+
+impl Display for WebhookUrl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for WebhookUrl {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<WebhookUrl> for Value {
+    fn from(url: WebhookUrl) -> Self {
+        Value::String(Some(Box::new(url.value().to_string())))
+    }
+}
@@ -0,0 +1,30 @@
+use crate::model::values::user_id::UserId;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::net::IpAddr;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Who viewed an article, so that repeated views by the same reader count once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Viewer {
+    User(UserId),
+    Anonymous(IpAddr),
+}
+
+impl Viewer {
+    /// Key the view is stored under. IP addresses are only stored as a digest keyed by `salt`,
+    /// which still tells repeated views apart.
+    pub fn key(&self, salt: &str) -> String {
+        match self {
+            Viewer::User(user_id) => format!("user:{}", user_id),
+            Viewer::Anonymous(ip) => {
+                let mut mac = HmacSha256::new_from_slice(salt.as_bytes())
+                    .expect("HMAC accepts keys of any size");
+                mac.update(ip.to_string().as_bytes());
+
+                format!("ip:{}", hex::encode(mac.finalize().into_bytes()))
+            }
+        }
+    }
+}
@@ -12,21 +12,66 @@ use utoipa::OpenApi;
         crate::http::routes::auth::register,
         crate::http::routes::users::get_current_user,
         crate::http::routes::users::update_user,
+        crate::http::routes::stats::get_stats,
         crate::http::routes::profiles::get_profile,
         crate::http::routes::profiles::follow_user,
         crate::http::routes::profiles::unfollow_user,
+        crate::http::routes::profiles::block_user,
+        crate::http::routes::profiles::unblock_user,
+        crate::http::routes::profiles::mute_user,
+        crate::http::routes::profiles::unmute_user,
+        crate::http::routes::profiles::list_followers,
+        crate::http::routes::profiles::list_following,
+        crate::http::routes::profiles::list_follow_requests,
+        crate::http::routes::profiles::approve_follow_request,
+        crate::http::routes::profiles::reject_follow_request,
         crate::http::routes::articles::list_articles,
         crate::http::routes::articles::feed_articles,
+        crate::http::routes::articles::trending_articles,
+        crate::http::routes::articles::related_articles,
         crate::http::routes::articles::get_article,
         crate::http::routes::articles::create_article,
         crate::http::routes::articles::update_article,
         crate::http::routes::articles::delete_article,
         crate::http::routes::articles::favorite_article,
         crate::http::routes::articles::unfavorite_article,
+        crate::http::routes::articles::add_article_reaction,
+        crate::http::routes::articles::remove_article_reaction,
+        crate::http::routes::articles::lock_comments,
+        crate::http::routes::articles::unlock_comments,
+        crate::http::routes::articles::invite_co_author,
+        crate::http::routes::articles::accept_co_author_invitation,
+        crate::http::routes::articles::remove_co_author,
+        crate::http::routes::series::create_series,
+        crate::http::routes::series::get_series,
+        crate::http::routes::series::update_series,
+        crate::http::routes::series::delete_series,
+        crate::http::routes::bookmarks::bookmark_article,
+        crate::http::routes::bookmarks::unbookmark_article,
+        crate::http::routes::bookmarks::list_bookmarks,
         crate::http::routes::comments::get_comments,
         crate::http::routes::comments::create_comment,
         crate::http::routes::comments::delete_comment,
+        crate::http::routes::comments::add_comment_reaction,
+        crate::http::routes::comments::remove_comment_reaction,
         crate::http::routes::tags::get_tags,
+        crate::http::routes::tags::update_tag,
+        crate::http::routes::tags::merge_tag,
+        crate::http::routes::tags::follow_tag,
+        crate::http::routes::tags::unfollow_tag,
+        crate::http::routes::uploads::upload_image,
+        crate::http::routes::uploads::get_upload,
+        crate::http::routes::notifications::list_notifications,
+        crate::http::routes::notifications::mark_notification_as_read,
+        crate::http::routes::notifications::mark_all_notifications_as_read,
+        crate::http::routes::notifications::get_notification_preferences,
+        crate::http::routes::notifications::update_notification_preferences,
+        crate::http::routes::events::article_events,
+        crate::http::routes::events::notification_events,
+        crate::http::routes::webhooks::create_webhook,
+        crate::http::routes::webhooks::list_webhooks,
+        crate::http::routes::webhooks::delete_webhook,
+        crate::http::routes::webhooks::list_webhook_deliveries,
         crate::http::routes::health::health_check,
     ),
     components(schemas(
@@ -38,24 +83,79 @@ use utoipa::OpenApi;
         crate::http::dto::user::UserData,
         crate::http::dto::user::UpdateUserRequest,
         crate::http::dto::user::UpdateUser,
+        crate::http::dto::stats::StatsQuery,
+        crate::http::dto::stats::StatsResponse,
+        crate::http::dto::stats::AuthorStats,
+        crate::http::dto::stats::ArticleStatsItem,
+        crate::http::dto::stats::DailyStatsItem,
         crate::http::dto::profile::ProfileResponse,
         crate::http::dto::profile::Profile,
+        crate::http::dto::profile::ProfilesResponse,
+        crate::http::dto::profile::FollowListQuery,
         crate::http::dto::article::ArticlesResponse,
         crate::http::dto::article::ArticleResponse,
         crate::http::dto::article::ArticleListItem,
         crate::http::dto::article::ArticleItem,
+        crate::http::dto::article::TocItem,
+        crate::http::dto::article::ArticleQuery,
         crate::http::dto::article::CreateArticleRequest,
         crate::http::dto::article::CreateArticle,
         crate::http::dto::article::UpdateArticleRequest,
         crate::http::dto::article::UpdateArticleQuery,
         crate::http::dto::article::ArticleListQuery,
         crate::http::dto::article::ArticleFeedListQuery,
+        crate::http::dto::article::ArticleTrendingListQuery,
+        crate::http::dto::article::ArticleRelatedListQuery,
+        crate::http::dto::series::SeriesResponse,
+        crate::http::dto::series::SeriesItem,
+        crate::http::dto::series::CreateSeriesRequest,
+        crate::http::dto::series::CreateSeries,
+        crate::http::dto::series::UpdateSeriesRequest,
+        crate::http::dto::series::UpdateSeries,
+        crate::http::dto::series::ArticleSeriesItem,
+        crate::http::dto::series::SeriesArticleLink,
+        crate::http::dto::bookmark::BookmarkRequest,
+        crate::http::dto::bookmark::BookmarkData,
+        crate::http::dto::bookmark::BookmarksResponse,
+        crate::http::dto::bookmark::BookmarkItem,
+        crate::http::dto::bookmark::BookmarkListQuery,
         crate::http::dto::comment::CommentsResponse,
         crate::http::dto::comment::CommentResponse,
         crate::http::dto::comment::CommentItem,
         crate::http::dto::comment::CreateCommentRequest,
         crate::http::dto::comment::CreateComment,
+        crate::http::dto::comment::CommentListQuery,
+        crate::http::dto::reaction::ReactionItem,
         crate::http::dto::tag::TagsResponse,
+        crate::http::dto::tag::TagResponse,
+        crate::http::dto::tag::TagItem,
+        crate::http::dto::tag::TagListQuery,
+        crate::http::dto::tag::UpdateTagRequest,
+        crate::http::dto::tag::UpdateTag,
+        crate::http::dto::tag::MergeTagRequest,
+        crate::http::dto::tag::MergeTag,
+        crate::http::dto::upload::UploadForm,
+        crate::http::dto::upload::UploadResponse,
+        crate::http::dto::upload::UploadItem,
+        crate::http::dto::notification::NotificationsResponse,
+        crate::http::dto::notification::NotificationResponse,
+        crate::http::dto::notification::NotificationItem,
+        crate::http::dto::notification::NotificationArticle,
+        crate::http::dto::notification::NotificationListQuery,
+        crate::http::dto::notification::NotificationPreferencesResponse,
+        crate::http::dto::notification::NotificationPreferencesItem,
+        crate::http::dto::notification::UpdateNotificationPreferencesRequest,
+        crate::http::dto::notification::UpdateNotificationPreferences,
+        crate::http::dto::event::CommentDeletedEvent,
+        crate::http::dto::event::FavoritesChangedEvent,
+        crate::http::dto::webhook::WebhookResponse,
+        crate::http::dto::webhook::WebhooksResponse,
+        crate::http::dto::webhook::WebhookItem,
+        crate::http::dto::webhook::CreateWebhookRequest,
+        crate::http::dto::webhook::CreateWebhook,
+        crate::http::dto::webhook::WebhookDeliveriesResponse,
+        crate::http::dto::webhook::WebhookDeliveryItem,
+        crate::http::dto::webhook::WebhookDeliveryListQuery,
         crate::http::dto::error::ErrorResponse,
         crate::http::dto::error::ErrorBody,
         crate::model::values::email::Email,
@@ -68,18 +168,45 @@ use utoipa::OpenApi;
         crate::model::values::article_description::ArticleDescription,
         crate::model::values::article_body::ArticleBody,
         crate::model::values::comment_body::CommentBody,
+        crate::model::values::series_title::SeriesTitle,
+        crate::model::values::series_description::SeriesDescription,
         crate::model::values::tag_name::TagName,
+        crate::model::values::tag_description::TagDescription,
+        crate::model::values::bookmark_folder::BookmarkFolder,
+        crate::model::values::bookmark_note::BookmarkNote,
         crate::model::values::comment_id::CommentId,
+        crate::model::values::reaction_kind::ReactionKind,
+        crate::model::values::notification_id::NotificationId,
+        crate::model::values::notification_kind::NotificationKind,
+        crate::model::values::webhook_id::WebhookId,
+        crate::model::values::webhook_delivery_id::WebhookDeliveryId,
+        crate::model::values::webhook_delivery_status::WebhookDeliveryStatus,
+        crate::model::values::webhook_event::WebhookEvent,
+        crate::model::values::webhook_url::WebhookUrl,
         crate::model::limit::Limit,
         crate::model::offset::Offset,
+        crate::model::tag_sort::TagSort,
+        crate::model::comment_sort::CommentSort,
+        crate::model::feed_source::FeedSource,
+        crate::model::trending_window::TrendingWindow,
+        crate::model::body_format::BodyFormat,
+        crate::model::article_sort::ArticleSort,
+        crate::model::article_sort::SortOrder,
+        crate::model::article_sort::TagMatch,
     )),
     tags(
         (name = "Authentication", description = "User registration and login"),
         (name = "User", description = "Current user operations"),
-        (name = "Profiles", description = "User profile viewing and following"),
-        (name = "Articles", description = "Article CRUD operations and favorites"),
+        (name = "Profiles", description = "User profile viewing, following, blocking and muting"),
+        (name = "Articles", description = "Article CRUD operations, favorites and reactions"),
+        (name = "Series", description = "Ordered collections of articles, such as multi-part tutorials"),
+        (name = "Bookmarks", description = "Private reading list of the current user"),
         (name = "Comments", description = "Article comment operations"),
         (name = "Tags", description = "Article tags"),
+        (name = "Uploads", description = "Image uploads for avatars and article media"),
+        (name = "Notifications", description = "Notifications about comments, favorites and follows"),
+        (name = "Events", description = "Server-sent event streams for real-time updates"),
+        (name = "Webhooks", description = "Signed outbound webhooks for article, comment and follow events"),
         (name = "Health", description = "Health check endpoint"),
     )
 )]
@@ -1,21 +1,41 @@
 use crate::app_error::AppError;
-use crate::database::Database;
+use crate::database::{Database, DbExecutor};
+use crate::model::article_sort::{ArticleSort, SortOrder, TagMatch};
+use crate::model::feed_source::FeedSource;
 use crate::model::indexed_article_field::IndexedArticleField;
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
 use crate::model::persistence::article::Article;
 use crate::model::persistence::article_view::{ArticleListView, ArticleView};
+use crate::model::persistence::bookmark_view::BookmarkView;
+use crate::model::persistence::co_author_view::CoAuthorView;
+use crate::model::persistence::reaction_count::ReactionCount;
+use crate::model::trending_window::TrendingWindow;
 use crate::model::values::article_id::ArticleId;
+use crate::model::values::bookmark_folder::BookmarkFolder;
+use crate::model::values::reaction_kind::ReactionKind;
+use crate::model::values::series_id::SeriesId;
+use crate::model::values::slug::Slug;
+use crate::model::values::tag_name::TagName;
 use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
 use crate::persistence::params::insert_article_params::InsertArticleParams;
+use crate::persistence::params::insert_article_view_params::InsertArticleViewParams;
+use crate::persistence::params::insert_bookmark_params::InsertBookmarkParams;
 use crate::persistence::params::list_articles_params::ListArticlesParams;
 use crate::persistence::params::update_article_params::UpdateArticleParams;
+use crate::persistence::profile_repository::{blocked_by_subquery, muted_subquery};
 use crate::persistence::schema::{
-    ArticleFavorites, ArticleTags, Articles, Tags, UserFollows, Users,
+    ArticleCoAuthors, ArticleFavorites, ArticleReactions, ArticleTags, ArticleViews, Articles,
+    Bookmarks, SeriesArticles, TagFollows, Tags, TrendingArticles, UserFollows, UsernameHistory,
+    Users,
 };
 use anyhow::Result;
-use sea_query::{Alias, Expr, Order, PostgresQueryBuilder, Query, SelectStatement};
+use chrono::{DateTime, Utc};
+use sea_query::{
+    Alias, Expr, Func, Iden, IntoColumnRef, JoinType, Order, PostgresQueryBuilder, Query,
+    SelectStatement, SimpleExpr,
+};
 use sea_query_binder::SqlxBinder;
 use sqlx::Row;
 
@@ -24,7 +44,7 @@ pub struct ArticleRepository {
     database: Database,
 }
 
-fn following_subquery(user_id: UserId) -> SelectStatement {
+pub(crate) fn following_subquery(user_id: UserId) -> SelectStatement {
     Query::select()
         .expr(Expr::cust("1"))
         .from(UserFollows::Table)
@@ -39,7 +59,135 @@ fn following_subquery(user_id: UserId) -> SelectStatement {
         .to_owned()
 }
 
-fn favorited_subquery(favorited_by_username: Username) -> SelectStatement {
+fn followed_tags_subquery(user_id: UserId) -> SelectStatement {
+    Query::select()
+        .expr(Expr::cust("1"))
+        .from(ArticleTags::Table)
+        .inner_join(
+            TagFollows::Table,
+            Expr::col((TagFollows::Table, TagFollows::TagId))
+                .eq(Expr::col((ArticleTags::Table, ArticleTags::TagId))),
+        )
+        .and_where(
+            Expr::col((TagFollows::Table, TagFollows::UserId))
+                .eq(user_id)
+                .and(
+                    Expr::col((ArticleTags::Table, ArticleTags::ArticleId))
+                        .eq(Expr::col((Articles::Table, Articles::Id))),
+                ),
+        )
+        .to_owned()
+}
+
+/// Articles of private authors are only listed to their approved followers, themselves and the
+/// article's co-authors.
+fn visible_author_condition(viewer_id: Option<UserId>) -> SimpleExpr {
+    let public = Expr::col((Users::Table, Users::Private)).eq(false);
+
+    match viewer_id {
+        Some(viewer_id) => public
+            .or(Expr::col((Users::Table, Users::Id)).eq(viewer_id))
+            .or(Expr::exists(following_subquery(viewer_id)))
+            .or(Expr::exists(co_author_subquery(viewer_id))),
+        None => public,
+    }
+}
+
+fn co_author_subquery(user_id: UserId) -> SelectStatement {
+    Query::select()
+        .expr(Expr::cust("1"))
+        .from(ArticleCoAuthors::Table)
+        .and_where(
+            Expr::col((ArticleCoAuthors::Table, ArticleCoAuthors::ArticleId))
+                .equals((Articles::Table, Articles::Id)),
+        )
+        .and_where(Expr::col((ArticleCoAuthors::Table, ArticleCoAuthors::UserId)).eq(user_id))
+        .and_where(accepted_co_author_condition())
+        .to_owned()
+}
+
+/// Invited co-authors only count once they accept.
+fn accepted_co_author_condition() -> SimpleExpr {
+    Expr::col((ArticleCoAuthors::Table, ArticleCoAuthors::AcceptedAt)).is_not_null()
+}
+
+/// Authors whose articles the viewer can read at all: visible to them and not blocking them.
+pub(crate) fn readable_author_condition(viewer_id: Option<UserId>) -> SimpleExpr {
+    match viewer_id {
+        Some(viewer_id) => visible_author_condition(Some(viewer_id))
+            .and(Expr::exists(blocked_by_subquery(viewer_id)).not()),
+        None => visible_author_condition(None),
+    }
+}
+
+/// Leaves out of article lists the authors the viewer can't read, and those they mute.
+fn listed_author_where_statement(viewer_id: Option<UserId>, query: &mut SelectStatement) {
+    if let Some(viewer_id) = viewer_id {
+        query.and_where(Expr::exists(muted_subquery(viewer_id)).not());
+    }
+
+    query.and_where(readable_author_condition(viewer_id));
+}
+
+/// Usernames are unique regardless of case, so filtering on one ignores it too.
+fn username_condition<C: IntoColumnRef>(column: C, username: &Username) -> SimpleExpr {
+    Expr::expr(Func::lower(Expr::col(column))).eq(Func::lower(Expr::val(username.value())))
+}
+
+/// Matches authors who used to have the username, so that links filtering on it keep working.
+fn previous_username_subquery(username: &Username) -> SelectStatement {
+    Query::select()
+        .expr(Expr::cust("1"))
+        .from(UsernameHistory::Table)
+        .and_where(
+            Expr::col((UsernameHistory::Table, UsernameHistory::UserId))
+                .equals((Users::Table, Users::Id)),
+        )
+        .and_where(username_condition(
+            (UsernameHistory::Table, UsernameHistory::Username),
+            username,
+        ))
+        .to_owned()
+}
+
+/// Matches articles co-authored by the user, which are listed along with their own articles.
+fn co_authored_subquery(username: &Username) -> SelectStatement {
+    let co_author = Alias::new("co_author");
+
+    Query::select()
+        .expr(Expr::cust("1"))
+        .from(ArticleCoAuthors::Table)
+        .join_as(
+            JoinType::InnerJoin,
+            Users::Table,
+            co_author.clone(),
+            Expr::col((co_author.clone(), Users::Id))
+                .equals((ArticleCoAuthors::Table, ArticleCoAuthors::UserId)),
+        )
+        .and_where(
+            Expr::col((ArticleCoAuthors::Table, ArticleCoAuthors::ArticleId))
+                .equals((Articles::Table, Articles::Id)),
+        )
+        .and_where(username_condition((co_author, Users::Username), username))
+        .and_where(accepted_co_author_condition())
+        .to_owned()
+}
+
+fn feed_where_statement(user_id: UserId, source: FeedSource, query: &mut SelectStatement) {
+    let from_authors = Expr::exists(following_subquery(user_id));
+    let from_tags = Expr::exists(followed_tags_subquery(user_id));
+
+    // An article matching both sources is still a single row, thanks to the grouping.
+    match source {
+        FeedSource::Authors => query.and_where(from_authors),
+        FeedSource::Tags => query.and_where(from_tags),
+        FeedSource::All => query.and_where(from_authors.or(from_tags)),
+    };
+
+    listed_author_where_statement(Some(user_id), query);
+}
+
+fn favorited_subquery(favorited_by_username: &Username) -> SelectStatement {
     Query::select()
         .column((ArticleFavorites::Table, ArticleFavorites::ArticleId))
         .from(ArticleFavorites::Table)
@@ -48,7 +196,10 @@ fn favorited_subquery(favorited_by_username: Username) -> SelectStatement {
             Expr::col((ArticleFavorites::Table, ArticleFavorites::UserId))
                 .eq(Expr::col((Users::Table, Users::Id))),
         )
-        .and_where(Expr::col((Users::Table, Users::Username)).eq(favorited_by_username))
+        .and_where(username_condition(
+            (Users::Table, Users::Username),
+            favorited_by_username,
+        ))
         .to_owned()
 }
 
@@ -65,6 +216,8 @@ fn build_article_view_query(
           .column((Articles::Table, Articles::CreatedAt))
           .column((Articles::Table, Articles::UpdatedAt))
           .column((Articles::Table, Articles::Body))
+          .column((Articles::Table, Articles::ViewsCount))
+          .column((Articles::Table, Articles::CommentsLocked))
           .expr_as(
             Expr::col((Users::Table, Users::Id)),
             Alias::new("author_id"),
@@ -85,6 +238,11 @@ fn build_article_view_query(
             Expr::cust("COUNT(DISTINCT article_favorites.user_id)"),
             Alias::new("favorites_count"),
           )
+          // A correlated count, joining the comments would multiply the favorites and tags rows.
+          .expr_as(
+            Expr::cust("(SELECT COUNT(*) FROM comments WHERE comments.article_id = articles.id)"),
+            Alias::new("comments_count"),
+          )
           .expr_as(
             Expr::cust("COALESCE(ARRAY_AGG(tags.name ORDER BY tags.name ASC) FILTER (WHERE tags.name IS NOT NULL), ARRAY[]::text[])::text[]"),
             Alias::new("tag_list"),
@@ -107,14 +265,29 @@ fn build_article_view_query(
                 )
                 .to_owned();
 
+            let bookmarked_subquery = Query::select()
+                .expr(Expr::cust("1"))
+                .from(Bookmarks::Table)
+                .and_where(
+                    Expr::col((Bookmarks::Table, Bookmarks::UserId))
+                        .eq(user_id)
+                        .and(
+                            Expr::col((Bookmarks::Table, Bookmarks::ArticleId))
+                                .eq(Expr::col((Articles::Table, Articles::Id))),
+                        ),
+                )
+                .to_owned();
+
             query
                 .expr_as(Expr::exists(following_subquery), Alias::new("following"))
-                .expr_as(Expr::exists(favorited_subquery), Alias::new("favorited"));
+                .expr_as(Expr::exists(favorited_subquery), Alias::new("favorited"))
+                .expr_as(Expr::exists(bookmarked_subquery), Alias::new("bookmarked"));
         }
         None => {
             query
                 .expr_as(Expr::cust("FALSE"), Alias::new("following"))
-                .expr_as(Expr::cust("FALSE"), Alias::new("favorited"));
+                .expr_as(Expr::cust("FALSE"), Alias::new("favorited"))
+                .expr_as(Expr::cust("FALSE"), Alias::new("bookmarked"));
         }
     }
 
@@ -150,34 +323,113 @@ fn build_article_view_query(
     query
 }
 
+/// Articles sharing a row of `table` (a tag or a favoriting user, through `shared_column`)
+/// with the given article.
+fn sharing_subquery<T>(
+    table: T,
+    article_column: T,
+    shared_column: T,
+    article_id: ArticleId,
+) -> SelectStatement
+where
+    T: Iden + Clone + 'static,
+{
+    let source = Alias::new("source");
+    let other = Alias::new("other");
+
+    Query::select()
+        .column((other.clone(), article_column.clone()))
+        .from_as(table.clone(), other.clone())
+        .join_as(
+            JoinType::InnerJoin,
+            table,
+            source.clone(),
+            Expr::col((source.clone(), shared_column.clone())).equals((other, shared_column)),
+        )
+        .and_where(Expr::col((source, article_column)).eq(article_id))
+        .to_owned()
+}
+
+/// How related an article is to the given one: each shared tag weighs more the fewer articles
+/// use it, and each user who favorited both articles adds one.
+fn related_score(article_id: ArticleId) -> SimpleExpr {
+    Expr::cust_with_values(
+        r#"(
+            SELECT COALESCE(SUM(1.0 / LN(1 + (
+                SELECT COUNT(*) FROM article_tags AS usage WHERE usage.tag_id = shared.tag_id
+            ))), 0)
+            FROM article_tags AS shared
+            INNER JOIN article_tags AS source
+                ON source.tag_id = shared.tag_id AND source.article_id = $1
+            WHERE shared.article_id = articles.id
+        ) + (
+            SELECT COUNT(*)
+            FROM article_favorites AS shared
+            INNER JOIN article_favorites AS source
+                ON source.user_id = shared.user_id AND source.article_id = $2
+            WHERE shared.article_id = articles.id
+        )"#,
+        [article_id, article_id],
+    )
+}
+
+fn tagged_subquery(tag_names: &[TagName]) -> SelectStatement {
+    Query::select()
+        .column((ArticleTags::Table, ArticleTags::ArticleId))
+        .from(ArticleTags::Table)
+        .inner_join(
+            Tags::Table,
+            Expr::col((ArticleTags::Table, ArticleTags::TagId))
+                .eq(Expr::col((Tags::Table, Tags::Id))),
+        )
+        .and_where(
+            Expr::col((ArticleTags::Table, ArticleTags::ArticleId))
+                .eq(Expr::col((Articles::Table, Articles::Id)))
+                .and(Expr::col((Tags::Table, Tags::Name)).is_in(tag_names.iter().cloned())),
+        )
+        .to_owned()
+}
+
 fn article_list_where_statement(params: &ListArticlesParams, query: &mut SelectStatement) {
-    if let Some(tag) = &params.tag {
-        query.and_where(Expr::exists(
-            Query::select()
-                .column((ArticleTags::Table, ArticleTags::ArticleId))
-                .from(ArticleTags::Table)
-                .inner_join(
-                    Tags::Table,
-                    Expr::col((ArticleTags::Table, ArticleTags::TagId))
-                        .eq(Expr::col((Tags::Table, Tags::Id))),
-                )
-                .and_where(
-                    Expr::col((ArticleTags::Table, ArticleTags::ArticleId))
-                        .eq(Expr::col((Articles::Table, Articles::Id)))
-                        .and(Expr::col((Tags::Table, Tags::Name)).eq(tag)),
-                )
-                .to_owned(),
-        ));
+    if !params.tags.is_empty() {
+        match params.tag_match {
+            TagMatch::Any => {
+                query.and_where(Expr::exists(tagged_subquery(&params.tags)));
+            }
+            TagMatch::All => {
+                for tag in &params.tags {
+                    query.and_where(Expr::exists(tagged_subquery(std::slice::from_ref(tag))));
+                }
+            }
+        }
+    }
+
+    if !params.excluded_tags.is_empty() {
+        query.and_where(Expr::exists(tagged_subquery(&params.excluded_tags)).not());
+    }
+
+    if let Some(created_after) = params.created_after {
+        query.and_where(Expr::col((Articles::Table, Articles::CreatedAt)).gte(created_after));
+    }
+
+    if let Some(created_before) = params.created_before {
+        query.and_where(Expr::col((Articles::Table, Articles::CreatedAt)).lt(created_before));
     }
 
     if let Some(author_username) = &params.author {
-        query.and_where(Expr::col((Users::Table, Users::Username)).eq(author_username.clone()));
+        query.and_where(
+            username_condition((Users::Table, Users::Username), author_username)
+                .or(Expr::exists(previous_username_subquery(author_username)))
+                .or(Expr::exists(co_authored_subquery(author_username))),
+        );
     }
 
     if let Some(favorited_by_username) = &params.favorited_by {
-        let favorited_subquery = favorited_subquery(favorited_by_username.clone());
+        let favorited_subquery = favorited_subquery(favorited_by_username);
         query.and_where(Expr::col((Articles::Table, Articles::Id)).in_subquery(favorited_subquery));
     }
+
+    listed_author_where_statement(params.user_id, query);
 }
 
 impl ArticleRepository {
@@ -185,7 +437,13 @@ impl ArticleRepository {
         ArticleRepository { database }
     }
 
-    pub async fn insert_article(&self, params: InsertArticleParams) -> Result<Article, AppError> {
+    pub async fn insert_article(
+        &self,
+        db: DbExecutor<'_>,
+        params: InsertArticleParams,
+    ) -> Result<Article, AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = Query::insert()
             .into_table(Articles::Table)
             .columns([
@@ -205,21 +463,22 @@ impl ArticleRepository {
             .returning_all()
             .build_sqlx(PostgresQueryBuilder);
 
-        let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
-            .await?;
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
 
         Ok(Article::from_row(row))
     }
 
     pub async fn get_article_by<T>(
         &self,
+        db: DbExecutor<'_>,
         field: IndexedArticleField,
         value: T,
     ) -> Result<Option<Article>, AppError>
     where
         sea_query::Value: From<T>,
     {
+        let mut conn = db.acquire().await?;
+
         let field_name = field.to_field_name();
 
         let (sql, values) = Query::select()
@@ -231,17 +490,28 @@ impl ArticleRepository {
             .column(Articles::AuthorId)
             .column(Articles::CreatedAt)
             .column(Articles::UpdatedAt)
+            .column(Articles::CommentsLocked)
             .from(Articles::Table)
             .and_where(Expr::col(field_name).eq(value))
             .build_sqlx(PostgresQueryBuilder);
 
         let row = sqlx::query_with(&sql, values)
-            .fetch_optional(self.database.pool())
+            .fetch_optional(&mut *conn)
             .await?;
 
         Ok(row.map(Article::from_row))
     }
 
+    /// Resolves the slug whoever asks, for callers checking access themselves.
+    pub async fn get_article_id(&self, slug: &Slug) -> Result<Option<ArticleId>, AppError> {
+        let article = self
+            .get_article_by((&self.database).into(), IndexedArticleField::Slug, slug)
+            .await?;
+
+        Ok(article.map(|article| article.id))
+    }
+
+    /// Leaves out articles the viewer can't read, see `readable_author_condition`.
     pub async fn get_article_view_by<T>(
         &self,
         field: IndexedArticleField,
@@ -253,16 +523,12 @@ impl ArticleRepository {
         T: Copy,
     {
         let query = build_article_view_query(user_id, move |q| {
-            q.and_where(Expr::col(field.to_field_name()).eq(value));
+            q.and_where(Expr::col(field.to_field_name()).eq(value))
+                .and_where(readable_author_condition(user_id));
         });
 
-        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
-
-        let row = sqlx::query_with(&sql, values)
-            .fetch_optional(self.database.pool())
-            .await?;
-
-        Ok(row.map(ArticleView::from_row))
+        self.fetch_article_view((&self.database).into(), query, user_id)
+            .await
     }
 
     pub async fn get_article_by_id(
@@ -270,25 +536,273 @@ impl ArticleRepository {
         article_id: ArticleId,
         user_id: Option<UserId>,
     ) -> Result<ArticleView, AppError> {
+        let article = self
+            .find_article_view((&self.database).into(), article_id, user_id)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+
+        Ok(article)
+    }
+
+    /// Looks the article up as `user_id` sees it, whether they can read it or not, on the
+    /// caller's connection.
+    pub async fn find_article_view(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        user_id: Option<UserId>,
+    ) -> Result<Option<ArticleView>, AppError> {
         let query = build_article_view_query(user_id, move |q| {
             q.and_where(Expr::col((Articles::Table, Articles::Id)).eq(article_id));
         });
 
+        self.fetch_article_view(db, query, user_id).await
+    }
+
+    /// Runs the article view query, loading co-authors and reactions on the same connection.
+    async fn fetch_article_view(
+        &self,
+        db: DbExecutor<'_>,
+        query: SelectStatement,
+        user_id: Option<UserId>,
+    ) -> Result<Option<ArticleView>, AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
 
         let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
+            .fetch_optional(&mut *conn)
             .await?;
 
-        Ok(ArticleView::from_row(row))
+        match row {
+            Some(row) => {
+                let mut article = ArticleView::from_row(row);
+                article.co_authors = self
+                    .list_co_authors((&mut *conn).into(), article.id, user_id)
+                    .await?;
+                article.reactions = self
+                    .count_reactions((&mut *conn).into(), article.id, user_id)
+                    .await?;
+                Ok(Some(article))
+            }
+            None => Ok(None),
+        }
     }
 
-    pub async fn update_article(&self, params: UpdateArticleParams) -> Result<Article, AppError> {
-        let updates = params.as_list();
+    async fn list_co_authors(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        user_id: Option<UserId>,
+    ) -> Result<Vec<CoAuthorView>, AppError> {
+        let following = match user_id {
+            Some(user_id) => Expr::exists(following_subquery(user_id)),
+            None => Expr::cust("FALSE"),
+        };
 
-        if updates.is_empty() {
-            return Err(AppError::BadData("No fields to update".to_string()));
-        }
+        let (sql, values) = Query::select()
+            .columns([
+                (Users::Table, Users::Username),
+                (Users::Table, Users::Bio),
+                (Users::Table, Users::Image),
+            ])
+            .expr_as(following, Alias::new("following"))
+            .from(ArticleCoAuthors::Table)
+            .inner_join(
+                Users::Table,
+                Expr::col((ArticleCoAuthors::Table, ArticleCoAuthors::UserId))
+                    .equals((Users::Table, Users::Id)),
+            )
+            .and_where(
+                Expr::col((ArticleCoAuthors::Table, ArticleCoAuthors::ArticleId)).eq(article_id),
+            )
+            .and_where(accepted_co_author_condition())
+            .order_by(
+                (ArticleCoAuthors::Table, ArticleCoAuthors::CreatedAt),
+                Order::Asc,
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let mut conn = db.acquire().await?;
+
+        let rows = sqlx::query_with(&sql, values).fetch_all(&mut *conn).await?;
+
+        Ok(rows.into_iter().map(CoAuthorView::from_row).collect())
+    }
+
+    async fn count_reactions(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        user_id: Option<UserId>,
+    ) -> Result<Vec<ReactionCount>, AppError> {
+        let reacted: SimpleExpr = match user_id {
+            Some(user_id) => Func::cust("BOOL_OR")
+                .arg(Expr::col((ArticleReactions::Table, ArticleReactions::UserId)).eq(user_id))
+                .into(),
+            None => Expr::cust("FALSE"),
+        };
+
+        let (sql, values) = Query::select()
+            .column((ArticleReactions::Table, ArticleReactions::Kind))
+            .expr_as(Expr::cust("COUNT(*)"), Alias::new("count"))
+            .expr_as(reacted, Alias::new("reacted"))
+            .from(ArticleReactions::Table)
+            .and_where(
+                Expr::col((ArticleReactions::Table, ArticleReactions::ArticleId)).eq(article_id),
+            )
+            .group_by_col((ArticleReactions::Table, ArticleReactions::Kind))
+            .order_by(
+                (ArticleReactions::Table, ArticleReactions::Kind),
+                Order::Asc,
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let mut conn = db.acquire().await?;
+
+        let rows = sqlx::query_with(&sql, values).fetch_all(&mut *conn).await?;
+
+        Ok(rows.iter().map(ReactionCount::from_row).collect())
+    }
+
+    /// Invites the user, who only becomes a co-author once they accept.
+    pub async fn invite_co_author(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        user_id: UserId,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(ArticleCoAuthors::Table)
+            .columns([ArticleCoAuthors::ArticleId, ArticleCoAuthors::UserId])
+            .values_panic([article_id.into(), user_id.into()])
+            .on_conflict(
+                sea_query::OnConflict::columns([
+                    ArticleCoAuthors::ArticleId,
+                    ArticleCoAuthors::UserId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Returns whether the user was invited, accepting again changes nothing.
+    pub async fn accept_co_author_invitation(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        user_id: UserId,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::update()
+            .table(ArticleCoAuthors::Table)
+            .value(
+                ArticleCoAuthors::AcceptedAt,
+                Func::coalesce([
+                    Expr::col(ArticleCoAuthors::AcceptedAt).into(),
+                    Expr::current_timestamp().into(),
+                ]),
+            )
+            .and_where(Expr::col(ArticleCoAuthors::ArticleId).eq(article_id))
+            .and_where(Expr::col(ArticleCoAuthors::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Also withdraws or declines invitations.
+    pub async fn remove_co_author(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        user_id: UserId,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(ArticleCoAuthors::Table)
+            .and_where(Expr::col(ArticleCoAuthors::ArticleId).eq(article_id))
+            .and_where(Expr::col(ArticleCoAuthors::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Whether the user can read the article, its author neither hiding it from them nor
+    /// blocking them.
+    pub async fn is_readable(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        user_id: UserId,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let subquery = Query::select()
+            .expr(Expr::cust("1"))
+            .from(Articles::Table)
+            .inner_join(
+                Users::Table,
+                Expr::col((Users::Table, Users::Id)).equals((Articles::Table, Articles::AuthorId)),
+            )
+            .and_where(Expr::col((Articles::Table, Articles::Id)).eq(article_id))
+            .and_where(readable_author_condition(Some(user_id)))
+            .to_owned();
+
+        let (sql, values) = Query::select()
+            .expr_as(Expr::exists(subquery), Alias::new("is_readable"))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(row.get("is_readable"))
+    }
+
+    pub async fn is_co_author(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        user_id: UserId,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let subquery = Query::select()
+            .expr(Expr::cust("1"))
+            .from(ArticleCoAuthors::Table)
+            .and_where(Expr::col(ArticleCoAuthors::ArticleId).eq(article_id))
+            .and_where(Expr::col(ArticleCoAuthors::UserId).eq(user_id))
+            .and_where(accepted_co_author_condition())
+            .to_owned();
+
+        let (sql, values) = Query::select()
+            .expr_as(Expr::exists(subquery), Alias::new("is_co_author"))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(row.get("is_co_author"))
+    }
+
+    pub async fn update_article(
+        &self,
+        db: DbExecutor<'_>,
+        params: UpdateArticleParams,
+    ) -> Result<Article, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let updates = params.as_list();
 
         let mut query = Query::update();
         query
@@ -304,22 +818,44 @@ impl ArticleRepository {
             .returning_all()
             .build_sqlx(PostgresQueryBuilder);
 
-        let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
-            .await?;
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
 
         Ok(Article::from_row(row))
     }
 
-    pub async fn delete_article(&self, article_id: ArticleId) -> Result<(), AppError> {
+    /// Doesn't touch `updated_at`, locking is not an edit of the article.
+    pub async fn set_comments_locked(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        locked: bool,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::update()
+            .table(Articles::Table)
+            .value(Articles::CommentsLocked, locked)
+            .and_where(Expr::col(Articles::Id).eq(article_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_article(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = Query::delete()
             .from_table(Articles::Table)
             .and_where(Expr::col(Articles::Id).eq(article_id))
             .build_sqlx(PostgresQueryBuilder);
 
-        sqlx::query_with(&sql, values)
-            .execute(self.database.pool())
-            .await?;
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
 
         Ok(())
     }
@@ -331,8 +867,30 @@ impl ArticleRepository {
         let mut query =
             build_article_view_query(params.user_id, |q| article_list_where_statement(&params, q));
 
+        let order = match params.order {
+            SortOrder::Asc => Order::Asc,
+            SortOrder::Desc => Order::Desc,
+        };
+
+        match params.sort {
+            ArticleSort::CreatedAt => {
+                query.order_by((Articles::Table, Articles::CreatedAt), order.clone());
+            }
+            ArticleSort::UpdatedAt => {
+                query.order_by((Articles::Table, Articles::UpdatedAt), order.clone());
+            }
+            ArticleSort::FavoritesCount => {
+                query.order_by(Alias::new("favorites_count"), order.clone());
+            }
+            ArticleSort::CommentsCount => {
+                query.order_by(Alias::new("comments_count"), order.clone());
+            }
+        }
+
+        // Keeps the order of articles with equal sort keys stable across pages.
         let (sql, values) = query
-            .order_by((Articles::Table, Articles::CreatedAt), Order::Desc)
+            .order_by((Articles::Table, Articles::CreatedAt), order.clone())
+            .order_by((Articles::Table, Articles::Id), order)
             .limit(params.limit.unwrap_or_default().value())
             .offset(params.offset.unwrap_or_default().value())
             .build_sqlx(PostgresQueryBuilder);
@@ -364,11 +922,12 @@ impl ArticleRepository {
     pub async fn get_feed_articles(
         &self,
         user_id: UserId,
+        source: FeedSource,
         limit: Option<Limit>,
         offset: Option<Offset>,
     ) -> Result<Vec<ArticleListView>, AppError> {
         let mut query = build_article_view_query(Some(user_id), |q| {
-            q.and_where(Expr::exists(following_subquery(user_id)));
+            feed_where_statement(user_id, source, q);
         });
 
         let (sql, values) = query
@@ -384,9 +943,13 @@ impl ArticleRepository {
         Ok(rows.into_iter().map(ArticleListView::from_row).collect())
     }
 
-    pub async fn count_feed_articles(&self, user_id: UserId) -> Result<u64, AppError> {
+    pub async fn count_feed_articles(
+        &self,
+        user_id: UserId,
+        source: FeedSource,
+    ) -> Result<u64, AppError> {
         let subquery = build_article_view_query(Some(user_id), |q| {
-            q.and_where(Expr::exists(following_subquery(user_id)));
+            feed_where_statement(user_id, source, q);
         });
 
         let (sql, values) = Query::select()
@@ -402,55 +965,423 @@ impl ArticleRepository {
         Ok(count as u64)
     }
 
-    pub async fn favorite_article(
+    pub async fn get_trending_articles(
+        &self,
+        user_id: Option<UserId>,
+        window: TrendingWindow,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+    ) -> Result<Vec<ArticleListView>, AppError> {
+        let mut query = build_article_view_query(user_id, |q| {
+            q.inner_join(
+                TrendingArticles::Table,
+                Expr::col((TrendingArticles::Table, TrendingArticles::ArticleId))
+                    .equals((Articles::Table, Articles::Id))
+                    .and(
+                        Expr::col((TrendingArticles::Table, TrendingArticles::TimeWindow))
+                            .eq(window),
+                    ),
+            );
+            listed_author_where_statement(user_id, q);
+        });
+
+        // There's a single trending row per article and window, MAX only satisfies the grouping.
+        let (sql, values) = query
+            .order_by_expr(
+                Expr::col((TrendingArticles::Table, TrendingArticles::Score)).max(),
+                Order::Desc,
+            )
+            .order_by((Articles::Table, Articles::CreatedAt), Order::Desc)
+            .limit(limit.unwrap_or_default().value())
+            .offset(offset.unwrap_or_default().value())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(ArticleListView::from_row).collect())
+    }
+
+    pub async fn count_trending_articles(
+        &self,
+        user_id: Option<UserId>,
+        window: TrendingWindow,
+    ) -> Result<u64, AppError> {
+        let mut query = Query::select();
+        query
+            .expr_as(Expr::cust("COUNT(*)"), "count")
+            .from(TrendingArticles::Table)
+            .inner_join(
+                Articles::Table,
+                Expr::col((Articles::Table, Articles::Id))
+                    .equals((TrendingArticles::Table, TrendingArticles::ArticleId)),
+            )
+            .inner_join(
+                Users::Table,
+                Expr::col((Users::Table, Users::Id)).equals((Articles::Table, Articles::AuthorId)),
+            )
+            .and_where(
+                Expr::col((TrendingArticles::Table, TrendingArticles::TimeWindow)).eq(window),
+            );
+
+        listed_author_where_statement(user_id, &mut query);
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        let count: i64 = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?
+            .get("count");
+
+        Ok(count as u64)
+    }
+
+    /// Articles sharing tags or favoriting users with the given article, most related first. The
+    /// reader's own articles are left out.
+    pub async fn get_related_articles(
         &self,
+        article_id: ArticleId,
+        user_id: Option<UserId>,
+        limit: Option<Limit>,
+    ) -> Result<Vec<ArticleListView>, AppError> {
+        let mut query = build_article_view_query(user_id, |q| {
+            q.and_where(
+                Expr::col((Articles::Table, Articles::Id))
+                    .in_subquery(sharing_subquery(
+                        ArticleTags::Table,
+                        ArticleTags::ArticleId,
+                        ArticleTags::TagId,
+                        article_id,
+                    ))
+                    .or(
+                        Expr::col((Articles::Table, Articles::Id)).in_subquery(sharing_subquery(
+                            ArticleFavorites::Table,
+                            ArticleFavorites::ArticleId,
+                            ArticleFavorites::UserId,
+                            article_id,
+                        )),
+                    ),
+            )
+            .and_where(Expr::col((Articles::Table, Articles::Id)).ne(article_id));
+
+            if let Some(user_id) = user_id {
+                q.and_where(Expr::col((Articles::Table, Articles::AuthorId)).ne(user_id));
+            }
+
+            listed_author_where_statement(user_id, q);
+        });
+
+        let (sql, values) = query
+            .order_by_expr(related_score(article_id), Order::Desc)
+            .order_by((Articles::Table, Articles::CreatedAt), Order::Desc)
+            .limit(limit.unwrap_or_default().value())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(ArticleListView::from_row).collect())
+    }
+
+    /// Recomputes the trending scores without blocking the readers of the previous ones.
+    pub async fn refresh_trending_articles(&self) -> Result<(), AppError> {
+        sqlx::query("REFRESH MATERIALIZED VIEW CONCURRENTLY trending_articles")
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Stores a batch of views, skipping those already counted in the same window, and adds the
+    /// new ones to the articles' views count. Returns how many articles got new views.
+    pub async fn record_article_views(
+        &self,
+        views: &[InsertArticleViewParams],
+    ) -> Result<u64, AppError> {
+        // A data-modifying CTE keeps both writes in one statement, which sea-query can't build.
+        let result = sqlx::query(
+            r#"
+            WITH inserted AS (
+                INSERT INTO article_views (article_id, viewer, window_start, viewed_at)
+                SELECT * FROM UNNEST($1::uuid[], $2::varchar[], $3::timestamptz[], $4::timestamptz[])
+                ON CONFLICT DO NOTHING
+                RETURNING article_id
+            )
+            UPDATE articles
+            SET views_count = articles.views_count + counted.views
+            FROM (SELECT article_id, COUNT(*) AS views FROM inserted GROUP BY article_id) AS counted
+            WHERE articles.id = counted.article_id
+            "#,
+        )
+        .bind(views.iter().map(|v| v.article_id.value()).collect::<Vec<_>>())
+        .bind(views.iter().map(|v| v.viewer.clone()).collect::<Vec<_>>())
+        .bind(views.iter().map(|v| v.window_start).collect::<Vec<_>>())
+        .bind(views.iter().map(|v| v.viewed_at).collect::<Vec<_>>())
+        .execute(self.database.pool())
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the views made before `before`, the articles' views counts are kept as they are.
+    pub async fn delete_article_views_before(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<u64, AppError> {
+        let (sql, values) = Query::delete()
+            .from_table(ArticleViews::Table)
+            .and_where(Expr::col(ArticleViews::ViewedAt).lt(before))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Returns whether the reaction is new, favoriting is the `like` reaction.
+    pub async fn add_reaction(
+        &self,
+        db: DbExecutor<'_>,
         user_id: UserId,
         article_id: ArticleId,
-    ) -> Result<(), AppError> {
+        kind: ReactionKind,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = Query::insert()
-            .into_table(ArticleFavorites::Table)
-            .columns([ArticleFavorites::UserId, ArticleFavorites::ArticleId])
-            .values_panic([user_id.into(), article_id.into()])
+            .into_table(ArticleReactions::Table)
+            .columns([
+                ArticleReactions::UserId,
+                ArticleReactions::ArticleId,
+                ArticleReactions::Kind,
+            ])
+            .values_panic([user_id.into(), article_id.into(), kind.into()])
             .on_conflict(
                 sea_query::OnConflict::columns([
-                    ArticleFavorites::UserId,
-                    ArticleFavorites::ArticleId,
+                    ArticleReactions::UserId,
+                    ArticleReactions::ArticleId,
+                    ArticleReactions::Kind,
                 ])
                 .do_nothing()
                 .to_owned(),
             )
             .build_sqlx(PostgresQueryBuilder);
 
-        sqlx::query_with(&sql, values)
-            .execute(self.database.pool())
-            .await?;
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn remove_reaction(
+        &self,
+        db: DbExecutor<'_>,
+        user_id: UserId,
+        article_id: ArticleId,
+        kind: ReactionKind,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(ArticleReactions::Table)
+            .and_where(Expr::col(ArticleReactions::UserId).eq(user_id))
+            .and_where(Expr::col(ArticleReactions::ArticleId).eq(article_id))
+            .and_where(Expr::col(ArticleReactions::Kind).eq(kind))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Bookmarks the article, or replaces the folder and note of an existing bookmark.
+    pub async fn bookmark_article(
+        &self,
+        db: DbExecutor<'_>,
+        params: InsertBookmarkParams,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(Bookmarks::Table)
+            .columns([
+                Bookmarks::UserId,
+                Bookmarks::ArticleId,
+                Bookmarks::Folder,
+                Bookmarks::Note,
+            ])
+            .values_panic([
+                params.user_id.into(),
+                params.article_id.into(),
+                params.folder.map(String::from).into(),
+                params.note.map(String::from).into(),
+            ])
+            .on_conflict(
+                sea_query::OnConflict::columns([Bookmarks::UserId, Bookmarks::ArticleId])
+                    .update_columns([Bookmarks::Folder, Bookmarks::Note])
+                    .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
 
         Ok(())
     }
 
-    pub async fn unfavorite_article(
+    pub async fn unbookmark_article(
         &self,
+        db: DbExecutor<'_>,
         user_id: UserId,
         article_id: ArticleId,
     ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = Query::delete()
-            .from_table(ArticleFavorites::Table)
-            .and_where(Expr::col(ArticleFavorites::UserId).eq(user_id))
-            .and_where(Expr::col(ArticleFavorites::ArticleId).eq(article_id))
+            .from_table(Bookmarks::Table)
+            .and_where(Expr::col(Bookmarks::UserId).eq(user_id))
+            .and_where(Expr::col(Bookmarks::ArticleId).eq(article_id))
             .build_sqlx(PostgresQueryBuilder);
 
-        sqlx::query_with(&sql, values)
-            .execute(self.database.pool())
-            .await?;
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
 
         Ok(())
     }
 
+    pub async fn list_bookmarks(
+        &self,
+        user_id: UserId,
+        folder: Option<&BookmarkFolder>,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+    ) -> Result<Vec<BookmarkView>, AppError> {
+        let mut query = build_article_view_query(Some(user_id), |q| {
+            q.inner_join(
+                Bookmarks::Table,
+                Expr::col((Bookmarks::Table, Bookmarks::ArticleId))
+                    .equals((Articles::Table, Articles::Id))
+                    .and(Expr::col((Bookmarks::Table, Bookmarks::UserId)).eq(user_id)),
+            );
+
+            if let Some(folder) = folder {
+                q.and_where(Expr::col((Bookmarks::Table, Bookmarks::Folder)).eq(folder.value()));
+            }
+        });
+
+        let (sql, values) = query
+            .expr_as(
+                Expr::col((Bookmarks::Table, Bookmarks::Folder)),
+                Alias::new("bookmark_folder"),
+            )
+            .expr_as(
+                Expr::col((Bookmarks::Table, Bookmarks::Note)),
+                Alias::new("bookmark_note"),
+            )
+            .expr_as(
+                Expr::col((Bookmarks::Table, Bookmarks::CreatedAt)),
+                Alias::new("bookmarked_at"),
+            )
+            .group_by_col((Bookmarks::Table, Bookmarks::UserId))
+            .group_by_col((Bookmarks::Table, Bookmarks::ArticleId))
+            .order_by((Bookmarks::Table, Bookmarks::CreatedAt), Order::Desc)
+            .order_by((Articles::Table, Articles::Id), Order::Desc)
+            .limit(limit.unwrap_or_default().value())
+            .offset(offset.unwrap_or_default().value())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(BookmarkView::from_row).collect())
+    }
+
+    pub async fn count_bookmarks(
+        &self,
+        user_id: UserId,
+        folder: Option<&BookmarkFolder>,
+    ) -> Result<u64, AppError> {
+        let mut query = Query::select();
+        query
+            .expr_as(Expr::cust("COUNT(*)"), "count")
+            .from(Bookmarks::Table)
+            .and_where(Expr::col(Bookmarks::UserId).eq(user_id));
+
+        if let Some(folder) = folder {
+            query.and_where(Expr::col(Bookmarks::Folder).eq(folder.value()));
+        }
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        let count: i64 = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?
+            .get("count");
+
+        Ok(count as u64)
+    }
+
+    /// Articles of the series in reading order, leaving out those the user can't see.
+    pub async fn list_series_articles(
+        &self,
+        series_id: SeriesId,
+        user_id: Option<UserId>,
+    ) -> Result<Vec<ArticleListView>, AppError> {
+        let mut query = build_article_view_query(user_id, |q| {
+            q.inner_join(
+                SeriesArticles::Table,
+                Expr::col((SeriesArticles::Table, SeriesArticles::ArticleId))
+                    .equals((Articles::Table, Articles::Id))
+                    .and(
+                        Expr::col((SeriesArticles::Table, SeriesArticles::SeriesId)).eq(series_id),
+                    ),
+            )
+            .and_where(readable_author_condition(user_id));
+        });
+
+        let (sql, values) = query
+            .group_by_col((SeriesArticles::Table, SeriesArticles::Position))
+            .order_by(
+                (SeriesArticles::Table, SeriesArticles::Position),
+                Order::Asc,
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(ArticleListView::from_row).collect())
+    }
+
+    pub async fn count_favorites(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+    ) -> Result<i64, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::select()
+            .expr_as(Expr::cust("COUNT(*)"), "count")
+            .from(ArticleFavorites::Table)
+            .and_where(Expr::col(ArticleFavorites::ArticleId).eq(article_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(row.get("count"))
+    }
+
     pub async fn add_tags_to_article(
         &self,
+        db: DbExecutor<'_>,
         article_id: ArticleId,
         tag_ids: &[uuid::Uuid],
     ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
         for tag_id in tag_ids {
             let (sql, values) = Query::insert()
                 .into_table(ArticleTags::Table)
@@ -463,11 +1394,56 @@ impl ArticleRepository {
                 )
                 .build_sqlx(PostgresQueryBuilder);
 
-            sqlx::query_with(&sql, values)
-                .execute(self.database.pool())
-                .await?;
+            sqlx::query_with(&sql, values).execute(&mut *conn).await?;
         }
 
         Ok(())
     }
+
+    /// Drops every tag of the article that isn't in `tag_ids`.
+    pub async fn retain_article_tags(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        tag_ids: &[uuid::Uuid],
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(ArticleTags::Table)
+            .and_where(Expr::col(ArticleTags::ArticleId).eq(article_id))
+            .and_where(Expr::col(ArticleTags::TagId).is_not_in(tag_ids.iter().copied()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_tags_from_article(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+        tag_names: &[TagName],
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(ArticleTags::Table)
+            .and_where(Expr::col(ArticleTags::ArticleId).eq(article_id))
+            .and_where(
+                Expr::col(ArticleTags::TagId).in_subquery(
+                    Query::select()
+                        .column(Tags::Id)
+                        .from(Tags::Table)
+                        .and_where(Expr::col(Tags::Name).is_in(tag_names.iter().cloned()))
+                        .to_owned(),
+                ),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
 }
@@ -1,14 +1,19 @@
 use crate::app_error::AppError;
-use crate::database::Database;
+use crate::database::{Database, DbExecutor};
+use crate::model::comment_sort::CommentSort;
+use crate::model::limit::Limit;
 use crate::model::persistence::comment::Comment;
 use crate::model::persistence::comment_view::CommentView;
+use crate::model::persistence::reaction_count::ReactionCount;
 use crate::model::values::article_id::ArticleId;
 use crate::model::values::comment_id::CommentId;
+use crate::model::values::reaction_kind::ReactionKind;
 use crate::model::values::user_id::UserId;
 use crate::persistence::params::insert_comment_params::InsertCommentParams;
-use crate::persistence::schema::{Comments, UserFollows, Users};
+use crate::persistence::profile_repository::muted_subquery;
+use crate::persistence::schema::{CommentReactions, Comments, UserFollows, Users};
 use anyhow::Result;
-use sea_query::{Alias, Expr, Order, PostgresQueryBuilder, Query};
+use sea_query::{Alias, Expr, Func, Order, PostgresQueryBuilder, Query, SimpleExpr};
 use sea_query_binder::SqlxBinder;
 use sqlx::Row;
 
@@ -60,6 +65,7 @@ fn comment_view_query(user_id: Option<UserId>) -> sea_query::SelectStatement {
                 .to_owned();
 
             select.expr_as(Expr::exists(subquery), Alias::new("following"));
+            select.and_where(Expr::exists(muted_subquery(user_id)).not());
         }
         None => {
             select.expr_as(Expr::val(false), Alias::new("following"));
@@ -69,12 +75,24 @@ fn comment_view_query(user_id: Option<UserId>) -> sea_query::SelectStatement {
     select
 }
 
+fn reactions_count() -> SimpleExpr {
+    Expr::cust(
+        "(SELECT COUNT(*) FROM comment_reactions WHERE comment_reactions.comment_id = comments.id)",
+    )
+}
+
 impl CommentRepository {
     pub fn new(database: Database) -> Self {
         CommentRepository { database }
     }
 
-    pub async fn insert_comment(&self, params: InsertCommentParams) -> Result<Comment, AppError> {
+    pub async fn insert_comment(
+        &self,
+        db: DbExecutor<'_>,
+        params: InsertCommentParams,
+    ) -> Result<Comment, AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = Query::insert()
             .into_table(Comments::Table)
             .columns([Comments::Body, Comments::ArticleId, Comments::AuthorId])
@@ -86,31 +104,66 @@ impl CommentRepository {
             .returning_all()
             .build_sqlx(PostgresQueryBuilder);
 
-        let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
-            .await?;
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
 
         Ok(Comment::from_row(row))
     }
 
-    pub async fn delete_comment(&self, comment_id: CommentId) -> Result<(), AppError> {
+    pub async fn delete_comment(
+        &self,
+        db: DbExecutor<'_>,
+        comment_id: CommentId,
+    ) -> Result<Option<ArticleId>, AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = Query::delete()
             .from_table(Comments::Table)
             .and_where(Expr::col(Comments::Id).eq(comment_id))
+            .returning_col(Comments::ArticleId)
             .build_sqlx(PostgresQueryBuilder);
 
-        sqlx::query_with(&sql, values)
-            .execute(self.database.pool())
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(&mut *conn)
             .await?;
 
-        Ok(())
+        Ok(row.map(|row| row.get("article_id")))
+    }
+
+    pub async fn get_comment_by_id(
+        &self,
+        db: DbExecutor<'_>,
+        comment_id: CommentId,
+    ) -> Result<Option<Comment>, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::select()
+            .columns([
+                Comments::Id,
+                Comments::Body,
+                Comments::ArticleId,
+                Comments::AuthorId,
+                Comments::CreatedAt,
+                Comments::UpdatedAt,
+            ])
+            .from(Comments::Table)
+            .and_where(Expr::col(Comments::Id).eq(comment_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        Ok(row.map(Comment::from_row))
     }
 
     pub async fn is_comment_author(
         &self,
+        db: DbExecutor<'_>,
         comment_id: CommentId,
         user_id: UserId,
     ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
         let subquery = Query::select()
             .expr(Expr::value(1))
             .from(Comments::Table)
@@ -122,30 +175,68 @@ impl CommentRepository {
             .expr_as(Expr::exists(subquery), "is_author")
             .build_sqlx(PostgresQueryBuilder);
 
-        let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
-            .await?;
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
 
         Ok(row.get("is_author"))
     }
 
+    /// Pages with a cursor, the id of the last comment of the previous page: comments are
+    /// compared to it on the sorted columns, so new comments don't shift the next pages.
     pub async fn get_comments(
         &self,
         article_id: ArticleId,
         user_id: Option<UserId>,
+        sort: CommentSort,
+        cursor: Option<CommentId>,
+        limit: Limit,
     ) -> Result<Vec<CommentView>, AppError> {
         let mut query = comment_view_query(user_id);
 
+        let created_at: SimpleExpr = Expr::col((Comments::Table, Comments::CreatedAt)).into();
+        let id: SimpleExpr = Expr::col((Comments::Table, Comments::Id)).into();
+
+        let (order, keys) = match sort {
+            CommentSort::Oldest => (Order::Asc, vec![created_at, id]),
+            CommentSort::Newest => (Order::Desc, vec![created_at, id]),
+            CommentSort::Top => (Order::Desc, vec![reactions_count(), created_at, id]),
+        };
+
+        // An unknown cursor gives an empty page, as the row comparison is then NULL.
+        if let Some(cursor) = cursor {
+            let cursor_keys = Query::select()
+                .exprs(keys.clone())
+                .from(Comments::Table)
+                .and_where(Expr::col((Comments::Table, Comments::Id)).eq(cursor))
+                .and_where(Expr::col((Comments::Table, Comments::ArticleId)).eq(article_id))
+                .to_owned();
+            let cursor_keys =
+                SimpleExpr::SubQuery(None, Box::new(cursor_keys.into_sub_query_statement()));
+
+            let after_cursor = match order {
+                Order::Asc => Expr::tuple(keys.clone()).gt(cursor_keys),
+                _ => Expr::tuple(keys.clone()).lt(cursor_keys),
+            };
+            query.and_where(after_cursor);
+        }
+
+        for key in keys {
+            query.order_by_expr(key, order.clone());
+        }
+
         let (sql, values) = query
-            .and_where(Expr::col(Comments::ArticleId).eq(article_id))
-            .order_by(Comments::CreatedAt, Order::Desc)
+            .and_where(Expr::col((Comments::Table, Comments::ArticleId)).eq(article_id))
+            .limit(limit.value())
             .build_sqlx(PostgresQueryBuilder);
 
         let rows = sqlx::query_with(&sql, values)
             .fetch_all(self.database.pool())
             .await?;
 
-        Ok(rows.into_iter().map(CommentView::from_row).collect())
+        let mut comments: Vec<_> = rows.into_iter().map(CommentView::from_row).collect();
+        self.load_reactions((&self.database).into(), &mut comments, user_id)
+            .await?;
+
+        Ok(comments)
     }
 
     pub async fn get_comment(
@@ -153,17 +244,139 @@ impl CommentRepository {
         comment_id: CommentId,
         user_id: Option<UserId>,
     ) -> Result<CommentView, AppError> {
+        self.find_comment((&self.database).into(), comment_id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    pub async fn find_comment(
+        &self,
+        db: DbExecutor<'_>,
+        comment_id: CommentId,
+        user_id: Option<UserId>,
+    ) -> Result<Option<CommentView>, AppError> {
+        let mut conn = db.acquire().await?;
+
         let mut query = comment_view_query(user_id);
 
         let (sql, values) = query
             .and_where(Expr::col((Comments::Table, Comments::Id)).eq(comment_id))
-            .order_by(Comments::CreatedAt, Order::Desc)
             .build_sqlx(PostgresQueryBuilder);
 
         let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        let mut comments: Vec<_> = row.into_iter().map(CommentView::from_row).collect();
+        self.load_reactions((&mut *conn).into(), &mut comments, user_id)
             .await?;
 
-        Ok(CommentView::from_row(row))
+        Ok(comments.pop())
+    }
+
+    /// Counts the reactions of all the comments in a single query.
+    async fn load_reactions(
+        &self,
+        db: DbExecutor<'_>,
+        comments: &mut [CommentView],
+        user_id: Option<UserId>,
+    ) -> Result<(), AppError> {
+        if comments.is_empty() {
+            return Ok(());
+        }
+
+        let reacted: SimpleExpr = match user_id {
+            Some(user_id) => Func::cust("BOOL_OR")
+                .arg(Expr::col((CommentReactions::Table, CommentReactions::UserId)).eq(user_id))
+                .into(),
+            None => Expr::cust("FALSE"),
+        };
+
+        let (sql, values) = Query::select()
+            .column((CommentReactions::Table, CommentReactions::CommentId))
+            .column((CommentReactions::Table, CommentReactions::Kind))
+            .expr_as(Expr::cust("COUNT(*)"), Alias::new("count"))
+            .expr_as(reacted, Alias::new("reacted"))
+            .from(CommentReactions::Table)
+            .and_where(
+                Expr::col((CommentReactions::Table, CommentReactions::CommentId))
+                    .is_in(comments.iter().map(|comment| comment.id)),
+            )
+            .group_by_col((CommentReactions::Table, CommentReactions::CommentId))
+            .group_by_col((CommentReactions::Table, CommentReactions::Kind))
+            .order_by(
+                (CommentReactions::Table, CommentReactions::Kind),
+                Order::Asc,
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let mut conn = db.acquire().await?;
+
+        let rows = sqlx::query_with(&sql, values).fetch_all(&mut *conn).await?;
+
+        for row in rows {
+            let comment_id: CommentId = row.get("comment_id");
+
+            if let Some(comment) = comments.iter_mut().find(|c| c.id == comment_id) {
+                comment.reactions.push(ReactionCount::from_row(&row));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the reaction is new.
+    pub async fn add_reaction(
+        &self,
+        db: DbExecutor<'_>,
+        user_id: UserId,
+        comment_id: CommentId,
+        kind: ReactionKind,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(CommentReactions::Table)
+            .columns([
+                CommentReactions::UserId,
+                CommentReactions::CommentId,
+                CommentReactions::Kind,
+            ])
+            .values_panic([user_id.into(), comment_id.into(), kind.into()])
+            .on_conflict(
+                sea_query::OnConflict::columns([
+                    CommentReactions::UserId,
+                    CommentReactions::CommentId,
+                    CommentReactions::Kind,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn remove_reaction(
+        &self,
+        db: DbExecutor<'_>,
+        user_id: UserId,
+        comment_id: CommentId,
+        kind: ReactionKind,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(CommentReactions::Table)
+            .and_where(Expr::col(CommentReactions::UserId).eq(user_id))
+            .and_where(Expr::col(CommentReactions::CommentId).eq(comment_id))
+            .and_where(Expr::col(CommentReactions::Kind).eq(kind))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected() > 0)
     }
 }
@@ -1,7 +1,12 @@
 pub mod article_repository;
 pub mod comment_repository;
+pub mod notification_repository;
+pub mod outbox_repository;
 pub mod params;
 pub mod profile_repository;
 pub mod schema;
+pub mod series_repository;
+pub mod stats_repository;
 pub mod tag_repository;
 pub mod user_repository;
+pub mod webhook_repository;
@@ -0,0 +1,285 @@
+use crate::app_error::AppError;
+use crate::database::{Database, DbExecutor};
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::notification_preferences::NotificationPreferences;
+use crate::model::persistence::notification_view::NotificationView;
+use crate::model::values::notification_id::NotificationId;
+use crate::model::values::user_id::UserId;
+use crate::persistence::params::insert_notification_params::InsertNotificationParams;
+use crate::persistence::params::update_notification_preferences_params::UpdateNotificationPreferencesParams;
+use crate::persistence::schema::{
+    Articles, NotificationPreferences as Preferences, Notifications, UserFollows, Users,
+};
+use anyhow::Result;
+use sea_query::{Alias, Expr, OnConflict, Order, PostgresQueryBuilder, Query, SelectStatement};
+use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+
+#[derive(Clone)]
+pub struct NotificationRepository {
+    database: Database,
+}
+
+fn notification_view_query(recipient_id: UserId) -> SelectStatement {
+    let following_subquery = Query::select()
+        .expr(Expr::cust("1"))
+        .from(UserFollows::Table)
+        .and_where(
+            Expr::col((UserFollows::Table, UserFollows::FollowerId))
+                .eq(recipient_id)
+                .and(
+                    Expr::col((UserFollows::Table, UserFollows::FolloweeId))
+                        .eq(Expr::col((Users::Table, Users::Id))),
+                ),
+        )
+        .to_owned();
+
+    Query::select()
+        .column((Notifications::Table, Notifications::Id))
+        .column((Notifications::Table, Notifications::Kind))
+        .column((Notifications::Table, Notifications::CommentId))
+        .column((Notifications::Table, Notifications::ReadAt))
+        .column((Notifications::Table, Notifications::CreatedAt))
+        .expr_as(
+            Expr::col((Articles::Table, Articles::Slug)),
+            Alias::new("article_slug"),
+        )
+        .expr_as(
+            Expr::col((Articles::Table, Articles::Title)),
+            Alias::new("article_title"),
+        )
+        .expr_as(
+            Expr::col((Users::Table, Users::Username)),
+            Alias::new("actor_username"),
+        )
+        .expr_as(
+            Expr::col((Users::Table, Users::Bio)),
+            Alias::new("actor_bio"),
+        )
+        .expr_as(
+            Expr::col((Users::Table, Users::Image)),
+            Alias::new("actor_image"),
+        )
+        .expr_as(Expr::exists(following_subquery), Alias::new("following"))
+        .from(Notifications::Table)
+        .inner_join(
+            Users::Table,
+            Expr::col((Notifications::Table, Notifications::ActorId))
+                .eq(Expr::col((Users::Table, Users::Id))),
+        )
+        .left_join(
+            Articles::Table,
+            Expr::col((Notifications::Table, Notifications::ArticleId))
+                .eq(Expr::col((Articles::Table, Articles::Id))),
+        )
+        .and_where(Expr::col((Notifications::Table, Notifications::RecipientId)).eq(recipient_id))
+        .to_owned()
+}
+
+impl NotificationRepository {
+    pub fn new(database: Database) -> Self {
+        NotificationRepository { database }
+    }
+
+    pub async fn insert_notification(
+        &self,
+        db: DbExecutor<'_>,
+        params: InsertNotificationParams,
+    ) -> Result<NotificationId, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(Notifications::Table)
+            .columns([
+                Notifications::RecipientId,
+                Notifications::ActorId,
+                Notifications::Kind,
+                Notifications::ArticleId,
+                Notifications::CommentId,
+            ])
+            .values_panic([
+                params.recipient_id.into(),
+                params.actor_id.into(),
+                params.kind.into(),
+                params.article_id.map(uuid::Uuid::from).into(),
+                params.comment_id.map(uuid::Uuid::from).into(),
+            ])
+            .returning_col(Notifications::Id)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(row.get("id"))
+    }
+
+    pub async fn list_notifications(
+        &self,
+        recipient_id: UserId,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+    ) -> Result<Vec<NotificationView>, AppError> {
+        let (sql, values) = notification_view_query(recipient_id)
+            .order_by(
+                (Notifications::Table, Notifications::CreatedAt),
+                Order::Desc,
+            )
+            .limit(limit.unwrap_or_default().value())
+            .offset(offset.unwrap_or_default().value())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(NotificationView::from_row).collect())
+    }
+
+    pub async fn get_notification(
+        &self,
+        recipient_id: UserId,
+        notification_id: NotificationId,
+    ) -> Result<Option<NotificationView>, AppError> {
+        let (sql, values) = notification_view_query(recipient_id)
+            .and_where(Expr::col((Notifications::Table, Notifications::Id)).eq(notification_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(NotificationView::from_row))
+    }
+
+    pub async fn count_notifications(
+        &self,
+        recipient_id: UserId,
+        unread_only: bool,
+    ) -> Result<u64, AppError> {
+        let mut query = Query::select();
+        query
+            .expr_as(Expr::cust("COUNT(*)"), "count")
+            .from(Notifications::Table)
+            .and_where(Expr::col(Notifications::RecipientId).eq(recipient_id));
+
+        if unread_only {
+            query.and_where(Expr::col(Notifications::ReadAt).is_null());
+        }
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        let count: i64 = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?
+            .get("count");
+
+        Ok(count as u64)
+    }
+
+    pub async fn mark_as_read(
+        &self,
+        recipient_id: UserId,
+        notification_id: NotificationId,
+    ) -> Result<bool, AppError> {
+        let (sql, values) = Query::update()
+            .table(Notifications::Table)
+            .value(
+                Notifications::ReadAt,
+                Expr::cust("COALESCE(read_at, NOW())"),
+            )
+            .and_where(Expr::col(Notifications::Id).eq(notification_id))
+            .and_where(Expr::col(Notifications::RecipientId).eq(recipient_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn mark_all_as_read(&self, recipient_id: UserId) -> Result<(), AppError> {
+        let (sql, values) = Query::update()
+            .table(Notifications::Table)
+            .value(Notifications::ReadAt, Expr::current_timestamp())
+            .and_where(Expr::col(Notifications::RecipientId).eq(recipient_id))
+            .and_where(Expr::col(Notifications::ReadAt).is_null())
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_preferences(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<NotificationPreferences>, AppError> {
+        self.find_preferences((&self.database).into(), user_id)
+            .await
+    }
+
+    /// Same as `get_preferences`, but on the connection of the caller's transaction.
+    pub async fn find_preferences(
+        &self,
+        db: DbExecutor<'_>,
+        user_id: UserId,
+    ) -> Result<Option<NotificationPreferences>, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::select()
+            .columns([
+                Preferences::UserId,
+                Preferences::ArticleCommented,
+                Preferences::ArticleFavorited,
+                Preferences::UserFollowed,
+            ])
+            .from(Preferences::Table)
+            .and_where(Expr::col(Preferences::UserId).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        Ok(row.map(NotificationPreferences::from_row))
+    }
+
+    pub async fn update_preferences(
+        &self,
+        params: UpdateNotificationPreferencesParams,
+    ) -> Result<NotificationPreferences, AppError> {
+        let updates = params.as_list();
+
+        let mut columns = vec![Preferences::UserId];
+        let mut values: Vec<sea_query::SimpleExpr> = vec![params.user_id.into()];
+        let mut update_columns = Vec::new();
+
+        for (column, value) in updates {
+            update_columns.push(column.clone());
+            columns.push(column);
+            values.push(value.into());
+        }
+
+        let on_conflict = OnConflict::column(Preferences::UserId)
+            .update_columns(update_columns)
+            .value(Preferences::UpdatedAt, Expr::current_timestamp())
+            .to_owned();
+
+        let (sql, values) = Query::insert()
+            .into_table(Preferences::Table)
+            .columns(columns)
+            .values_panic(values)
+            .on_conflict(on_conflict)
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?;
+
+        Ok(NotificationPreferences::from_row(row))
+    }
+}
@@ -0,0 +1,128 @@
+use crate::app_error::AppError;
+use crate::database::DbExecutor;
+use crate::events::app_event::AppEvent;
+use crate::model::persistence::outbox_event::OutboxEvent;
+use crate::persistence::schema::OutboxEvents;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sea_query::{Expr, LockBehavior, LockType, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+
+/// Holds no pool of its own, events are written and claimed inside a transaction and only
+/// the cleanup job deletes them through the pool.
+#[derive(Clone, Default)]
+pub struct OutboxRepository;
+
+impl OutboxRepository {
+    pub fn new() -> Self {
+        OutboxRepository
+    }
+
+    pub async fn insert_event(&self, db: DbExecutor<'_>, event: &AppEvent) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let payload = serde_json::to_value(event).expect("Events are serializable");
+
+        let (sql, values) = Query::insert()
+            .into_table(OutboxEvents::Table)
+            .columns([OutboxEvents::EventType, OutboxEvents::Payload])
+            .values_panic([event.event_type().into(), payload.into()])
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    /// Locks the oldest unprocessed events until the transaction ends, skipping the ones
+    /// another dispatcher is already working on.
+    pub async fn claim_pending(
+        &self,
+        db: DbExecutor<'_>,
+        batch_size: u64,
+    ) -> Result<Vec<OutboxEvent>, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::select()
+            .columns([
+                OutboxEvents::Id,
+                OutboxEvents::Payload,
+                OutboxEvents::Attempts,
+                OutboxEvents::CreatedAt,
+            ])
+            .from(OutboxEvents::Table)
+            .and_where(Expr::col(OutboxEvents::ProcessedAt).is_null())
+            .order_by(OutboxEvents::Id, Order::Asc)
+            .limit(batch_size)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values).fetch_all(&mut *conn).await?;
+
+        Ok(rows.into_iter().map(OutboxEvent::from_row).collect())
+    }
+
+    pub async fn mark_processed(&self, db: DbExecutor<'_>, id: i64) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::update()
+            .table(OutboxEvents::Table)
+            .value(OutboxEvents::ProcessedAt, Expr::current_timestamp())
+            .and_where(Expr::col(OutboxEvents::Id).eq(id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    /// Records a failed attempt. Events that are given up on are marked processed, keeping
+    /// the error for inspection.
+    pub async fn mark_failed(
+        &self,
+        db: DbExecutor<'_>,
+        id: i64,
+        error: String,
+        give_up: bool,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let mut query = Query::update();
+        query
+            .table(OutboxEvents::Table)
+            .value(
+                OutboxEvents::Attempts,
+                Expr::col(OutboxEvents::Attempts).add(1),
+            )
+            .value(OutboxEvents::LastError, error)
+            .and_where(Expr::col(OutboxEvents::Id).eq(id));
+
+        if give_up {
+            query.value(OutboxEvents::ProcessedAt, Expr::current_timestamp());
+        }
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    /// Deletes the events processed before `before`, pending ones are kept however old.
+    pub async fn delete_processed(
+        &self,
+        db: DbExecutor<'_>,
+        before: DateTime<Utc>,
+    ) -> Result<u64, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(OutboxEvents::Table)
+            .and_where(Expr::col(OutboxEvents::ProcessedAt).lt(before))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected())
+    }
+}
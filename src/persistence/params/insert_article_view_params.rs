@@ -0,0 +1,9 @@
+use crate::model::values::article_id::ArticleId;
+use chrono::{DateTime, Utc};
+
+pub struct InsertArticleViewParams {
+    pub article_id: ArticleId,
+    pub viewer: String,
+    pub window_start: DateTime<Utc>,
+    pub viewed_at: DateTime<Utc>,
+}
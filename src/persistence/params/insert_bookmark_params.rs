@@ -0,0 +1,11 @@
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::bookmark_folder::BookmarkFolder;
+use crate::model::values::bookmark_note::BookmarkNote;
+use crate::model::values::user_id::UserId;
+
+pub struct InsertBookmarkParams {
+    pub user_id: UserId,
+    pub article_id: ArticleId,
+    pub folder: Option<BookmarkFolder>,
+    pub note: Option<BookmarkNote>,
+}
@@ -0,0 +1,12 @@
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::comment_id::CommentId;
+use crate::model::values::notification_kind::NotificationKind;
+use crate::model::values::user_id::UserId;
+
+pub struct InsertNotificationParams {
+    pub recipient_id: UserId,
+    pub actor_id: UserId,
+    pub kind: NotificationKind,
+    pub article_id: Option<ArticleId>,
+    pub comment_id: Option<CommentId>,
+}
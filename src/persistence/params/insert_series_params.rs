@@ -0,0 +1,11 @@
+use crate::model::values::series_description::SeriesDescription;
+use crate::model::values::series_title::SeriesTitle;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+
+pub struct InsertSeriesParams {
+    pub slug: Slug,
+    pub title: SeriesTitle,
+    pub description: Option<SeriesDescription>,
+    pub owner_id: UserId,
+}
@@ -0,0 +1,10 @@
+use crate::model::values::user_id::UserId;
+use crate::model::values::webhook_event::WebhookEvent;
+use crate::model::values::webhook_url::WebhookUrl;
+
+pub struct InsertWebhookParams {
+    pub owner_id: UserId,
+    pub url: WebhookUrl,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+}
@@ -1,14 +1,22 @@
 use crate::domain::commands::list_articles_query::ListArticlesQuery;
+use crate::model::article_sort::{ArticleSort, SortOrder, TagMatch};
 use crate::model::limit::Limit;
 use crate::model::offset::Offset;
 use crate::model::values::tag_name::TagName;
 use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
+use chrono::{DateTime, Utc};
 
 pub struct ListArticlesParams {
-    pub(crate) tag: Option<TagName>,
+    pub(crate) tags: Vec<TagName>,
+    pub(crate) tag_match: TagMatch,
+    pub(crate) excluded_tags: Vec<TagName>,
     pub(crate) author: Option<Username>,
     pub(crate) favorited_by: Option<Username>,
+    pub(crate) created_after: Option<DateTime<Utc>>,
+    pub(crate) created_before: Option<DateTime<Utc>>,
+    pub(crate) sort: ArticleSort,
+    pub(crate) order: SortOrder,
     pub(crate) user_id: Option<UserId>,
     pub(crate) limit: Option<Limit>,
     pub(crate) offset: Option<Offset>,
@@ -17,9 +25,15 @@ pub struct ListArticlesParams {
 impl ListArticlesParams {
     pub fn from_query(query: ListArticlesQuery, user_id: Option<UserId>) -> ListArticlesParams {
         ListArticlesParams {
-            tag: query.tag,
+            tags: query.tags,
+            tag_match: query.tag_match,
+            excluded_tags: query.excluded_tags,
             author: query.author,
             favorited_by: query.favorited_by,
+            created_after: query.created_after,
+            created_before: query.created_before,
+            sort: query.sort,
+            order: query.order,
             user_id,
             limit: query.limit,
             offset: query.offset,
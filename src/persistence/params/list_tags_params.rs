@@ -0,0 +1,9 @@
+use crate::model::limit::Limit;
+use crate::model::tag_sort::TagSort;
+use crate::model::values::user_id::UserId;
+
+pub struct ListTagsParams {
+    pub(crate) sort: TagSort,
+    pub(crate) limit: Option<Limit>,
+    pub(crate) user_id: Option<UserId>,
+}
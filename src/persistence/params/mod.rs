@@ -1,7 +1,16 @@
 pub mod insert_article_params;
+pub mod insert_article_view_params;
+pub mod insert_bookmark_params;
 pub mod insert_comment_params;
+pub mod insert_notification_params;
+pub mod insert_series_params;
 pub mod insert_tag_params;
 pub mod insert_user_params;
+pub mod insert_webhook_params;
 pub mod list_articles_params;
+pub mod list_tags_params;
 pub mod update_article_params;
+pub mod update_notification_preferences_params;
+pub mod update_series_params;
+pub mod update_tag_params;
 pub mod update_user_params;
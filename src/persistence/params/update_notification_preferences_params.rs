@@ -0,0 +1,27 @@
+use crate::model::values::user_id::UserId;
+use crate::persistence::schema::NotificationPreferences;
+
+pub struct UpdateNotificationPreferencesParams {
+    pub(crate) user_id: UserId,
+    pub(crate) article_commented: Option<bool>,
+    pub(crate) article_favorited: Option<bool>,
+    pub(crate) user_followed: Option<bool>,
+}
+
+impl UpdateNotificationPreferencesParams {
+    pub fn as_list(&self) -> Vec<(NotificationPreferences, bool)> {
+        let mut fields = Vec::new();
+
+        if let Some(article_commented) = self.article_commented {
+            fields.push((NotificationPreferences::ArticleCommented, article_commented));
+        }
+        if let Some(article_favorited) = self.article_favorited {
+            fields.push((NotificationPreferences::ArticleFavorited, article_favorited));
+        }
+        if let Some(user_followed) = self.user_followed {
+            fields.push((NotificationPreferences::UserFollowed, user_followed));
+        }
+
+        fields
+    }
+}
@@ -0,0 +1,30 @@
+use crate::model::values::series_description::SeriesDescription;
+use crate::model::values::series_id::SeriesId;
+use crate::model::values::series_title::SeriesTitle;
+use crate::model::values::slug::Slug;
+use crate::persistence::schema::Series;
+
+pub struct UpdateSeriesParams {
+    pub series_id: SeriesId,
+    pub slug: Option<Slug>,
+    pub title: Option<SeriesTitle>,
+    pub description: Option<SeriesDescription>,
+}
+
+impl UpdateSeriesParams {
+    pub fn as_list(&self) -> Vec<(Series, String)> {
+        let mut fields = Vec::new();
+
+        if let Some(slug) = &self.slug {
+            fields.push((Series::Slug, slug.value().to_string()));
+        }
+        if let Some(title) = &self.title {
+            fields.push((Series::Title, title.value().to_string()));
+        }
+        if let Some(description) = &self.description {
+            fields.push((Series::Description, description.value().to_string()));
+        }
+
+        fields
+    }
+}
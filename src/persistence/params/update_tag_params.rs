@@ -0,0 +1,25 @@
+use crate::model::values::tag_description::TagDescription;
+use crate::model::values::tag_id::TagId;
+use crate::model::values::tag_name::TagName;
+use crate::persistence::schema::Tags;
+
+pub struct UpdateTagParams {
+    pub tag_id: TagId,
+    pub name: Option<TagName>,
+    pub description: Option<TagDescription>,
+}
+
+impl UpdateTagParams {
+    pub fn as_list(&self) -> Vec<(Tags, String)> {
+        let mut fields = Vec::new();
+
+        if let Some(name) = &self.name {
+            fields.push((Tags::Name, name.value().to_string()));
+        }
+        if let Some(description) = &self.description {
+            fields.push((Tags::Description, description.value().to_string()));
+        }
+
+        fields
+    }
+}
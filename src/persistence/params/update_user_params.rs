@@ -5,6 +5,8 @@ use crate::model::values::password_hash::PasswordHash;
 use crate::model::values::user_id::UserId;
 use crate::model::values::username::Username;
 use crate::persistence::schema::Users;
+use chrono::{DateTime, Utc};
+use sea_query::Value;
 
 pub struct UpdateUserParams {
     pub(crate) user_id: UserId,
@@ -13,26 +15,35 @@ pub struct UpdateUserParams {
     pub(crate) password_hash: Option<PasswordHash>,
     pub(crate) bio: Option<Bio>,
     pub(crate) image: Option<Image>,
+    pub(crate) private: Option<bool>,
+    pub(crate) username_changed_at: Option<DateTime<Utc>>,
 }
 
 impl UpdateUserParams {
-    pub fn as_list(&self) -> Vec<(Users, String)> {
+    pub fn as_list(&self) -> Vec<(Users, Value)> {
         let mut fields = Vec::new();
 
         if let Some(email) = &self.email {
-            fields.push((Users::Email, email.value().to_string()));
+            fields.push((Users::Email, email.value().into()));
         }
         if let Some(username) = &self.username {
-            fields.push((Users::Username, username.value().to_string()));
+            fields.push((Users::Username, username.value().into()));
+            fields.push((Users::UsernameSkeleton, username.skeleton().into()));
         }
         if let Some(password_hash) = &self.password_hash {
-            fields.push((Users::PasswordHash, password_hash.value().to_string()));
+            fields.push((Users::PasswordHash, password_hash.value().into()));
         }
         if let Some(bio) = &self.bio {
-            fields.push((Users::Bio, bio.value().to_string()));
+            fields.push((Users::Bio, bio.value().into()));
         }
         if let Some(image) = &self.image {
-            fields.push((Users::Image, image.value().to_string()));
+            fields.push((Users::Image, image.value().into()));
+        }
+        if let Some(private) = self.private {
+            fields.push((Users::Private, private.into()));
+        }
+        if let Some(username_changed_at) = self.username_changed_at {
+            fields.push((Users::UsernameChangedAt, username_changed_at.into()));
         }
 
         fields
@@ -1,12 +1,138 @@
 use crate::app_error::AppError;
-use crate::database::Database;
+use crate::database::{Database, DbExecutor};
+use crate::model::follow_direction::FollowDirection;
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::profile_view::ProfileView;
 use crate::model::values::user_id::UserId;
-use crate::persistence::schema::UserFollows;
+use crate::model::values::username::Username;
+use crate::persistence::schema::{
+    Articles, FollowRequests, UserBlocks, UserFollows, UserMutes, Users,
+};
 use anyhow::Result;
-use sea_query::{Expr, PostgresQueryBuilder, Query};
+use sea_query::{
+    Alias, Cond, Expr, Func, Iden, Order, PostgresQueryBuilder, Query, SelectStatement, SimpleExpr,
+};
 use sea_query_binder::SqlxBinder;
 use sqlx::Row;
 
+/// Number of rows of `table` whose `column` references the selected user.
+fn count_subquery<T: Iden + 'static>(table: T, column: T) -> SimpleExpr {
+    SimpleExpr::SubQuery(
+        None,
+        Box::new(
+            Query::select()
+                .expr(Expr::cust("COUNT(*)"))
+                .from(table)
+                .and_where(Expr::col(column).equals((Users::Table, Users::Id)))
+                .to_owned()
+                .into_sub_query_statement(),
+        ),
+    )
+}
+
+/// Rows of a relationship `table` going from `user_id` through `from` to the selected user
+/// through `to`.
+fn relation_subquery<T: Iden + Clone + 'static>(
+    table: T,
+    from: T,
+    to: T,
+    user_id: UserId,
+) -> SelectStatement {
+    Query::select()
+        .expr(Expr::cust("1"))
+        .from(table.clone())
+        .and_where(Expr::col((table.clone(), from)).eq(user_id))
+        .and_where(Expr::col((table, to)).equals((Users::Table, Users::Id)))
+        .to_owned()
+}
+
+/// Matches the selected user when followed by `user_id`.
+fn following_subquery(user_id: UserId) -> SelectStatement {
+    relation_subquery(
+        UserFollows::Table,
+        UserFollows::FollowerId,
+        UserFollows::FolloweeId,
+        user_id,
+    )
+}
+
+/// Matches the selected user when muted by `user_id`, to hide their articles and comments.
+pub(crate) fn muted_subquery(user_id: UserId) -> SelectStatement {
+    relation_subquery(
+        UserMutes::Table,
+        UserMutes::MuterId,
+        UserMutes::MutedId,
+        user_id,
+    )
+}
+
+/// Matches the selected user when they block `user_id`.
+pub(crate) fn blocked_by_subquery(user_id: UserId) -> SelectStatement {
+    relation_subquery(
+        UserBlocks::Table,
+        UserBlocks::BlockedId,
+        UserBlocks::BlockerId,
+        user_id,
+    )
+}
+
+/// Selects users as profiles, with their counters computed in the same query.
+fn build_profile_view_query(viewer_id: Option<UserId>) -> SelectStatement {
+    let relation = |subquery: fn(UserId) -> SelectStatement| match viewer_id {
+        Some(viewer_id) => Expr::exists(subquery(viewer_id)),
+        None => Expr::cust("FALSE"),
+    };
+
+    Query::select()
+        .columns([
+            (Users::Table, Users::Id),
+            (Users::Table, Users::Username),
+            (Users::Table, Users::Bio),
+            (Users::Table, Users::Image),
+            (Users::Table, Users::Private),
+        ])
+        .expr_as(relation(following_subquery), Alias::new("following"))
+        .expr_as(
+            relation(|viewer_id| {
+                relation_subquery(
+                    FollowRequests::Table,
+                    FollowRequests::RequesterId,
+                    FollowRequests::TargetId,
+                    viewer_id,
+                )
+            }),
+            Alias::new("follow_requested"),
+        )
+        .expr_as(
+            relation(|viewer_id| {
+                relation_subquery(
+                    UserBlocks::Table,
+                    UserBlocks::BlockerId,
+                    UserBlocks::BlockedId,
+                    viewer_id,
+                )
+            }),
+            Alias::new("blocking"),
+        )
+        .expr_as(relation(muted_subquery), Alias::new("muting"))
+        .expr_as(relation(blocked_by_subquery), Alias::new("blocked_by"))
+        .expr_as(
+            count_subquery(UserFollows::Table, UserFollows::FolloweeId),
+            Alias::new("followers_count"),
+        )
+        .expr_as(
+            count_subquery(UserFollows::Table, UserFollows::FollowerId),
+            Alias::new("following_count"),
+        )
+        .expr_as(
+            count_subquery(Articles::Table, Articles::AuthorId),
+            Alias::new("articles_count"),
+        )
+        .from(Users::Table)
+        .to_owned()
+}
+
 #[derive(Clone)]
 pub struct ProfileRepository {
     database: Database,
@@ -19,9 +145,12 @@ impl ProfileRepository {
 
     pub async fn follow_user(
         &self,
+        db: DbExecutor<'_>,
         follower_id: UserId,
         followee_id: UserId,
-    ) -> Result<(), AppError> {
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = Query::insert()
             .into_table(UserFollows::Table)
             .columns([UserFollows::FollowerId, UserFollows::FolloweeId])
@@ -33,13 +162,12 @@ impl ProfileRepository {
             )
             .build_sqlx(PostgresQueryBuilder);
 
-        sqlx::query_with(&sql, values)
-            .execute(self.database.pool())
-            .await?;
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
 
-        Ok(())
+        Ok(result.rows_affected() > 0)
     }
 
+    /// Also cancels a pending request to follow the user.
     pub async fn unfollow_user(
         &self,
         follower_id: UserId,
@@ -55,14 +183,129 @@ impl ProfileRepository {
             .execute(self.database.pool())
             .await?;
 
+        self.delete_follow_request((&self.database).into(), follower_id, followee_id)
+            .await?;
+
         Ok(())
     }
 
+    pub async fn is_private(&self, db: DbExecutor<'_>, user_id: UserId) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::select()
+            .column(Users::Private)
+            .from(Users::Table)
+            .and_where(Expr::col(Users::Id).eq(user_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        Ok(row.is_some_and(|row| row.get("private")))
+    }
+
+    pub async fn request_follow(
+        &self,
+        db: DbExecutor<'_>,
+        requester_id: UserId,
+        target_id: UserId,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(FollowRequests::Table)
+            .columns([FollowRequests::RequesterId, FollowRequests::TargetId])
+            .values_panic([requester_id.into(), target_id.into()])
+            .on_conflict(
+                sea_query::OnConflict::columns([
+                    FollowRequests::RequesterId,
+                    FollowRequests::TargetId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Returns whether a pending request was deleted.
+    pub async fn delete_follow_request(
+        &self,
+        db: DbExecutor<'_>,
+        requester_id: UserId,
+        target_id: UserId,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(FollowRequests::Table)
+            .and_where(Expr::col(FollowRequests::RequesterId).eq(requester_id))
+            .and_where(Expr::col(FollowRequests::TargetId).eq(target_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Lists the users waiting for `target_id` to approve their follow, newest requests first.
+    pub async fn list_follow_requests(
+        &self,
+        target_id: UserId,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+    ) -> Result<Vec<ProfileView>, AppError> {
+        let (sql, values) = build_profile_view_query(Some(target_id))
+            .inner_join(
+                FollowRequests::Table,
+                Expr::col((FollowRequests::Table, FollowRequests::RequesterId))
+                    .equals((Users::Table, Users::Id))
+                    .and(
+                        Expr::col((FollowRequests::Table, FollowRequests::TargetId)).eq(target_id),
+                    ),
+            )
+            .order_by(
+                (FollowRequests::Table, FollowRequests::CreatedAt),
+                Order::Desc,
+            )
+            .order_by((Users::Table, Users::Id), Order::Desc)
+            .limit(limit.unwrap_or_default().value())
+            .offset(offset.unwrap_or_default().value())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(ProfileView::from_row).collect())
+    }
+
+    pub async fn count_follow_requests(&self, target_id: UserId) -> Result<i64, AppError> {
+        let (sql, values) = Query::select()
+            .expr_as(Expr::cust("COUNT(*)"), Alias::new("count"))
+            .from(FollowRequests::Table)
+            .and_where(Expr::col(FollowRequests::TargetId).eq(target_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?;
+
+        Ok(row.get("count"))
+    }
+
     pub async fn is_following(
         &self,
+        db: DbExecutor<'_>,
         follower_id: UserId,
         followee_id: UserId,
     ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
         let subquery = Query::select()
             .expr(Expr::cust("1"))
             .from(UserFollows::Table)
@@ -77,10 +320,201 @@ impl ProfileRepository {
             )
             .build_sqlx(PostgresQueryBuilder);
 
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(row.get("is_following"))
+    }
+
+    /// Finds the user whatever the case of the username.
+    pub async fn get_profile(
+        &self,
+        username: &Username,
+        viewer_id: Option<UserId>,
+    ) -> Result<Option<ProfileView>, AppError> {
+        let (sql, values) = build_profile_view_query(viewer_id)
+            .and_where(
+                Expr::expr(Func::lower(Expr::col((Users::Table, Users::Username))))
+                    .eq(Func::lower(Expr::val(username.value()))),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
         let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
+            .fetch_optional(self.database.pool())
             .await?;
 
-        Ok(row.get("is_following"))
+        Ok(row.map(ProfileView::from_row))
+    }
+
+    /// Lists followers or followed users of a user, most recent follows first.
+    pub async fn list_follows(
+        &self,
+        user_id: UserId,
+        direction: FollowDirection,
+        viewer_id: Option<UserId>,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+    ) -> Result<Vec<ProfileView>, AppError> {
+        let (listed, of) = match direction {
+            FollowDirection::Followers => (UserFollows::FollowerId, UserFollows::FolloweeId),
+            FollowDirection::Following => (UserFollows::FolloweeId, UserFollows::FollowerId),
+        };
+
+        let (sql, values) = build_profile_view_query(viewer_id)
+            .inner_join(
+                UserFollows::Table,
+                Expr::col((UserFollows::Table, listed))
+                    .equals((Users::Table, Users::Id))
+                    .and(Expr::col((UserFollows::Table, of)).eq(user_id)),
+            )
+            .order_by((UserFollows::Table, UserFollows::CreatedAt), Order::Desc)
+            .order_by((Users::Table, Users::Id), Order::Desc)
+            .limit(limit.unwrap_or_default().value())
+            .offset(offset.unwrap_or_default().value())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(ProfileView::from_row).collect())
+    }
+
+    /// Also removes follows between both users, in either direction.
+    pub async fn block_user(
+        &self,
+        db: DbExecutor<'_>,
+        blocker_id: UserId,
+        blocked_id: UserId,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(UserBlocks::Table)
+            .columns([UserBlocks::BlockerId, UserBlocks::BlockedId])
+            .values_panic([blocker_id.into(), blocked_id.into()])
+            .on_conflict(
+                sea_query::OnConflict::columns([UserBlocks::BlockerId, UserBlocks::BlockedId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(UserFollows::Table)
+            .cond_where(
+                Cond::any()
+                    .add(
+                        Expr::col(UserFollows::FollowerId)
+                            .eq(blocker_id)
+                            .and(Expr::col(UserFollows::FolloweeId).eq(blocked_id)),
+                    )
+                    .add(
+                        Expr::col(UserFollows::FollowerId)
+                            .eq(blocked_id)
+                            .and(Expr::col(UserFollows::FolloweeId).eq(blocker_id)),
+                    ),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        // Pending requests go too, so that neither of them can follow the other by approving one.
+        let (sql, values) = Query::delete()
+            .from_table(FollowRequests::Table)
+            .cond_where(
+                Cond::any()
+                    .add(
+                        Expr::col(FollowRequests::RequesterId)
+                            .eq(blocker_id)
+                            .and(Expr::col(FollowRequests::TargetId).eq(blocked_id)),
+                    )
+                    .add(
+                        Expr::col(FollowRequests::RequesterId)
+                            .eq(blocked_id)
+                            .and(Expr::col(FollowRequests::TargetId).eq(blocker_id)),
+                    ),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    pub async fn unblock_user(
+        &self,
+        blocker_id: UserId,
+        blocked_id: UserId,
+    ) -> Result<(), AppError> {
+        let (sql, values) = Query::delete()
+            .from_table(UserBlocks::Table)
+            .and_where(Expr::col(UserBlocks::BlockerId).eq(blocker_id))
+            .and_where(Expr::col(UserBlocks::BlockedId).eq(blocked_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn is_blocking(
+        &self,
+        db: DbExecutor<'_>,
+        blocker_id: UserId,
+        blocked_id: UserId,
+    ) -> Result<bool, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let subquery = Query::select()
+            .expr(Expr::cust("1"))
+            .from(UserBlocks::Table)
+            .and_where(Expr::col(UserBlocks::BlockerId).eq(blocker_id))
+            .and_where(Expr::col(UserBlocks::BlockedId).eq(blocked_id))
+            .to_owned();
+
+        let (sql, values) = Query::select()
+            .expr_as(Expr::exists(subquery), Alias::new("is_blocking"))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(row.get("is_blocking"))
+    }
+
+    pub async fn mute_user(&self, muter_id: UserId, muted_id: UserId) -> Result<(), AppError> {
+        let (sql, values) = Query::insert()
+            .into_table(UserMutes::Table)
+            .columns([UserMutes::MuterId, UserMutes::MutedId])
+            .values_panic([muter_id.into(), muted_id.into()])
+            .on_conflict(
+                sea_query::OnConflict::columns([UserMutes::MuterId, UserMutes::MutedId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn unmute_user(&self, muter_id: UserId, muted_id: UserId) -> Result<(), AppError> {
+        let (sql, values) = Query::delete()
+            .from_table(UserMutes::Table)
+            .and_where(Expr::col(UserMutes::MuterId).eq(muter_id))
+            .and_where(Expr::col(UserMutes::MutedId).eq(muted_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
     }
 }
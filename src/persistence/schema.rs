@@ -10,10 +10,22 @@ pub enum Users {
     PasswordHash,
     Bio,
     Image,
+    Private,
+    UsernameChangedAt,
+    UsernameSkeleton,
     CreatedAt,
     UpdatedAt,
 }
 
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum UsernameHistory {
+    Table,
+    UserId,
+    Username,
+    ChangedAt,
+}
+
 #[allow(dead_code)]
 #[derive(Iden, Clone)]
 pub enum Articles {
@@ -26,6 +38,8 @@ pub enum Articles {
     AuthorId,
     CreatedAt,
     UpdatedAt,
+    ViewsCount,
+    CommentsLocked,
 }
 
 #[derive(Iden)]
@@ -33,11 +47,12 @@ pub enum Tags {
     Table,
     Id,
     Name,
+    Description,
     CreatedAt,
 }
 
 #[allow(dead_code)]
-#[derive(Iden)]
+#[derive(Iden, Clone)]
 pub enum ArticleTags {
     Table,
     ArticleId,
@@ -58,19 +73,115 @@ pub enum Comments {
 }
 
 #[allow(dead_code)]
-#[derive(Iden)]
+#[derive(Iden, Clone)]
 pub enum UserFollows {
     Table,
     FollowerId,
     FolloweeId,
+    CreatedAt,
+}
+
+#[derive(Iden, Clone)]
+pub enum UserBlocks {
+    Table,
+    BlockerId,
+    BlockedId,
+}
+
+#[derive(Iden, Clone)]
+pub enum UserMutes {
+    Table,
+    MuterId,
+    MutedId,
+}
+
+#[derive(Iden, Clone)]
+pub enum FollowRequests {
+    Table,
+    RequesterId,
+    TargetId,
+    CreatedAt,
 }
 
 #[allow(dead_code)]
 #[derive(Iden)]
+pub enum ArticleViews {
+    Table,
+    ArticleId,
+    Viewer,
+    WindowStart,
+    ViewedAt,
+}
+
+#[derive(Iden)]
+pub enum ArticleCoAuthors {
+    Table,
+    ArticleId,
+    UserId,
+    CreatedAt,
+    AcceptedAt,
+}
+
+#[derive(Iden)]
+pub enum Series {
+    Table,
+    Id,
+    Slug,
+    Title,
+    Description,
+    OwnerId,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+pub enum SeriesArticles {
+    Table,
+    SeriesId,
+    ArticleId,
+    Position,
+}
+
+#[derive(Iden)]
+pub enum Bookmarks {
+    Table,
+    UserId,
+    ArticleId,
+    Folder,
+    Note,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+pub enum TagFollows {
+    Table,
+    UserId,
+    TagId,
+}
+
+#[allow(dead_code)]
+#[derive(Iden, Clone)]
 pub enum ArticleFavorites {
     Table,
     UserId,
     ArticleId,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+pub enum ArticleReactions {
+    Table,
+    UserId,
+    ArticleId,
+    Kind,
+}
+
+#[derive(Iden)]
+pub enum CommentReactions {
+    Table,
+    UserId,
+    CommentId,
+    Kind,
 }
 
 #[allow(dead_code)]
@@ -107,3 +218,78 @@ pub enum CommentView {
     AuthorImage,
     Following,
 }
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum Notifications {
+    Table,
+    Id,
+    RecipientId,
+    ActorId,
+    Kind,
+    ArticleId,
+    CommentId,
+    ReadAt,
+    CreatedAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden, Clone)]
+pub enum NotificationPreferences {
+    Table,
+    UserId,
+    ArticleCommented,
+    ArticleFavorited,
+    UserFollowed,
+    UpdatedAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum Webhooks {
+    Table,
+    Id,
+    OwnerId,
+    Url,
+    Secret,
+    Events,
+    CreatedAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum WebhookDeliveries {
+    Table,
+    Id,
+    WebhookId,
+    Event,
+    Payload,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    LastResponseStatus,
+    LastError,
+    CreatedAt,
+    DeliveredAt,
+}
+
+#[allow(dead_code)]
+#[derive(Iden)]
+pub enum OutboxEvents {
+    Table,
+    Id,
+    EventType,
+    Payload,
+    Attempts,
+    LastError,
+    CreatedAt,
+    ProcessedAt,
+}
+
+#[derive(Iden)]
+pub enum TrendingArticles {
+    Table,
+    TimeWindow,
+    ArticleId,
+    Score,
+}
@@ -0,0 +1,320 @@
+use crate::app_error::AppError;
+use crate::database::{Database, DbExecutor};
+use crate::model::persistence::series::Series;
+use crate::model::persistence::series_navigation::SeriesNavigation;
+use crate::model::persistence::series_view::SeriesView;
+use crate::model::values::article_id::ArticleId;
+use crate::model::values::series_id::SeriesId;
+use crate::model::values::slug::Slug;
+use crate::model::values::user_id::UserId;
+use crate::persistence::article_repository::{following_subquery, readable_author_condition};
+use crate::persistence::params::insert_series_params::InsertSeriesParams;
+use crate::persistence::params::update_series_params::UpdateSeriesParams;
+use crate::persistence::schema::{Articles, Series as SeriesTable, SeriesArticles, Users};
+use anyhow::Result;
+use sea_query::{Alias, Expr, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder;
+
+/// Window of the articles of a series, in reading order.
+const READING_ORDER: &str =
+    "OVER (PARTITION BY series_articles.series_id ORDER BY series_articles.position)";
+
+#[derive(Clone)]
+pub struct SeriesRepository {
+    database: Database,
+}
+
+impl SeriesRepository {
+    pub fn new(database: Database) -> Self {
+        SeriesRepository { database }
+    }
+
+    pub async fn insert_series(
+        &self,
+        db: DbExecutor<'_>,
+        params: InsertSeriesParams,
+    ) -> Result<Series, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(SeriesTable::Table)
+            .columns([
+                SeriesTable::Slug,
+                SeriesTable::Title,
+                SeriesTable::Description,
+                SeriesTable::OwnerId,
+            ])
+            .values_panic([
+                params.slug.into(),
+                params.title.into(),
+                params.description.map(String::from).into(),
+                params.owner_id.into(),
+            ])
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(Series::from_row(row))
+    }
+
+    pub async fn get_series_by_slug(
+        &self,
+        db: DbExecutor<'_>,
+        slug: &Slug,
+    ) -> Result<Option<Series>, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::select()
+            .column(sea_query::Asterisk)
+            .from(SeriesTable::Table)
+            .and_where(Expr::col(SeriesTable::Slug).eq(slug))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        Ok(row.map(Series::from_row))
+    }
+
+    /// The series along with the profile of its owner, as seen by the user.
+    pub async fn get_series_view(
+        &self,
+        slug: &Slug,
+        user_id: Option<UserId>,
+    ) -> Result<Option<SeriesView>, AppError> {
+        let following = match user_id {
+            Some(user_id) => Expr::exists(following_subquery(user_id)),
+            None => Expr::cust("FALSE"),
+        };
+
+        let (sql, values) = Query::select()
+            .columns([
+                (SeriesTable::Table, SeriesTable::Id),
+                (SeriesTable::Table, SeriesTable::Slug),
+                (SeriesTable::Table, SeriesTable::Title),
+                (SeriesTable::Table, SeriesTable::Description),
+                (SeriesTable::Table, SeriesTable::CreatedAt),
+                (SeriesTable::Table, SeriesTable::UpdatedAt),
+            ])
+            .expr_as(
+                Expr::col((Users::Table, Users::Username)),
+                Alias::new("owner_username"),
+            )
+            .expr_as(
+                Expr::col((Users::Table, Users::Bio)),
+                Alias::new("owner_bio"),
+            )
+            .expr_as(
+                Expr::col((Users::Table, Users::Image)),
+                Alias::new("owner_image"),
+            )
+            .expr_as(following, Alias::new("following"))
+            .from(SeriesTable::Table)
+            .inner_join(
+                Users::Table,
+                Expr::col((SeriesTable::Table, SeriesTable::OwnerId))
+                    .equals((Users::Table, Users::Id)),
+            )
+            .and_where(Expr::col((SeriesTable::Table, SeriesTable::Slug)).eq(slug))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(SeriesView::from_row))
+    }
+
+    pub async fn update_series(
+        &self,
+        db: DbExecutor<'_>,
+        params: UpdateSeriesParams,
+    ) -> Result<Series, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let mut query = Query::update();
+        query
+            .table(SeriesTable::Table)
+            .value(SeriesTable::UpdatedAt, Expr::current_timestamp());
+
+        for (column, value) in params.as_list() {
+            query.value(column, value);
+        }
+
+        let (sql, values) = query
+            .and_where(Expr::col(SeriesTable::Id).eq(params.series_id))
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(Series::from_row(row))
+    }
+
+    pub async fn delete_series(
+        &self,
+        db: DbExecutor<'_>,
+        series_id: SeriesId,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(SeriesTable::Table)
+            .and_where(Expr::col(SeriesTable::Id).eq(series_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    /// The series the article belongs to, if any.
+    pub async fn find_series_of_article(
+        &self,
+        db: DbExecutor<'_>,
+        article_id: ArticleId,
+    ) -> Result<Option<Series>, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::select()
+            .column((SeriesTable::Table, sea_query::Asterisk))
+            .from(SeriesTable::Table)
+            .inner_join(
+                SeriesArticles::Table,
+                Expr::col((SeriesArticles::Table, SeriesArticles::SeriesId))
+                    .equals((SeriesTable::Table, SeriesTable::Id)),
+            )
+            .and_where(Expr::col((SeriesArticles::Table, SeriesArticles::ArticleId)).eq(article_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        Ok(row.map(Series::from_row))
+    }
+
+    /// Replaces the articles of the series, positioned in the order of `article_ids`.
+    pub async fn set_series_articles(
+        &self,
+        db: DbExecutor<'_>,
+        series_id: SeriesId,
+        article_ids: &[ArticleId],
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(SeriesArticles::Table)
+            .and_where(Expr::col(SeriesArticles::SeriesId).eq(series_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        if article_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut query = Query::insert();
+        query.into_table(SeriesArticles::Table).columns([
+            SeriesArticles::SeriesId,
+            SeriesArticles::ArticleId,
+            SeriesArticles::Position,
+        ]);
+
+        for (position, article_id) in article_ids.iter().enumerate() {
+            query.values_panic([
+                series_id.into(),
+                (*article_id).into(),
+                (position as i32).into(),
+            ]);
+        }
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    /// The series of the article with its previous and next articles, if it belongs to one.
+    /// Articles the viewer can't read are skipped, both in the links and the positions.
+    pub async fn get_navigation(
+        &self,
+        article_id: ArticleId,
+        viewer_id: Option<UserId>,
+    ) -> Result<Option<SeriesNavigation>, AppError> {
+        let reading = Alias::new("reading");
+
+        let reading_order = Query::select()
+            .column((SeriesArticles::Table, SeriesArticles::SeriesId))
+            .column((SeriesArticles::Table, SeriesArticles::ArticleId))
+            .expr_as(
+                Expr::cust(format!("ROW_NUMBER() {READING_ORDER}")),
+                Alias::new("position"),
+            )
+            .expr_as(
+                Expr::cust("COUNT(*) OVER (PARTITION BY series_articles.series_id)"),
+                Alias::new("articles_count"),
+            )
+            .expr_as(
+                Expr::cust(format!("LAG(articles.slug) {READING_ORDER}")),
+                Alias::new("previous_slug"),
+            )
+            .expr_as(
+                Expr::cust(format!("LAG(articles.title) {READING_ORDER}")),
+                Alias::new("previous_title"),
+            )
+            .expr_as(
+                Expr::cust(format!("LEAD(articles.slug) {READING_ORDER}")),
+                Alias::new("next_slug"),
+            )
+            .expr_as(
+                Expr::cust(format!("LEAD(articles.title) {READING_ORDER}")),
+                Alias::new("next_title"),
+            )
+            .from(SeriesArticles::Table)
+            .inner_join(
+                Articles::Table,
+                Expr::col((Articles::Table, Articles::Id))
+                    .equals((SeriesArticles::Table, SeriesArticles::ArticleId)),
+            )
+            .inner_join(
+                Users::Table,
+                Expr::col((Users::Table, Users::Id)).equals((Articles::Table, Articles::AuthorId)),
+            )
+            .and_where(readable_author_condition(viewer_id))
+            .to_owned();
+
+        let (sql, values) = Query::select()
+            .expr_as(
+                Expr::col((SeriesTable::Table, SeriesTable::Slug)),
+                Alias::new("series_slug"),
+            )
+            .expr_as(
+                Expr::col((SeriesTable::Table, SeriesTable::Title)),
+                Alias::new("series_title"),
+            )
+            .column((reading.clone(), Alias::new("position")))
+            .column((reading.clone(), Alias::new("articles_count")))
+            .column((reading.clone(), Alias::new("previous_slug")))
+            .column((reading.clone(), Alias::new("previous_title")))
+            .column((reading.clone(), Alias::new("next_slug")))
+            .column((reading.clone(), Alias::new("next_title")))
+            .from_subquery(reading_order, reading.clone())
+            .inner_join(
+                SeriesTable::Table,
+                Expr::col((SeriesTable::Table, SeriesTable::Id))
+                    .equals((reading.clone(), SeriesArticles::SeriesId)),
+            )
+            .and_where(Expr::col((reading, SeriesArticles::ArticleId)).eq(article_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(SeriesNavigation::from_row))
+    }
+}
@@ -0,0 +1,151 @@
+use crate::app_error::AppError;
+use crate::database::Database;
+use crate::model::persistence::article_stats_view::{ArticleStatsView, DailyArticleStatsView};
+use crate::model::values::user_id::UserId;
+use crate::persistence::schema::{ArticleFavorites, ArticleViews, Articles, Comments};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sea_query::{
+    Alias, Expr, Iden, Order, PostgresQueryBuilder, Query, SelectStatement, UnionType,
+};
+use sea_query_binder::SqlxBinder;
+
+#[derive(Clone)]
+pub struct StatsRepository {
+    database: Database,
+}
+
+/// Selects `at` and a 1 in the column of `kind`, and a 0 in the other ones.
+fn activity_query<T>(article_id: T, at: T, kind: &str) -> SelectStatement
+where
+    T: Iden + 'static,
+{
+    let mut query = Query::select();
+    query
+        .expr_as(Expr::col(article_id), Alias::new("article_id"))
+        .expr_as(Expr::col(at), Alias::new("at"));
+
+    for column in ["views", "favorites", "comments"] {
+        let value = if column == kind { "1" } else { "0" };
+        query.expr_as(Expr::cust(value), Alias::new(column));
+    }
+
+    query
+}
+
+/// One row per view, favorite and comment since `since`.
+fn article_activity_query(since: DateTime<Utc>) -> SelectStatement {
+    activity_query(ArticleViews::ArticleId, ArticleViews::ViewedAt, "views")
+        .from(ArticleViews::Table)
+        .and_where(Expr::col(ArticleViews::ViewedAt).gte(since))
+        .union(
+            UnionType::All,
+            activity_query(
+                ArticleFavorites::ArticleId,
+                ArticleFavorites::CreatedAt,
+                "favorites",
+            )
+            .from(ArticleFavorites::Table)
+            .and_where(Expr::col(ArticleFavorites::CreatedAt).gte(since))
+            .to_owned(),
+        )
+        .union(
+            UnionType::All,
+            activity_query(Comments::ArticleId, Comments::CreatedAt, "comments")
+                .from(Comments::Table)
+                .and_where(Expr::col(Comments::CreatedAt).gte(since))
+                .to_owned(),
+        )
+        .to_owned()
+}
+
+impl StatsRepository {
+    pub fn new(database: Database) -> Self {
+        StatsRepository { database }
+    }
+
+    /// All time counts of every article of the author, newest article first.
+    pub async fn get_article_stats(
+        &self,
+        author_id: UserId,
+    ) -> Result<Vec<ArticleStatsView>, AppError> {
+        let (sql, values) = Query::select()
+            .columns([
+                (Articles::Table, Articles::Id),
+                (Articles::Table, Articles::Slug),
+                (Articles::Table, Articles::Title),
+                (Articles::Table, Articles::ViewsCount),
+            ])
+            .expr_as(
+                Expr::cust(
+                    "(SELECT COUNT(*) FROM article_favorites WHERE article_favorites.article_id = articles.id)",
+                ),
+                Alias::new("favorites_count"),
+            )
+            .expr_as(
+                Expr::cust(
+                    "(SELECT COUNT(*) FROM comments WHERE comments.article_id = articles.id)",
+                ),
+                Alias::new("comments_count"),
+            )
+            .from(Articles::Table)
+            .and_where(Expr::col((Articles::Table, Articles::AuthorId)).eq(author_id))
+            .order_by((Articles::Table, Articles::CreatedAt), Order::Desc)
+            .order_by((Articles::Table, Articles::Id), Order::Desc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(ArticleStatsView::from_row).collect())
+    }
+
+    /// Daily counts of the author's articles since `since`, leaving out the days without any.
+    pub async fn get_daily_article_stats(
+        &self,
+        author_id: UserId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<DailyArticleStatsView>, AppError> {
+        let activity = Alias::new("activity");
+
+        let (sql, values) = Query::select()
+            .column((activity.clone(), Alias::new("article_id")))
+            .expr_as(
+                Expr::cust("(DATE_TRUNC('day', activity.at AT TIME ZONE 'UTC'))::date"),
+                Alias::new("day"),
+            )
+            .expr_as(
+                Expr::cust("SUM(activity.views)::bigint"),
+                Alias::new("views"),
+            )
+            .expr_as(
+                Expr::cust("SUM(activity.favorites)::bigint"),
+                Alias::new("favorites"),
+            )
+            .expr_as(
+                Expr::cust("SUM(activity.comments)::bigint"),
+                Alias::new("comments"),
+            )
+            .from_subquery(article_activity_query(since), activity.clone())
+            .inner_join(
+                Articles::Table,
+                Expr::col((Articles::Table, Articles::Id))
+                    .equals((activity.clone(), Alias::new("article_id"))),
+            )
+            .and_where(Expr::col((Articles::Table, Articles::AuthorId)).eq(author_id))
+            .group_by_col((activity, Alias::new("article_id")))
+            .group_by_col(Alias::new("day"))
+            .order_by(Alias::new("day"), Order::Asc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(DailyArticleStatsView::from_row)
+            .collect())
+    }
+}
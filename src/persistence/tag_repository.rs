@@ -1,11 +1,19 @@
 use crate::app_error::AppError;
-use crate::database::Database;
+use crate::database::{Database, DbExecutor};
 use crate::model::persistence::tag::Tag;
+use crate::model::persistence::tag_view::TagView;
+use crate::model::tag_sort::TagSort;
+use crate::model::values::tag_id::TagId;
 use crate::model::values::tag_name::TagName;
+use crate::model::values::user_id::UserId;
 use crate::persistence::params::insert_tag_params::InsertTagParams;
-use crate::persistence::schema::Tags;
+use crate::persistence::params::list_tags_params::ListTagsParams;
+use crate::persistence::params::update_tag_params::UpdateTagParams;
+use crate::persistence::schema::{ArticleTags, TagFollows, Tags};
 use anyhow::Result;
-use sea_query::{Expr, Order, PostgresQueryBuilder, Query};
+use sea_query::{
+    Alias, Expr, LockBehavior, LockType, Order, PostgresQueryBuilder, Query, SelectStatement,
+};
 use sea_query_binder::SqlxBinder;
 use sqlx::Row;
 
@@ -14,60 +22,366 @@ pub struct TagRepository {
     database: Database,
 }
 
+fn tag_view_query(user_id: Option<UserId>) -> SelectStatement {
+    let mut query = Query::select();
+    query
+        .column((Tags::Table, Tags::Name))
+        .column((Tags::Table, Tags::Description))
+        .expr_as(
+            Expr::col((ArticleTags::Table, ArticleTags::ArticleId)).count(),
+            Alias::new("articles_count"),
+        );
+
+    match user_id {
+        Some(user_id) => {
+            let following_subquery = Query::select()
+                .expr(Expr::cust("1"))
+                .from(TagFollows::Table)
+                .and_where(
+                    Expr::col((TagFollows::Table, TagFollows::UserId))
+                        .eq(user_id)
+                        .and(
+                            Expr::col((TagFollows::Table, TagFollows::TagId))
+                                .equals((Tags::Table, Tags::Id)),
+                        ),
+                )
+                .to_owned();
+
+            query.expr_as(Expr::exists(following_subquery), Alias::new("following"));
+        }
+        None => {
+            query.expr_as(Expr::cust("FALSE"), Alias::new("following"));
+        }
+    }
+
+    query
+        .from(Tags::Table)
+        .left_join(
+            ArticleTags::Table,
+            Expr::col((ArticleTags::Table, ArticleTags::TagId))
+                .eq(Expr::col((Tags::Table, Tags::Id))),
+        )
+        .group_by_col((Tags::Table, Tags::Id))
+        .to_owned()
+}
+
 impl TagRepository {
     pub fn new(database: Database) -> Self {
         TagRepository { database }
     }
 
-    pub async fn insert_tag(&self, params: InsertTagParams) -> Result<Tag, AppError> {
+    /// Returns `None` when a tag with the same name already exists.
+    pub async fn insert_tag(
+        &self,
+        db: DbExecutor<'_>,
+        params: InsertTagParams,
+    ) -> Result<Option<Tag>, AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = Query::insert()
             .into_table(Tags::Table)
             .columns([Tags::Name])
             .values_panic([params.name.into()])
+            .on_conflict(
+                sea_query::OnConflict::column(Tags::Name)
+                    .do_nothing()
+                    .to_owned(),
+            )
             .returning_all()
             .build_sqlx(PostgresQueryBuilder);
 
         let row = sqlx::query_with(&sql, values)
-            .fetch_one(self.database.pool())
+            .fetch_optional(&mut *conn)
             .await?;
 
-        Ok(Tag::from_row(row))
+        Ok(row.map(Tag::from_row))
     }
 
-    pub async fn get_tag_by_name(&self, name: &TagName) -> Result<Option<Tag>, AppError> {
+    /// Also keeps the orphaned tags cleanup away from the tag until the transaction ends,
+    /// so that it can safely be attached to an article.
+    pub async fn get_tag_by_name(
+        &self,
+        db: DbExecutor<'_>,
+        name: &TagName,
+    ) -> Result<Option<Tag>, AppError> {
+        let mut conn = db.acquire().await?;
+
         let (sql, values) = Query::select()
-            .columns([Tags::Id, Tags::Name, Tags::CreatedAt])
+            .columns([Tags::Id, Tags::Name, Tags::Description, Tags::CreatedAt])
             .from(Tags::Table)
             .and_where(Expr::col(Tags::Name).eq(name))
+            .lock(LockType::KeyShare)
             .build_sqlx(PostgresQueryBuilder);
 
         let row = sqlx::query_with(&sql, values)
-            .fetch_optional(self.database.pool())
+            .fetch_optional(&mut *conn)
             .await?;
 
         Ok(row.map(Tag::from_row))
     }
 
-    pub async fn get_all_tags(&self) -> Result<Vec<TagName>, AppError> {
-        let (sql, values) = Query::select()
-            .column(Tags::Name)
-            .from(Tags::Table)
-            .order_by(Tags::Name, Order::Desc)
-            .build_sqlx(PostgresQueryBuilder);
+    /// Lists the tags used by at least one article.
+    pub async fn list_tags(&self, params: ListTagsParams) -> Result<Vec<TagView>, AppError> {
+        let mut query = tag_view_query(params.user_id);
+        query.and_having(
+            Expr::expr(Expr::col((ArticleTags::Table, ArticleTags::ArticleId)).count()).gt(0),
+        );
+
+        if params.sort == TagSort::Popular {
+            query.order_by(Alias::new("articles_count"), Order::Desc);
+        }
+
+        query.order_by((Tags::Table, Tags::Name), Order::Asc);
+
+        if let Some(limit) = params.limit {
+            query.limit(limit.value());
+        }
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
 
         let rows = sqlx::query_with(&sql, values)
             .fetch_all(self.database.pool())
             .await?;
 
-        Ok(rows.into_iter().map(|row| row.get("name")).collect())
+        Ok(rows.into_iter().map(TagView::from_row).collect())
     }
 
-    pub async fn get_or_create_tag(&self, name: &TagName) -> Result<Tag, AppError> {
-        if let Some(tag) = self.get_tag_by_name(name).await? {
-            Ok(tag)
-        } else {
-            self.insert_tag(InsertTagParams { name: name.clone() })
-                .await
+    pub async fn get_tag_view(
+        &self,
+        tag_id: TagId,
+        user_id: Option<UserId>,
+    ) -> Result<TagView, AppError> {
+        let (sql, values) = tag_view_query(user_id)
+            .and_where(Expr::col((Tags::Table, Tags::Id)).eq(tag_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        Ok(TagView::from_row(row))
+    }
+
+    pub async fn update_tag(
+        &self,
+        db: DbExecutor<'_>,
+        params: UpdateTagParams,
+    ) -> Result<Tag, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let mut query = Query::update();
+        query.table(Tags::Table);
+
+        for (column, value) in params.as_list() {
+            query.value(column, value);
         }
+
+        let (sql, values) = query
+            .and_where(Expr::col(Tags::Id).eq(params.tag_id))
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(Tag::from_row(row))
+    }
+
+    /// Moves every article and follower of the `source` tag over to the `target` tag, then
+    /// deletes `source`.
+    pub async fn merge_tag(
+        &self,
+        db: DbExecutor<'_>,
+        source: TagId,
+        target: TagId,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(ArticleTags::Table)
+            .columns([ArticleTags::ArticleId, ArticleTags::TagId])
+            .select_from(
+                Query::select()
+                    .column(ArticleTags::ArticleId)
+                    .expr(Expr::val(target))
+                    .from(ArticleTags::Table)
+                    .and_where(Expr::col(ArticleTags::TagId).eq(source))
+                    .to_owned(),
+            )
+            .expect("Selected columns match the inserted ones")
+            .on_conflict(
+                sea_query::OnConflict::columns([ArticleTags::ArticleId, ArticleTags::TagId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(TagFollows::Table)
+            .columns([TagFollows::UserId, TagFollows::TagId])
+            .select_from(
+                Query::select()
+                    .column(TagFollows::UserId)
+                    .expr(Expr::val(target))
+                    .from(TagFollows::Table)
+                    .and_where(Expr::col(TagFollows::TagId).eq(source))
+                    .to_owned(),
+            )
+            .expect("Selected columns match the inserted ones")
+            .on_conflict(
+                sea_query::OnConflict::columns([TagFollows::UserId, TagFollows::TagId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        // The article_tags and tag_follows rows still pointing at the source tag go away with the cascade.
+        let (sql, values) = Query::delete()
+            .from_table(Tags::Table)
+            .and_where(Expr::col(Tags::Id).eq(source))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    /// Deletes the tags no article uses and no user follows anymore. Tags locked by a running transaction are
+    /// skipped, as they are about to be attached to an article.
+    pub async fn delete_orphaned_tags(&self) -> Result<u64, AppError> {
+        let orphaned_tags = Query::select()
+            .column(Tags::Id)
+            .from(Tags::Table)
+            .and_where(
+                Expr::exists(
+                    Query::select()
+                        .column(ArticleTags::TagId)
+                        .from(ArticleTags::Table)
+                        .and_where(
+                            Expr::col((ArticleTags::Table, ArticleTags::TagId))
+                                .equals((Tags::Table, Tags::Id)),
+                        )
+                        .to_owned(),
+                )
+                .not(),
+            )
+            .and_where(
+                Expr::exists(
+                    Query::select()
+                        .column(TagFollows::TagId)
+                        .from(TagFollows::Table)
+                        .and_where(
+                            Expr::col((TagFollows::Table, TagFollows::TagId))
+                                .equals((Tags::Table, Tags::Id)),
+                        )
+                        .to_owned(),
+                )
+                .not(),
+            )
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .to_owned();
+
+        let (sql, values) = Query::delete()
+            .from_table(Tags::Table)
+            .and_where(Expr::col(Tags::Id).in_subquery(orphaned_tags))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Keeps the tag locked against the orphaned tags cleanup until the transaction ends, see
+    /// `get_tag_by_name`.
+    ///
+    /// The tag may be created by another transaction, or deleted by the cleanup, between looking
+    /// it up and inserting it, so both are retried until one of them finds it.
+    pub async fn get_or_create_tag(
+        &self,
+        db: DbExecutor<'_>,
+        name: &TagName,
+    ) -> Result<Tag, AppError> {
+        let mut conn = db.acquire().await?;
+
+        loop {
+            if let Some(tag) = self.get_tag_by_name((&mut *conn).into(), name).await? {
+                return Ok(tag);
+            }
+
+            let params = InsertTagParams { name: name.clone() };
+
+            if let Some(tag) = self.insert_tag((&mut *conn).into(), params).await? {
+                return Ok(tag);
+            }
+        }
+    }
+
+    pub async fn follow_tag(
+        &self,
+        db: DbExecutor<'_>,
+        user_id: UserId,
+        tag_id: TagId,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(TagFollows::Table)
+            .columns([TagFollows::UserId, TagFollows::TagId])
+            .values_panic([user_id.into(), tag_id.into()])
+            .on_conflict(
+                sea_query::OnConflict::columns([TagFollows::UserId, TagFollows::TagId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    pub async fn unfollow_tag(
+        &self,
+        db: DbExecutor<'_>,
+        user_id: UserId,
+        tag_id: TagId,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(TagFollows::Table)
+            .and_where(Expr::col(TagFollows::UserId).eq(user_id))
+            .and_where(Expr::col(TagFollows::TagId).eq(tag_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    pub async fn list_followed_tags(&self, user_id: UserId) -> Result<Vec<TagName>, AppError> {
+        let (sql, values) = Query::select()
+            .column((Tags::Table, Tags::Name))
+            .from(TagFollows::Table)
+            .inner_join(
+                Tags::Table,
+                Expr::col((TagFollows::Table, TagFollows::TagId))
+                    .eq(Expr::col((Tags::Table, Tags::Id))),
+            )
+            .and_where(Expr::col((TagFollows::Table, TagFollows::UserId)).eq(user_id))
+            .order_by((Tags::Table, Tags::Name), Order::Asc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(|row| row.get("name")).collect())
     }
 }
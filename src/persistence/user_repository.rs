@@ -1,13 +1,18 @@
 use crate::app_error::AppError;
-use crate::database::Database;
+use crate::database::{Database, DbExecutor};
 use crate::model::indexed_user_field::IndexedUserField;
 use crate::model::persistence::user::User;
+use crate::model::values::user_id::UserId;
+use crate::model::values::username::Username;
 use crate::persistence::params::insert_user_params::InsertUserParams;
 use crate::persistence::params::update_user_params::UpdateUserParams;
-use crate::persistence::schema::Users;
+use crate::persistence::schema::{UsernameHistory, Users};
 use anyhow::Result;
-use sea_query::{Expr, PostgresQueryBuilder, Query};
+use sea_query::{Expr, Func, OnConflict, Order, PostgresQueryBuilder, Query, SimpleExpr};
 use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+
+const SKELETON_BATCH_SIZE: u64 = 500;
 
 #[derive(Clone)]
 pub struct UserRepository {
@@ -20,12 +25,20 @@ impl UserRepository {
     }
 
     pub(crate) async fn insert_user(&self, params: InsertUserParams) -> Result<User, AppError> {
+        let username_skeleton = params.username.skeleton();
+
         let (sql, values) = Query::insert()
             .into_table(Users::Table)
-            .columns([Users::Email, Users::Username, Users::PasswordHash])
+            .columns([
+                Users::Email,
+                Users::Username,
+                Users::UsernameSkeleton,
+                Users::PasswordHash,
+            ])
             .values_panic([
                 params.email.into(),
                 params.username.into(),
+                username_skeleton.into(),
                 params.password_hash.into(),
             ])
             .returning_all()
@@ -38,7 +51,13 @@ impl UserRepository {
         Ok(User::from_row(row))
     }
 
-    pub(crate) async fn update_user(&self, params: UpdateUserParams) -> Result<User, AppError> {
+    pub(crate) async fn update_user(
+        &self,
+        db: DbExecutor<'_>,
+        params: UpdateUserParams,
+    ) -> Result<User, AppError> {
+        let mut conn = db.acquire().await?;
+
         let updates = params.as_list();
 
         if updates.is_empty() {
@@ -57,11 +76,202 @@ impl UserRepository {
             .returning_all()
             .build_sqlx(PostgresQueryBuilder);
 
+        let row = sqlx::query_with(&sql, values).fetch_one(&mut *conn).await?;
+
+        Ok(User::from_row(row))
+    }
+
+    /// Keeps the previous username of a user, unless they're taking back one of their own.
+    ///
+    /// Accounts renamed when usernames became unique regardless of case may have the username in
+    /// their history, it goes to the user giving it up now, whose links it used to be.
+    pub(crate) async fn record_username_change(
+        &self,
+        db: DbExecutor<'_>,
+        user_id: UserId,
+        previous: &Username,
+        current: &Username,
+    ) -> Result<(), AppError> {
+        let mut conn = db.acquire().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(UsernameHistory::Table)
+            .and_where(Expr::col(UsernameHistory::UserId).eq(user_id))
+            .and_where(Expr::expr(lower_col(UsernameHistory::Username)).eq(lower_val(current)))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(UsernameHistory::Table)
+            .columns([UsernameHistory::UserId, UsernameHistory::Username])
+            .values_panic([user_id.into(), previous.into()])
+            .on_conflict(
+                OnConflict::new()
+                    .expr(lower_col(UsernameHistory::Username))
+                    .update_columns([
+                        UsernameHistory::UserId,
+                        UsernameHistory::Username,
+                        UsernameHistory::ChangedAt,
+                    ])
+                    .to_owned(),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(())
+    }
+
+    /// Whether a user other than `except_user_id` has, or used to have, the username, ignoring
+    /// case.
+    pub(crate) async fn is_username_taken(
+        &self,
+        username: &Username,
+        except_user_id: Option<UserId>,
+    ) -> Result<bool, AppError> {
+        let mut current = Query::select()
+            .expr(Expr::cust("1"))
+            .from(Users::Table)
+            .and_where(Expr::expr(lower_col(Users::Username)).eq(lower_val(username)))
+            .to_owned();
+
+        let mut previous = Query::select()
+            .expr(Expr::cust("1"))
+            .from(UsernameHistory::Table)
+            .and_where(Expr::expr(lower_col(UsernameHistory::Username)).eq(lower_val(username)))
+            .to_owned();
+
+        if let Some(user_id) = except_user_id {
+            current.and_where(Expr::col(Users::Id).ne(user_id));
+            previous.and_where(Expr::col(UsernameHistory::UserId).ne(user_id));
+        }
+
+        let (sql, values) = Query::select()
+            .expr_as(
+                Expr::exists(current).or(Expr::exists(previous)),
+                sea_query::Alias::new("is_taken"),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
         let row = sqlx::query_with(&sql, values)
             .fetch_one(self.database.pool())
             .await?;
 
-        Ok(User::from_row(row))
+        Ok(row.get("is_taken"))
+    }
+
+    /// Whether a user other than `except_user_id` has a username looking like this one, see
+    /// `Username::skeleton`.
+    pub(crate) async fn is_username_confusable(
+        &self,
+        username: &Username,
+        except_user_id: Option<UserId>,
+    ) -> Result<bool, AppError> {
+        let mut lookalike = Query::select()
+            .expr(Expr::cust("1"))
+            .from(Users::Table)
+            .and_where(Expr::col(Users::UsernameSkeleton).eq(username.skeleton()))
+            .to_owned();
+
+        if let Some(user_id) = except_user_id {
+            lookalike.and_where(Expr::col(Users::Id).ne(user_id));
+        }
+
+        let (sql, values) = Query::select()
+            .expr_as(
+                Expr::exists(lookalike),
+                sea_query::Alias::new("is_confusable"),
+            )
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?;
+
+        Ok(row.get("is_confusable"))
+    }
+
+    /// Computes the username skeletons missing for users created before they were kept,
+    /// returning how many were filled in.
+    pub(crate) async fn fill_username_skeletons(&self) -> Result<u64, AppError> {
+        let mut filled = 0;
+
+        loop {
+            let (sql, values) = Query::select()
+                .column(Users::Id)
+                .column(Users::Username)
+                .from(Users::Table)
+                .and_where(Expr::col(Users::UsernameSkeleton).is_null())
+                .limit(SKELETON_BATCH_SIZE)
+                .build_sqlx(PostgresQueryBuilder);
+
+            let rows = sqlx::query_with(&sql, values)
+                .fetch_all(self.database.pool())
+                .await?;
+
+            if rows.is_empty() {
+                return Ok(filled);
+            }
+
+            let (ids, skeletons): (Vec<_>, Vec<_>) = rows
+                .iter()
+                .map(|row| {
+                    let user_id: UserId = row.get("id");
+                    let username: Username = row.get("username");
+                    (user_id.value(), username.skeleton())
+                })
+                .unzip();
+
+            // Updates the whole batch in one statement, which sea-query can't build from arrays.
+            let result = sqlx::query(
+                r#"
+                UPDATE users
+                SET username_skeleton = batch.skeleton
+                FROM UNNEST($1::uuid[], $2::text[]) AS batch(id, skeleton)
+                WHERE users.id = batch.id
+                "#,
+            )
+            .bind(ids)
+            .bind(skeletons)
+            .execute(self.database.pool())
+            .await?;
+
+            filled += result.rows_affected();
+        }
+    }
+
+    /// Finds the current username of the user having, or having had, the username, ignoring
+    /// case.
+    pub(crate) async fn find_current_username(
+        &self,
+        username: &Username,
+    ) -> Result<Option<Username>, AppError> {
+        let previous_owner = Query::select()
+            .column(UsernameHistory::UserId)
+            .from(UsernameHistory::Table)
+            .and_where(Expr::expr(lower_col(UsernameHistory::Username)).eq(lower_val(username)))
+            .to_owned();
+
+        let is_current = Expr::expr(lower_col(Users::Username)).eq(lower_val(username));
+
+        let (sql, values) = Query::select()
+            .column(Users::Username)
+            .from(Users::Table)
+            .cond_where(
+                is_current
+                    .clone()
+                    .or(Expr::col(Users::Id).in_subquery(previous_owner)),
+            )
+            .order_by_expr(is_current, Order::Desc)
+            .limit(1)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(|row| row.get("username")))
     }
 
     pub(crate) async fn get_user_by<T>(
@@ -72,7 +282,28 @@ impl UserRepository {
     where
         sea_query::Value: From<T>,
     {
-        let field_name = field.to_field_name();
+        self.find_user_by((&self.database).into(), field, value)
+            .await
+    }
+
+    /// Same as `get_user_by`, but on the connection of the caller's transaction.
+    pub(crate) async fn find_user_by<T>(
+        &self,
+        db: DbExecutor<'_>,
+        field: IndexedUserField,
+        value: T,
+    ) -> Result<Option<User>, AppError>
+    where
+        sea_query::Value: From<T>,
+    {
+        let mut conn = db.acquire().await?;
+
+        // Usernames are unique regardless of case, so any case finds the user.
+        let condition = match field {
+            IndexedUserField::Username => Expr::expr(Func::lower(Expr::col(field.to_field_name())))
+                .eq(Func::lower(Expr::val(value))),
+            _ => Expr::col(field.to_field_name()).eq(value),
+        };
 
         let (sql, values) = Query::select()
             .column(Users::Id)
@@ -81,14 +312,24 @@ impl UserRepository {
             .column(Users::PasswordHash)
             .column(Users::Bio)
             .column(Users::Image)
+            .column(Users::Private)
+            .column(Users::UsernameChangedAt)
             .from(Users::Table)
-            .and_where(Expr::col(field_name).eq(value))
+            .and_where(condition)
             .build_sqlx(PostgresQueryBuilder);
 
         let row = sqlx::query_with(&sql, values)
-            .fetch_optional(self.database.pool())
+            .fetch_optional(&mut *conn)
             .await?;
 
         Ok(row.map(User::from_row))
     }
 }
+
+fn lower_col<T: sea_query::Iden + 'static>(column: T) -> SimpleExpr {
+    Func::lower(Expr::col(column)).into()
+}
+
+fn lower_val(username: &Username) -> SimpleExpr {
+    Func::lower(Expr::val(username.value())).into()
+}
@@ -0,0 +1,317 @@
+use crate::app_error::AppError;
+use crate::database::{Database, DbExecutor};
+use crate::model::limit::Limit;
+use crate::model::offset::Offset;
+use crate::model::persistence::webhook::Webhook;
+use crate::model::persistence::webhook_delivery::{DueWebhookDelivery, WebhookDelivery};
+use crate::model::values::user_id::UserId;
+use crate::model::values::webhook_delivery_id::WebhookDeliveryId;
+use crate::model::values::webhook_delivery_status::WebhookDeliveryStatus;
+use crate::model::values::webhook_event::WebhookEvent;
+use crate::model::values::webhook_id::WebhookId;
+use crate::persistence::params::insert_webhook_params::InsertWebhookParams;
+use crate::persistence::schema::{UserFollows, WebhookDeliveries, Webhooks};
+use anyhow::Result;
+use sea_query::{
+    ArrayType, Expr, LockBehavior, LockType, Order, PostgresQueryBuilder, Query, Value,
+};
+use sea_query_binder::SqlxBinder;
+use sqlx::Row;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct WebhookRepository {
+    database: Database,
+}
+
+fn retry_at(delay: Duration) -> sea_query::SimpleExpr {
+    Expr::cust_with_values("NOW() + make_interval(secs => $1)", [delay.as_secs_f64()])
+}
+
+impl WebhookRepository {
+    pub fn new(database: Database) -> Self {
+        WebhookRepository { database }
+    }
+
+    pub async fn insert_webhook(&self, params: InsertWebhookParams) -> Result<Webhook, AppError> {
+        let events = params
+            .events
+            .into_iter()
+            .map(Value::from)
+            .collect::<Vec<_>>();
+
+        let (sql, values) = Query::insert()
+            .into_table(Webhooks::Table)
+            .columns([
+                Webhooks::OwnerId,
+                Webhooks::Url,
+                Webhooks::Secret,
+                Webhooks::Events,
+            ])
+            .values_panic([
+                params.owner_id.into(),
+                params.url.into(),
+                params.secret.into(),
+                Value::Array(ArrayType::String, Some(Box::new(events))).into(),
+            ])
+            .returning_all()
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?;
+
+        Ok(Webhook::from_row(row))
+    }
+
+    pub async fn list_webhooks(&self, owner_id: UserId) -> Result<Vec<Webhook>, AppError> {
+        let (sql, values) = Query::select()
+            .column(sea_query::Asterisk)
+            .from(Webhooks::Table)
+            .and_where(Expr::col(Webhooks::OwnerId).eq(owner_id))
+            .order_by(Webhooks::CreatedAt, Order::Asc)
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(Webhook::from_row).collect())
+    }
+
+    pub async fn get_webhook(
+        &self,
+        owner_id: UserId,
+        webhook_id: WebhookId,
+    ) -> Result<Option<Webhook>, AppError> {
+        let (sql, values) = Query::select()
+            .column(sea_query::Asterisk)
+            .from(Webhooks::Table)
+            .and_where(Expr::col(Webhooks::Id).eq(webhook_id))
+            .and_where(Expr::col(Webhooks::OwnerId).eq(owner_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let row = sqlx::query_with(&sql, values)
+            .fetch_optional(self.database.pool())
+            .await?;
+
+        Ok(row.map(Webhook::from_row))
+    }
+
+    pub async fn delete_webhook(
+        &self,
+        owner_id: UserId,
+        webhook_id: WebhookId,
+    ) -> Result<bool, AppError> {
+        let (sql, values) = Query::delete()
+            .from_table(Webhooks::Table)
+            .and_where(Expr::col(Webhooks::Id).eq(webhook_id))
+            .and_where(Expr::col(Webhooks::OwnerId).eq(owner_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Queues a delivery of the payload for every webhook subscribed to the event.
+    /// Queues the event for the webhooks subscribed to it whose owner is one of `user_ids` or
+    /// follows `followed_id`, leaving out everyone else.
+    pub async fn enqueue_deliveries(
+        &self,
+        db: DbExecutor<'_>,
+        event: WebhookEvent,
+        payload: serde_json::Value,
+        user_ids: &[UserId],
+        followed_id: Option<UserId>,
+    ) -> Result<u64, AppError> {
+        let mut conn = db.acquire().await?;
+
+        let mut audience = Expr::col((Webhooks::Table, Webhooks::OwnerId)).is_in(user_ids.to_vec());
+
+        if let Some(followed_id) = followed_id {
+            let follower = Query::select()
+                .expr(Expr::cust("1"))
+                .from(UserFollows::Table)
+                .and_where(
+                    Expr::col((UserFollows::Table, UserFollows::FollowerId))
+                        .equals((Webhooks::Table, Webhooks::OwnerId)),
+                )
+                .and_where(Expr::col((UserFollows::Table, UserFollows::FolloweeId)).eq(followed_id))
+                .to_owned();
+
+            audience = audience.or(Expr::exists(follower));
+        }
+
+        let subscribed_webhooks = Query::select()
+            .column(Webhooks::Id)
+            .expr(Expr::val(event))
+            .expr(Expr::val(payload))
+            .from(Webhooks::Table)
+            .and_where(Expr::cust_with_values("$1 = ANY(events)", [event]))
+            .and_where(audience)
+            .to_owned();
+
+        let (sql, values) = Query::insert()
+            .into_table(WebhookDeliveries::Table)
+            .columns([
+                WebhookDeliveries::WebhookId,
+                WebhookDeliveries::Event,
+                WebhookDeliveries::Payload,
+            ])
+            .select_from(subscribed_webhooks)
+            .expect("Selected columns match the inserted ones")
+            .build_sqlx(PostgresQueryBuilder);
+
+        let result = sqlx::query_with(&sql, values).execute(&mut *conn).await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn list_deliveries(
+        &self,
+        webhook_id: WebhookId,
+        limit: Option<Limit>,
+        offset: Option<Offset>,
+    ) -> Result<Vec<WebhookDelivery>, AppError> {
+        let (sql, values) = Query::select()
+            .column(sea_query::Asterisk)
+            .from(WebhookDeliveries::Table)
+            .and_where(Expr::col(WebhookDeliveries::WebhookId).eq(webhook_id))
+            .order_by(WebhookDeliveries::CreatedAt, Order::Desc)
+            .limit(limit.unwrap_or_default().value())
+            .offset(offset.unwrap_or_default().value())
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(WebhookDelivery::from_row).collect())
+    }
+
+    pub async fn count_deliveries(&self, webhook_id: WebhookId) -> Result<u64, AppError> {
+        let (sql, values) = Query::select()
+            .expr_as(Expr::cust("COUNT(*)"), "count")
+            .from(WebhookDeliveries::Table)
+            .and_where(Expr::col(WebhookDeliveries::WebhookId).eq(webhook_id))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let count: i64 = sqlx::query_with(&sql, values)
+            .fetch_one(self.database.pool())
+            .await?
+            .get("count");
+
+        Ok(count as u64)
+    }
+
+    /// Claims up to `batch_size` due deliveries by pushing their next attempt `lease` into the
+    /// future, so that other instances skip them while they're being sent.
+    pub async fn claim_due_deliveries(
+        &self,
+        batch_size: u64,
+        lease: Duration,
+    ) -> Result<Vec<DueWebhookDelivery>, AppError> {
+        let due_deliveries = Query::select()
+            .column(WebhookDeliveries::Id)
+            .from(WebhookDeliveries::Table)
+            .and_where(Expr::col(WebhookDeliveries::Status).eq(WebhookDeliveryStatus::Pending))
+            .and_where(Expr::col(WebhookDeliveries::NextAttemptAt).lte(Expr::current_timestamp()))
+            .order_by(WebhookDeliveries::NextAttemptAt, Order::Asc)
+            .limit(batch_size)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .to_owned();
+
+        let (sql, values) = Query::update()
+            .table(WebhookDeliveries::Table)
+            .value(WebhookDeliveries::NextAttemptAt, retry_at(lease))
+            .from(Webhooks::Table)
+            .and_where(
+                Expr::col((WebhookDeliveries::Table, WebhookDeliveries::WebhookId))
+                    .equals((Webhooks::Table, Webhooks::Id)),
+            )
+            .and_where(
+                Expr::col((WebhookDeliveries::Table, WebhookDeliveries::Id))
+                    .in_subquery(due_deliveries),
+            )
+            .returning(Query::returning().exprs([
+                Expr::col((WebhookDeliveries::Table, WebhookDeliveries::Id)),
+                Expr::col((WebhookDeliveries::Table, WebhookDeliveries::Event)),
+                Expr::col((WebhookDeliveries::Table, WebhookDeliveries::Payload)),
+                Expr::col((WebhookDeliveries::Table, WebhookDeliveries::Attempts)),
+                Expr::col((Webhooks::Table, Webhooks::Url)),
+                Expr::col((Webhooks::Table, Webhooks::Secret)),
+            ]))
+            .build_sqlx(PostgresQueryBuilder);
+
+        let rows = sqlx::query_with(&sql, values)
+            .fetch_all(self.database.pool())
+            .await?;
+
+        Ok(rows.into_iter().map(DueWebhookDelivery::from_row).collect())
+    }
+
+    /// Deliveries already settled by another worker are left as they are.
+    pub async fn mark_delivery_succeeded(
+        &self,
+        delivery_id: WebhookDeliveryId,
+        response_status: i32,
+    ) -> Result<(), AppError> {
+        let (sql, values) = Query::update()
+            .table(WebhookDeliveries::Table)
+            .value(WebhookDeliveries::Status, WebhookDeliveryStatus::Succeeded)
+            .value(
+                WebhookDeliveries::Attempts,
+                Expr::col(WebhookDeliveries::Attempts).add(1),
+            )
+            .value(WebhookDeliveries::LastResponseStatus, response_status)
+            .value(WebhookDeliveries::LastError, Option::<String>::None)
+            .value(WebhookDeliveries::DeliveredAt, Expr::current_timestamp())
+            .and_where(Expr::col(WebhookDeliveries::Id).eq(delivery_id))
+            .and_where(Expr::col(WebhookDeliveries::Status).eq(WebhookDeliveryStatus::Pending))
+            .build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Records a failed attempt, scheduling the next one after `retry_in` or giving up on the
+    /// delivery when there is none. Deliveries already settled are left as they are.
+    pub async fn mark_delivery_failed(
+        &self,
+        delivery_id: WebhookDeliveryId,
+        response_status: Option<i32>,
+        error: String,
+        retry_in: Option<Duration>,
+    ) -> Result<(), AppError> {
+        let mut query = Query::update();
+        query
+            .table(WebhookDeliveries::Table)
+            .value(
+                WebhookDeliveries::Attempts,
+                Expr::col(WebhookDeliveries::Attempts).add(1),
+            )
+            .value(WebhookDeliveries::LastResponseStatus, response_status)
+            .value(WebhookDeliveries::LastError, error)
+            .and_where(Expr::col(WebhookDeliveries::Id).eq(delivery_id))
+            .and_where(Expr::col(WebhookDeliveries::Status).eq(WebhookDeliveryStatus::Pending));
+
+        match retry_in {
+            Some(delay) => query.value(WebhookDeliveries::NextAttemptAt, retry_at(delay)),
+            None => query.value(WebhookDeliveries::Status, WebhookDeliveryStatus::Failed),
+        };
+
+        let (sql, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        sqlx::query_with(&sql, values)
+            .execute(self.database.pool())
+            .await?;
+
+        Ok(())
+    }
+}
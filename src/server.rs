@@ -1,6 +1,7 @@
 use crate::app_config::HttpConfig;
 use crate::http::{AppState, router};
 use std::io::Error;
+use std::net::SocketAddr;
 use tracing::info;
 
 pub async fn init_server(config: &HttpConfig, state: AppState) -> Result<(), Error> {
@@ -10,7 +11,11 @@ pub async fn init_server(config: &HttpConfig, state: AppState) -> Result<(), Err
 
     info!("Starting server on {}", config.url());
 
-    axum::serve(listener, routes.into_make_service()).await?;
+    axum::serve(
+        listener,
+        routes.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
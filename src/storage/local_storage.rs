@@ -0,0 +1,65 @@
+use crate::app_error::AppError;
+use crate::storage::{Storage, StoredObject, is_valid_key};
+use anyhow::Context;
+use async_trait::async_trait;
+use image::ImageFormat;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/// Keeps files in a directory of the local filesystem, created on the first upload.
+///
+/// Content types aren't stored, they're derived from the key's extension when reading.
+pub struct LocalStorage {
+    dir: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(dir: PathBuf) -> Self {
+        LocalStorage { dir }
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    async fn put(&self, key: &str, _content_type: &str, bytes: Vec<u8>) -> Result<(), AppError> {
+        if !is_valid_key(key) {
+            return Err(AppError::BadData(format!("Invalid storage key: {key}")));
+        }
+
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .with_context(|| format!("Couldn't create upload directory {:?}", self.dir))?;
+
+        tokio::fs::write(self.dir.join(key), bytes)
+            .await
+            .with_context(|| format!("Couldn't write upload {key}"))?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<StoredObject>, AppError> {
+        if !is_valid_key(key) {
+            return Ok(None);
+        }
+
+        let bytes = match tokio::fs::read(self.dir.join(key)).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(anyhow::Error::new(e)
+                    .context(format!("Couldn't read upload {key}"))
+                    .into());
+            }
+        };
+
+        let content_type = ImageFormat::from_path(key)
+            .map(|format| format.to_mime_type())
+            .unwrap_or("application/octet-stream")
+            .to_string();
+
+        Ok(Some(StoredObject {
+            content_type,
+            bytes,
+        }))
+    }
+}
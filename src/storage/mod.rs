@@ -0,0 +1,40 @@
+pub mod local_storage;
+pub mod s3_storage;
+
+use crate::app_config::{S3Config, StorageBackend, UploadsConfig};
+use crate::app_error::AppError;
+use async_trait::async_trait;
+use local_storage::LocalStorage;
+use s3_storage::S3Storage;
+use std::sync::Arc;
+
+pub struct StoredObject {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Where uploaded files are kept.
+///
+/// Keys are generated by the application and only made of ASCII letters, digits, `.`, `-`
+/// and `_`; implementations may reject anything else.
+#[async_trait]
+pub trait Storage: Send + Sync {
+    async fn put(&self, key: &str, content_type: &str, bytes: Vec<u8>) -> Result<(), AppError>;
+
+    async fn get(&self, key: &str) -> Result<Option<StoredObject>, AppError>;
+}
+
+pub fn create_storage(uploads: &UploadsConfig, s3: &S3Config) -> Arc<dyn Storage> {
+    match uploads.storage_backend {
+        StorageBackend::Local => Arc::new(LocalStorage::new(uploads.local_dir.clone().into())),
+        StorageBackend::S3 => Arc::new(S3Storage::new(s3.clone())),
+    }
+}
+
+pub(crate) fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with('.')
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'_'))
+}
@@ -0,0 +1,149 @@
+use crate::app_config::S3Config;
+use crate::app_error::AppError;
+use crate::storage::{Storage, StoredObject, is_valid_key};
+use crate::utils::aws_signer::{AwsSigner, amz_date, payload_hash};
+use anyhow::{Context, anyhow};
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{Method, StatusCode, Url};
+use std::time::Duration;
+
+/// Keeps files in a bucket of an S3 compatible service, addressed path-style
+/// (`<endpoint>/<bucket>/<key>`) so it also works against local stand-ins like MinIO.
+pub struct S3Storage {
+    client: reqwest::Client,
+    signer: AwsSigner,
+    config: S3Config,
+}
+
+impl S3Storage {
+    pub fn new(config: S3Config) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms))
+            .build()
+            .expect("Failed to build S3 HTTP client");
+
+        let signer = AwsSigner::new(
+            config.access_key_id.clone(),
+            config.secret_access_key.0.clone(),
+            config.region.clone(),
+            "s3".to_string(),
+        );
+
+        S3Storage {
+            client,
+            signer,
+            config,
+        }
+    }
+
+    fn signed_request(
+        &self,
+        method: Method,
+        key: &str,
+        content_type: Option<&str>,
+        body: Vec<u8>,
+    ) -> Result<reqwest::RequestBuilder, AppError> {
+        let url = Url::parse(&format!(
+            "{}/{}/{}",
+            self.config.endpoint.trim_end_matches('/'),
+            self.config.bucket,
+            key
+        ))
+        .context("Invalid S3 endpoint")?;
+
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(anyhow!("S3 endpoint has no host").into()),
+        };
+
+        let now = Utc::now();
+        let date = amz_date(now);
+        let hash = payload_hash(&body);
+
+        let mut headers = vec![
+            ("host", host.as_str()),
+            ("x-amz-content-sha256", hash.as_str()),
+            ("x-amz-date", date.as_str()),
+        ];
+        if let Some(content_type) = content_type {
+            headers.push(("content-type", content_type));
+        }
+
+        let authorization =
+            self.signer
+                .authorization(method.as_str(), url.path(), &headers, &hash, now);
+
+        let mut request = self
+            .client
+            .request(method, url)
+            .header(AUTHORIZATION, authorization)
+            .header("x-amz-content-sha256", &hash)
+            .header("x-amz-date", &date);
+        if let Some(content_type) = content_type {
+            request = request.header(CONTENT_TYPE, content_type);
+        }
+
+        Ok(request.body(body))
+    }
+}
+
+#[async_trait]
+impl Storage for S3Storage {
+    async fn put(&self, key: &str, content_type: &str, bytes: Vec<u8>) -> Result<(), AppError> {
+        if !is_valid_key(key) {
+            return Err(AppError::BadData(format!("Invalid storage key: {key}")));
+        }
+
+        let response = self
+            .signed_request(Method::PUT, key, Some(content_type), bytes)?
+            .send()
+            .await
+            .with_context(|| format!("Couldn't upload {key} to S3"))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("S3 rejected upload of {key}: {}", response.status()).into());
+        }
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<StoredObject>, AppError> {
+        if !is_valid_key(key) {
+            return Ok(None);
+        }
+
+        let response = self
+            .signed_request(Method::GET, key, None, Vec::new())?
+            .send()
+            .await
+            .with_context(|| format!("Couldn't fetch {key} from S3"))?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => return Ok(None),
+            status if !status.is_success() => {
+                return Err(anyhow!("S3 rejected download of {key}: {status}").into());
+            }
+            _ => {}
+        }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("application/octet-stream")
+            .to_string();
+
+        let bytes = response
+            .bytes()
+            .await
+            .with_context(|| format!("Couldn't fetch {key} from S3"))?;
+
+        Ok(Some(StoredObject {
+            content_type,
+            bytes: bytes.to_vec(),
+        }))
+    }
+}
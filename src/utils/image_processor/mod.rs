@@ -0,0 +1,166 @@
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageError, ImageFormat, ImageReader, Limits};
+use std::io::Cursor;
+
+/// Formats accepted for uploads, detected from the content rather than the declared type.
+const SUPPORTED_FORMATS: [ImageFormat; 4] = [
+    ImageFormat::Png,
+    ImageFormat::Jpeg,
+    ImageFormat::Gif,
+    ImageFormat::WebP,
+];
+
+pub struct ProcessedImage {
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+    pub image: Vec<u8>,
+    pub thumbnail: Vec<u8>,
+    pub thumbnail_format: ImageFormat,
+}
+
+/// Decodes an uploaded image, encodes it again in the same format and renders a thumbnail
+/// fitting in a `thumbnail_size` square.
+///
+/// Encoding it again leaves out the metadata of the upload, e.g. the location a photo was
+/// taken at, so the orientation it records is applied to the pixels first. Animations are
+/// reduced to their first frame, and WebP images are encoded losslessly.
+///
+/// Thumbnails of JPEG images are JPEG, every other format gets a PNG thumbnail, since GIF
+/// would drop colors and WebP can only be encoded losslessly.
+pub fn process(
+    bytes: &[u8],
+    max_dimension: u32,
+    thumbnail_size: u32,
+) -> Result<ProcessedImage, String> {
+    let mut reader = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| format!("Couldn't read image: {e}"))?;
+
+    let format = reader
+        .format()
+        .filter(|format| SUPPORTED_FORMATS.contains(format))
+        .ok_or("Image must be a PNG, JPEG, GIF or WebP file")?;
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(max_dimension);
+    limits.max_image_height = Some(max_dimension);
+    reader.limits(limits);
+
+    let decode_error = |e| match e {
+        ImageError::Limits(_) => format!(
+            "Image cannot be wider or taller than {} pixels",
+            max_dimension
+        ),
+        e => format!("Couldn't decode image: {e}"),
+    };
+
+    let mut decoder = reader.into_decoder().map_err(decode_error)?;
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let mut image = DynamicImage::from_decoder(decoder).map_err(decode_error)?;
+    image.apply_orientation(orientation);
+
+    let thumbnail = if image.width() > thumbnail_size || image.height() > thumbnail_size {
+        image.thumbnail(thumbnail_size, thumbnail_size)
+    } else {
+        image.clone()
+    };
+
+    let (thumbnail, thumbnail_format) = match format {
+        ImageFormat::Jpeg => (
+            DynamicImage::ImageRgb8(thumbnail.to_rgb8()),
+            ImageFormat::Jpeg,
+        ),
+        _ => (thumbnail, ImageFormat::Png),
+    };
+
+    Ok(ProcessedImage {
+        format,
+        width: image.width(),
+        height: image.height(),
+        image: encode(&image, format).map_err(|e| format!("Couldn't encode image: {e}"))?,
+        thumbnail: encode(&thumbnail, thumbnail_format)
+            .map_err(|e| format!("Couldn't encode thumbnail: {e}"))?,
+        thumbnail_format,
+    })
+}
+
+fn encode(image: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, ImageError> {
+    let mut encoded = Cursor::new(Vec::new());
+    image.write_to(&mut encoded, format)?;
+
+    Ok(encoded.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::image_processor::process;
+    use image::{DynamicImage, ImageFormat};
+    use std::io::Cursor;
+
+    fn encode(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+        let mut bytes = Cursor::new(Vec::new());
+        DynamicImage::new_rgb8(width, height)
+            .write_to(&mut bytes, format)
+            .unwrap();
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn test_thumbnail_keeps_aspect_ratio() {
+        let processed = process(&encode(400, 200, ImageFormat::Jpeg), 1000, 100).unwrap();
+
+        assert_eq!(processed.format, ImageFormat::Jpeg);
+        assert_eq!((processed.width, processed.height), (400, 200));
+        assert_eq!(processed.thumbnail_format, ImageFormat::Jpeg);
+
+        let thumbnail = image::load_from_memory(&processed.thumbnail).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (100, 50));
+    }
+
+    #[test]
+    fn test_small_images_are_not_upscaled() {
+        let processed = process(&encode(20, 30, ImageFormat::Gif), 1000, 100).unwrap();
+
+        assert_eq!(processed.thumbnail_format, ImageFormat::Png);
+
+        let thumbnail = image::load_from_memory(&processed.thumbnail).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (20, 30));
+    }
+
+    /// Inserts an Exif segment rotating the image by 90 degrees, followed by `extra` bytes.
+    fn with_exif(jpeg: &[u8], extra: &[u8]) -> Vec<u8> {
+        let mut payload = b"Exif\0\0II*\0\x08\0\0\0".to_vec();
+        // A single entry, the orientation, and no next directory.
+        payload.extend_from_slice(&[1, 0, 0x12, 0x01, 3, 0, 1, 0, 0, 0, 6, 0, 0, 0]);
+        payload.extend_from_slice(&[0, 0, 0, 0]);
+        payload.extend_from_slice(extra);
+
+        let mut bytes = jpeg[..2].to_vec();
+        bytes.extend_from_slice(&[0xFF, 0xE1]);
+        bytes.extend_from_slice(&(payload.len() as u16 + 2).to_be_bytes());
+        bytes.extend_from_slice(&payload);
+        bytes.extend_from_slice(&jpeg[2..]);
+        bytes
+    }
+
+    #[test]
+    fn test_metadata_is_stripped_after_applying_orientation() {
+        let upload = with_exif(&encode(40, 20, ImageFormat::Jpeg), b"GPS 48.8584 2.2945");
+        let processed = process(&upload, 1000, 100).unwrap();
+
+        assert_eq!((processed.width, processed.height), (20, 40));
+        assert!(!processed.image.windows(4).any(|w| w == b"Exif"));
+        assert!(!processed.image.windows(3).any(|w| w == b"GPS"));
+
+        let image = image::load_from_memory(&processed.image).unwrap();
+        assert_eq!((image.width(), image.height()), (20, 40));
+    }
+
+    #[test]
+    fn test_rejects_unsupported_and_oversized_images() {
+        assert!(process(b"<svg></svg>", 1000, 100).is_err());
+        assert!(process(b"BM, a bitmap header", 1000, 100).is_err());
+        assert!(process(&encode(300, 10, ImageFormat::Png), 200, 100).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use ammonia::Builder;
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, TagEnd, html};
+use std::collections::HashSet;
+
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Prefixed to every id of the rendered HTML, so that it can't clash with the ids of the page
+/// embedding it.
+const ID_PREFIX: &str = "user-content-";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TocEntry {
+    pub level: u8,
+    pub text: String,
+    /// Id of the heading in the rendered HTML.
+    pub anchor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedMarkdown {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+    pub reading_time_minutes: u32,
+}
+
+/// Renders CommonMark (with tables, strikethrough and task lists) to HTML stripped of scripts,
+/// event handlers and anything else unsafe to embed. Headings get ids, listed in the table of
+/// contents.
+pub fn render(markdown: &str) -> RenderedMarkdown {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut events: Vec<Event> = Parser::new_ext(markdown, options).collect();
+
+    let mut toc = Vec::new();
+    let mut anchors = HashSet::new();
+    let mut words = 0;
+    let mut heading: Option<(usize, String)> = None;
+
+    for index in 0..events.len() {
+        match &events[index] {
+            Event::Start(Tag::Heading { .. }) => heading = Some((index, String::new())),
+            Event::Text(text) | Event::Code(text) => {
+                words += text.split_whitespace().count();
+
+                if let Some((_, heading_text)) = heading.as_mut() {
+                    heading_text.push_str(text);
+                }
+            }
+            Event::End(TagEnd::Heading(level)) => {
+                let level = *level as u8;
+
+                if let Some((start, text)) = heading.take() {
+                    let anchor = unique_anchor(&text, &mut anchors);
+
+                    if let Event::Start(Tag::Heading { id, .. }) = &mut events[start] {
+                        *id = Some(CowStr::from(anchor.clone()));
+                    }
+
+                    toc.push(TocEntry {
+                        level,
+                        text: text.trim().to_string(),
+                        anchor: format!("{ID_PREFIX}{anchor}"),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    let html = Builder::default()
+        .add_tag_attributes("h1", ["id"])
+        .add_tag_attributes("h2", ["id"])
+        .add_tag_attributes("h3", ["id"])
+        .add_tag_attributes("h4", ["id"])
+        .add_tag_attributes("h5", ["id"])
+        .add_tag_attributes("h6", ["id"])
+        .id_prefix(Some(ID_PREFIX))
+        .clean(&unsafe_html)
+        .to_string();
+
+    RenderedMarkdown {
+        html,
+        toc,
+        reading_time_minutes: words.div_ceil(WORDS_PER_MINUTE).max(1) as u32,
+    }
+}
+
+/// Lowercase words of the heading joined by dashes, numbered when the same heading appears twice.
+fn unique_anchor(text: &str, anchors: &mut HashSet<String>) -> String {
+    let slug = text
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let slug = if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug
+    };
+
+    let mut anchor = slug.clone();
+    let mut suffix = 1;
+    while !anchors.insert(anchor.clone()) {
+        anchor = format!("{slug}-{suffix}");
+        suffix += 1;
+    }
+
+    anchor
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::markdown::{TocEntry, render};
+
+    #[test]
+    fn test_render_markdown_to_html() {
+        let rendered = render("Some *emphasis* and a [link](https://example.com).");
+
+        assert_eq!(
+            rendered.html,
+            "<p>Some <em>emphasis</em> and a <a href=\"https://example.com\" rel=\"noopener noreferrer\">link</a>.</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_strips_unsafe_html() {
+        let rendered = render(
+            "<script>alert(1)</script>\n\n<img src=\"a.png\" onerror=\"alert(1)\">\n\n[click](javascript:alert(1))",
+        );
+
+        assert!(!rendered.html.contains("script"));
+        assert!(!rendered.html.contains("onerror"));
+        assert!(!rendered.html.contains("javascript"));
+        assert!(rendered.html.contains("<img src=\"a.png\">"));
+    }
+
+    #[test]
+    fn test_render_builds_table_of_contents() {
+        let rendered = render("# Intro\n\ntext\n\n## Getting `started`!\n\n## Intro");
+
+        assert_eq!(
+            rendered.toc,
+            vec![
+                TocEntry {
+                    level: 1,
+                    text: "Intro".to_string(),
+                    anchor: "user-content-intro".to_string()
+                },
+                TocEntry {
+                    level: 2,
+                    text: "Getting started!".to_string(),
+                    anchor: "user-content-getting-started".to_string()
+                },
+                TocEntry {
+                    level: 2,
+                    text: "Intro".to_string(),
+                    anchor: "user-content-intro-1".to_string()
+                },
+            ]
+        );
+        assert!(
+            rendered
+                .html
+                .contains("<h2 id=\"user-content-getting-started\">")
+        );
+    }
+
+    #[test]
+    fn test_reading_time_rounds_up() {
+        assert_eq!(render("word").reading_time_minutes, 1);
+        assert_eq!(render(&"word ".repeat(200)).reading_time_minutes, 1);
+        assert_eq!(render(&"word ".repeat(201)).reading_time_minutes, 2);
+    }
+}
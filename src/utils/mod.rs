@@ -1,2 +1,8 @@
+pub mod aws_signer;
 pub mod hasher;
+pub mod image_processor;
 pub mod jwt;
+pub mod markdown;
+pub mod public_address;
+pub mod unicode_text;
+pub mod webhook_signer;
@@ -0,0 +1,146 @@
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// Whether the address is reachable on the public internet, rather than loopback, private,
+/// link-local or otherwise reserved for local use.
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4(ip),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_multicast()
+        // "This network", carrier-grade NAT and benchmarking.
+        || first == 0
+        || (first == 100 && (second & 0xc0) == 64)
+        || (first == 198 && (second & 0xfe) == 18)
+        // Reserved, along with the broadcast address.
+        || first >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // Unique local and link-local.
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80)
+}
+
+/// Whether the host of a URL, as written, is obviously local: `localhost` or a non-public IP
+/// address. Names resolving to local addresses are only caught by [`PublicAddressResolver`].
+pub fn is_local_host(host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_lowercase();
+
+    if host == "localhost" || host.ends_with(".localhost") {
+        return true;
+    }
+
+    host.trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+        .is_ok_and(|ip| !is_public_address(ip))
+}
+
+/// Resolves host names like the system does, keeping only public addresses so that requests
+/// can't be pointed at internal services, even by a name changing what it resolves to.
+pub struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_address(addr.ip()))
+                .collect();
+
+            if addrs.is_empty() {
+                return Err(format!("{} has no public address", name.as_str()).into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::public_address::{PublicAddressResolver, is_local_host, is_public_address};
+    use reqwest::dns::Resolve;
+
+    #[test]
+    fn test_local_addresses_are_not_public() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "0.0.0.0",
+            "100.64.0.1",
+            "198.18.0.1",
+            "198.19.255.255",
+            "224.0.0.251",
+            "239.255.255.250",
+            "240.0.0.1",
+            "255.255.255.255",
+            "::1",
+            "::",
+            "fd00::1",
+            "ff02::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public_address(ip.parse().unwrap()), "{}", ip);
+        }
+
+        for ip in [
+            "93.184.216.34",
+            "8.8.8.8",
+            "198.20.0.1",
+            "223.255.255.255",
+            "2606:4700::1111",
+        ] {
+            assert!(is_public_address(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[test]
+    fn test_local_hosts() {
+        for host in [
+            "localhost",
+            "LOCALHOST.",
+            "api.localhost",
+            "127.0.0.1",
+            "[::1]",
+        ] {
+            assert!(is_local_host(host), "{}", host);
+        }
+
+        for host in ["example.com", "localhost.example.com", "93.184.216.34"] {
+            assert!(!is_local_host(host), "{}", host);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolver_refuses_names_of_local_addresses() {
+        let resolved = PublicAddressResolver
+            .resolve("localhost".parse().unwrap())
+            .await;
+
+        assert!(resolved.is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_security::{GeneralSecurityProfile, MixedScript};
+
+/// Applies NFKC normalization, folding compatibility characters such as full-width letters
+/// or ligatures into their usual form, so that visually identical text compares equal.
+pub fn normalize(value: &str) -> String {
+    value.nfkc().collect()
+}
+
+/// Rejects identifiers made to look like others (UTS #39): mixing scripts, like a Cyrillic
+/// "а" among Latin letters, or using invisible characters and symbols.
+///
+/// ASCII is always accepted but for control characters, so existing usernames with spaces or
+/// punctuation stay valid.
+pub fn check_confusable(value: &str) -> Result<(), String> {
+    if value
+        .chars()
+        .any(|c| c.is_control() || (!c.is_ascii() && !c.identifier_allowed()))
+    {
+        return Err("contains characters that are not allowed".to_string());
+    }
+
+    if !value.is_single_script() {
+        return Err("cannot mix characters of different scripts".to_string());
+    }
+
+    Ok(())
+}
+
+/// Computes the UTS #39 skeleton of the text, ignoring case: texts that look alike, even when
+/// written in a single script like an all-Cyrillic "аdmin", share the same skeleton.
+pub fn skeleton(value: &str) -> String {
+    unicode_security::skeleton(&value.to_lowercase()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::unicode_text::{check_confusable, normalize, skeleton};
+
+    #[test]
+    fn test_normalize_folds_compatibility_characters() {
+        assert_eq!(normalize("ｂｏｂ"), "bob");
+        assert_eq!(normalize("ﬁsh"), "fish");
+        assert_eq!(normalize("cafe\u{301}"), "café");
+    }
+
+    #[test]
+    fn test_accepts_single_script_identifiers() {
+        assert!(check_confusable("John Doe-42").is_ok());
+        assert!(check_confusable("алиса").is_ok());
+        assert!(check_confusable("山田たろう").is_ok());
+        assert!(check_confusable("zoë").is_ok());
+    }
+
+    #[test]
+    fn test_rejects_confusable_identifiers() {
+        // Latin "p" and "ypal" around a Cyrillic "а".
+        assert!(check_confusable("p\u{430}ypal").is_err());
+        assert!(check_confusable("bo\u{200b}b").is_err());
+        assert!(check_confusable("bob\u{7}").is_err());
+        assert!(check_confusable("bob♥").is_err());
+    }
+
+    #[test]
+    fn test_lookalikes_share_a_skeleton() {
+        // A Cyrillic "а" followed by Latin letters, and "scope" written only in Cyrillic.
+        assert_eq!(skeleton("\u{430}dmin"), skeleton("admin"));
+        assert_eq!(
+            skeleton("\u{455}\u{441}\u{43e}\u{440}\u{435}"),
+            skeleton("scope")
+        );
+        assert_eq!(skeleton("ADMIN"), skeleton("admin"));
+        assert_ne!(skeleton("alice"), skeleton("admin"));
+    }
+}
@@ -0,0 +1,46 @@
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Generates a random 256-bit secret, hex encoded.
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Signs the request body, in the `sha256=<hex digest>` format of the `X-Realworld-Signature`
+/// header.
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhook_signer::{generate_secret, sign};
+
+    #[test]
+    fn test_sign_matches_hmac_sha256() {
+        // RFC 4231, test case 2
+        let signature = sign("Jefe", b"what do ya want for nothing?");
+
+        assert_eq!(
+            signature,
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_generate_secret_is_random() {
+        let secret = generate_secret();
+
+        assert_eq!(secret.len(), 64);
+        assert_ne!(secret, generate_secret());
+    }
+}
@@ -1201,3 +1201,348 @@ async fn test_combine_multiple_filters() {
             .contains(&json!("rust"))
     );
 }
+
+async fn update_article(
+    app: axum::Router,
+    token: &str,
+    slug: &str,
+    payload: serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(format!("/api/articles/{}", slug))
+                .header("content-type", "application/json")
+                .header("authorization", format!("Token {}", token))
+                .body(Body::from(serde_json::to_string(&payload).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+async fn create_tagged_article(app: axum::Router, token: &str, tags: &[&str]) {
+    let payload = json!({
+        "article": {
+            "title": "Tagged Article",
+            "description": "Tagged description",
+            "body": "Tagged body",
+            "tagList": tags
+        }
+    });
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/articles")
+                .header("content-type", "application/json")
+                .header("authorization", format!("Token {}", token))
+                .body(Body::from(serde_json::to_string(&payload).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_update_article_replaces_tag_list() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    create_tagged_article(app.clone(), &token, &["rust", "testing"]).await;
+
+    let (status, body) = update_article(
+        app.clone(),
+        &token,
+        "tagged-article",
+        json!({ "article": { "tagList": ["testing", "web"] } }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["tagList"], json!(["testing", "web"]));
+    assert_eq!(body["article"]["body"], "Tagged body");
+
+    let (status, body) = update_article(
+        app,
+        &token,
+        "tagged-article",
+        json!({ "article": { "tagList": [] } }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["tagList"], json!([]));
+}
+
+#[tokio::test]
+async fn test_update_article_adds_and_removes_tags() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    create_tagged_article(app.clone(), &token, &["rust", "testing"]).await;
+
+    let (status, body) = update_article(
+        app.clone(),
+        &token,
+        "tagged-article",
+        json!({
+            "article": {
+                "title": "Retagged Article",
+                "addTags": ["web", "rust"],
+                "removeTags": ["testing", "unknown"]
+            }
+        }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["slug"], "retagged-article");
+    assert_eq!(body["article"]["tagList"], json!(["rust", "web"]));
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri("/api/articles?tag=testing")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(body["articlesCount"], 0);
+}
+
+#[tokio::test]
+async fn test_update_article_rejects_tag_list_with_tag_operations() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    create_tagged_article(app.clone(), &token, &["rust"]).await;
+
+    let (status, _) = update_article(
+        app.clone(),
+        &token,
+        "tagged-article",
+        json!({ "article": { "tagList": ["web"], "addTags": ["testing"] } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = update_article(app, &token, "tagged-article", json!({ "article": {} })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+async fn create_article_with_tags(
+    app: axum::Router,
+    token: &str,
+    title: &str,
+    tags: &[&str],
+) -> serde_json::Value {
+    let payload = json!({
+        "article": {
+            "title": title,
+            "description": "Test description",
+            "body": "Test body",
+            "tagList": tags
+        }
+    });
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/articles")
+                .header("content-type", "application/json")
+                .header("authorization", format!("Token {}", token))
+                .body(Body::from(serde_json::to_string(&payload).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    body["article"].clone()
+}
+
+async fn list_titles(app: axum::Router, uri: &str) -> (StatusCode, Vec<String>) {
+    let response = app
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap_or_default();
+
+    let titles = body["articles"]
+        .as_array()
+        .map(|articles| {
+            articles
+                .iter()
+                .map(|a| a["title"].as_str().unwrap().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    (status, titles)
+}
+
+#[tokio::test]
+async fn test_filter_articles_by_multiple_tags() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    create_article_with_tags(app.clone(), &token, "Rust Only", &["rust"]).await;
+    create_article_with_tags(app.clone(), &token, "Rust And Web", &["rust", "web"]).await;
+    create_article_with_tags(app.clone(), &token, "Web Only", &["web"]).await;
+    create_article_with_tags(app.clone(), &token, "Untagged", &[]).await;
+
+    let (status, titles) = list_titles(app.clone(), "/api/articles?tags=rust,web").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles, vec!["Web Only", "Rust And Web", "Rust Only"]);
+
+    let (_, titles) = list_titles(app.clone(), "/api/articles?tags=rust,web&tagMode=all").await;
+    assert_eq!(titles, vec!["Rust And Web"]);
+
+    let (_, titles) = list_titles(app, "/api/articles?tag=web&tags=rust&tagMode=all").await;
+    assert_eq!(titles, vec!["Rust And Web"]);
+}
+
+#[tokio::test]
+async fn test_filter_articles_excluding_tags() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    create_article_with_tags(app.clone(), &token, "Rust Only", &["rust"]).await;
+    create_article_with_tags(app.clone(), &token, "Rust And Web", &["rust", "web"]).await;
+    create_article_with_tags(app.clone(), &token, "Untagged", &[]).await;
+
+    let (_, titles) = list_titles(app.clone(), "/api/articles?excludeTags=web").await;
+    assert_eq!(titles, vec!["Untagged", "Rust Only"]);
+
+    let (_, titles) = list_titles(app, "/api/articles?tag=rust&excludeTags=web").await;
+    assert_eq!(titles, vec!["Rust Only"]);
+}
+
+#[tokio::test]
+async fn test_filter_articles_by_creation_date() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    create_article_with_tags(app.clone(), &token, "First", &[]).await;
+    let second = create_article_with_tags(app.clone(), &token, "Second", &[]).await;
+    let third = create_article_with_tags(app.clone(), &token, "Third", &[]).await;
+
+    let (_, titles) = list_titles(
+        app.clone(),
+        &format!(
+            "/api/articles?createdAfter={}",
+            second["createdAt"].as_str().unwrap()
+        ),
+    )
+    .await;
+    assert_eq!(titles, vec!["Third", "Second"]);
+
+    let (_, titles) = list_titles(
+        app,
+        &format!(
+            "/api/articles?createdAfter={}&createdBefore={}",
+            second["createdAt"].as_str().unwrap(),
+            third["createdAt"].as_str().unwrap()
+        ),
+    )
+    .await;
+    assert_eq!(titles, vec!["Second"]);
+}
+
+#[tokio::test]
+async fn test_sort_articles_by_favorites_and_comments() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    create_article_with_tags(app.clone(), &token, "Quiet", &[]).await;
+    create_article_with_tags(app.clone(), &token, "Favorited", &[]).await;
+    create_article_with_tags(app.clone(), &token, "Discussed", &[]).await;
+
+    for (method, uri, payload) in [
+        ("POST", "/api/articles/favorited/favorite", json!({})),
+        (
+            "POST",
+            "/api/articles/discussed/comments",
+            json!({ "comment": { "body": "First" } }),
+        ),
+        (
+            "POST",
+            "/api/articles/discussed/comments",
+            json!({ "comment": { "body": "Second" } }),
+        ),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .header("authorization", format!("Token {}", reader))
+                    .body(Body::from(serde_json::to_string(&payload).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+    }
+
+    let (_, titles) = list_titles(app.clone(), "/api/articles?sort=favoritesCount").await;
+    assert_eq!(titles, vec!["Favorited", "Discussed", "Quiet"]);
+
+    let (_, titles) = list_titles(app.clone(), "/api/articles?sort=commentsCount").await;
+    assert_eq!(titles, vec!["Discussed", "Favorited", "Quiet"]);
+
+    let (_, titles) = list_titles(app, "/api/articles?sort=createdAt&order=asc").await;
+    assert_eq!(titles, vec!["Quiet", "Favorited", "Discussed"]);
+}
+
+#[tokio::test]
+async fn test_invalid_article_filters_are_rejected() {
+    let app = common::create_test_app().await;
+
+    let (status, _) = list_titles(
+        app.clone(),
+        "/api/articles?createdAfter=2025-02-01T00:00:00Z&createdBefore=2025-01-01T00:00:00Z",
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = list_titles(app.clone(), "/api/articles?tags=rust,web&excludeTags=web").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = list_titles(app.clone(), "/api/articles?sort=title").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = list_titles(app, "/api/articles?createdAfter=yesterday").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
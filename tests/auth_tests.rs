@@ -215,3 +215,110 @@ async fn test_login_with_nonexistent_email_fails() {
     // Then
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
+
+async fn post_json(
+    app: axum::Router,
+    uri: &str,
+    payload: serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_string(&payload).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    (
+        status,
+        serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null),
+    )
+}
+
+#[tokio::test]
+async fn test_email_is_case_insensitive() {
+    // Given
+    let app = common::create_test_app().await;
+    let register_payload = |username: &str, email: &str| {
+        json!({
+            "user": { "username": username, "email": email, "password": "password123" }
+        })
+    };
+
+    // When
+    let (status, body) = post_json(
+        app.clone(),
+        "/api/users",
+        register_payload("mixed", "Mixed@Example.COM"),
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["user"]["email"], "mixed@example.com");
+
+    let (status, _) = post_json(
+        app.clone(),
+        "/api/users/login",
+        json!({ "user": { "email": "MIXED@example.com", "password": "password123" } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = post_json(
+        app.clone(),
+        "/api/users",
+        register_payload("other", "mixed@EXAMPLE.com"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_username_is_normalized() {
+    // Given
+    let app = common::create_test_app().await;
+    let register_payload = |username: &str, email: &str| {
+        json!({
+            "user": { "username": username, "email": email, "password": "password123" }
+        })
+    };
+
+    // When
+    let (status, body) = post_json(
+        app.clone(),
+        "/api/users",
+        register_payload("ｂｏｂ", "bob@example.com"),
+    )
+    .await;
+
+    // Then
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["user"]["username"], "bob");
+
+    let (status, _) = post_json(
+        app.clone(),
+        "/api/users",
+        register_payload("BOB", "bob2@example.com"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // A Cyrillic "а" among Latin letters.
+    let (status, _) = post_json(
+        app.clone(),
+        "/api/users",
+        register_payload("p\u{430}ypal", "paypal@example.com"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
@@ -0,0 +1,379 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::{Value, json};
+
+async fn add_comment(app: axum::Router, token: &str, slug: &str, body: &str) -> StatusCode {
+    let (status, _) = send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(token),
+        Some(json!({ "comment": { "body": body } })),
+    )
+    .await;
+    status
+}
+
+fn field<'a>(body: &'a Value, list: &str, field: &str) -> Vec<&'a str> {
+    body[list]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item[field].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_block_user() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Alice's article").await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/bob/follow",
+        Some(&alice),
+        None,
+    )
+    .await;
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/bob/block",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profile"]["blocking"], true);
+    assert_eq!(body["profile"]["muting"], false);
+    // Follows in both directions are gone.
+    assert_eq!(body["profile"]["following"], false);
+    assert_eq!(body["profile"]["followersCount"], 0);
+    assert_eq!(body["profile"]["followingCount"], 0);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    assert_eq!(
+        add_comment(app.clone(), &bob, &slug, "Let me in").await,
+        StatusCode::FORBIDDEN
+    );
+
+    let (status, _) = send(app.clone(), "GET", "/api/profiles/alice", Some(&bob), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/alice/followers",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Others, and the blocked user anonymously, still see the profile.
+    let (status, _) = send(app.clone(), "GET", "/api/profiles/alice", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // The blocked user can still block back.
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/block",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profile"]["blocking"], true);
+    send(
+        app.clone(),
+        "DELETE",
+        "/api/profiles/alice/block",
+        Some(&bob),
+        None,
+    )
+    .await;
+
+    let (status, body) = send(
+        app.clone(),
+        "DELETE",
+        "/api/profiles/bob/block",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profile"]["blocking"], false);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        add_comment(app.clone(), &bob, &slug, "Thanks").await,
+        StatusCode::CREATED
+    );
+    let (status, _) = send(app.clone(), "GET", "/api/profiles/alice", Some(&bob), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_blocked_user_cannot_comment() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    let alice_slug = create_article(app.clone(), &alice, "Alice's article").await;
+    let bob_slug = create_article(app.clone(), &bob, "Bob's article").await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/bob/block",
+        Some(&alice),
+        None,
+    )
+    .await;
+
+    assert_eq!(
+        add_comment(app.clone(), &bob, &alice_slug, "Let me in").await,
+        StatusCode::FORBIDDEN
+    );
+
+    // The block only goes one way, and doesn't concern anyone else.
+    assert_eq!(
+        add_comment(app.clone(), &alice, &bob_slug, "Still here").await,
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        add_comment(app.clone(), &carol, &alice_slug, "Hello").await,
+        StatusCode::CREATED
+    );
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}/comments", alice_slug),
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(field(&body, "comments", "body"), vec!["Hello"]);
+
+    // Muting doesn't stop comments, it only hides them from the muting user.
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/carol/mute",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(
+        add_comment(app.clone(), &carol, &alice_slug, "Hello again").await,
+        StatusCode::CREATED
+    );
+}
+
+#[tokio::test]
+async fn test_blocked_user_cannot_read_articles() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Alice's article").await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/bob/block",
+        Some(&alice),
+        None,
+    )
+    .await;
+
+    for path in ["", "/comments", "/events"] {
+        let uri = format!("/api/articles/{}{}", slug, path);
+        let (status, _) = send(app.clone(), "GET", &uri, Some(&bob), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "GET {}", uri);
+    }
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Anonymously the article is still there, untouched.
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["favoritesCount"], 0);
+
+    // Blocks go one way, the blocker still reads the blocked user's articles.
+    let bob_slug = create_article(app.clone(), &bob, "Bob's article").await;
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", bob_slug),
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_mute_user_hides_articles_and_comments() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    create_article(app.clone(), &bob, "Bob's article").await;
+    let slug = create_article(app.clone(), &carol, "Carol's article").await;
+    add_comment(app.clone(), &bob, &slug, "From bob").await;
+    add_comment(app.clone(), &carol, &slug, "From carol").await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/bob/follow",
+        Some(&alice),
+        None,
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/carol/follow",
+        Some(&alice),
+        None,
+    )
+    .await;
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/bob/mute",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profile"]["muting"], true);
+    // Muting doesn't unfollow.
+    assert_eq!(body["profile"]["following"], true);
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles", Some(&alice), None).await;
+    assert_eq!(field(&body, "articles", "title"), vec!["Carol's article"]);
+    assert_eq!(body["articlesCount"], 1);
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles/feed", Some(&alice), None).await;
+    assert_eq!(field(&body, "articles", "title"), vec!["Carol's article"]);
+    assert_eq!(body["articlesCount"], 1);
+
+    let comments_uri = format!("/api/articles/{}/comments", slug);
+    let (_, body) = send(app.clone(), "GET", &comments_uri, Some(&alice), None).await;
+    assert_eq!(field(&body, "comments", "body"), vec!["From carol"]);
+
+    // Nobody else is affected.
+    let (_, body) = send(app.clone(), "GET", &comments_uri, Some(&carol), None).await;
+    assert_eq!(field(&body, "comments", "body").len(), 2);
+    let (_, body) = send(app.clone(), "GET", "/api/articles", None, None).await;
+    assert_eq!(body["articlesCount"], 2);
+
+    let (status, body) = send(
+        app.clone(),
+        "DELETE",
+        "/api/profiles/bob/mute",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profile"]["muting"], false);
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles/feed", Some(&alice), None).await;
+    assert_eq!(body["articlesCount"], 2);
+    let (_, body) = send(app.clone(), "GET", &comments_uri, Some(&alice), None).await;
+    assert_eq!(field(&body, "comments", "body").len(), 2);
+}
+
+#[tokio::test]
+async fn test_cannot_block_or_mute_yourself() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/block",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/mute",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send(app.clone(), "POST", "/api/profiles/alice/mute", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/nobody/block",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,223 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::{Value, json};
+
+async fn bookmark(app: axum::Router, token: &str, slug: &str, payload: Option<Value>) -> Value {
+    let (status, body) = send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/bookmark", slug),
+        Some(token),
+        payload,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    body
+}
+
+#[tokio::test]
+async fn test_bookmark_is_private_and_separate_from_favorites() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Read later").await;
+
+    let body = bookmark(app.clone(), &reader, &slug, None).await;
+    assert_eq!(body["article"]["bookmarked"], true);
+    assert_eq!(body["article"]["favorited"], false);
+    assert_eq!(body["article"]["favoritesCount"], 0);
+
+    let uri = format!("/api/articles/{}", slug);
+
+    let (_, body) = send(app.clone(), "GET", &uri, Some(&reader), None).await;
+    assert_eq!(body["article"]["bookmarked"], true);
+
+    let (_, body) = send(app.clone(), "GET", &uri, Some(&author), None).await;
+    assert_eq!(body["article"]["bookmarked"], false);
+
+    let (_, body) = send(app.clone(), "GET", &uri, None, None).await;
+    assert_eq!(body["article"]["bookmarked"], false);
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles", Some(&reader), None).await;
+    assert_eq!(body["articles"][0]["bookmarked"], true);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/bookmarks",
+        Some(&author),
+        None,
+    )
+    .await;
+    assert_eq!(body["bookmarksCount"], 0);
+}
+
+#[tokio::test]
+async fn test_list_bookmarks_with_folders_and_notes() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    let first = create_article(app.clone(), &author, "First").await;
+    let second = create_article(app.clone(), &author, "Second").await;
+    let third = create_article(app.clone(), &author, "Third").await;
+
+    bookmark(
+        app.clone(),
+        &reader,
+        &first,
+        Some(json!({ "bookmark": { "folder": "rust", "note": "Check the benchmarks" } })),
+    )
+    .await;
+    bookmark(app.clone(), &reader, &second, Some(json!({}))).await;
+    bookmark(
+        app.clone(),
+        &reader,
+        &third,
+        Some(json!({ "bookmark": { "folder": "rust" } })),
+    )
+    .await;
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/bookmarks",
+        Some(&reader),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["bookmarksCount"], 3);
+    let titles: Vec<&str> = body["bookmarks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["article"]["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, vec!["Third", "Second", "First"]);
+    assert_eq!(body["bookmarks"][1]["folder"], Value::Null);
+    assert_eq!(body["bookmarks"][2]["folder"], "rust");
+    assert_eq!(body["bookmarks"][2]["note"], "Check the benchmarks");
+    assert_eq!(body["bookmarks"][2]["article"]["bookmarked"], true);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/bookmarks?folder=rust&limit=1&offset=1",
+        Some(&reader),
+        None,
+    )
+    .await;
+    assert_eq!(body["bookmarksCount"], 2);
+    assert_eq!(body["bookmarks"].as_array().unwrap().len(), 1);
+    assert_eq!(body["bookmarks"][0]["article"]["title"], "First");
+}
+
+#[tokio::test]
+async fn test_bookmarking_again_replaces_folder_and_note() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Article").await;
+
+    bookmark(
+        app.clone(),
+        &author,
+        &slug,
+        Some(json!({ "bookmark": { "folder": "later", "note": "Skim" } })),
+    )
+    .await;
+    bookmark(
+        app.clone(),
+        &author,
+        &slug,
+        Some(json!({ "bookmark": { "folder": "done" } })),
+    )
+    .await;
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/bookmarks",
+        Some(&author),
+        None,
+    )
+    .await;
+    assert_eq!(body["bookmarksCount"], 1);
+    assert_eq!(body["bookmarks"][0]["folder"], "done");
+    assert_eq!(body["bookmarks"][0]["note"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_remove_bookmark() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Article").await;
+    bookmark(app.clone(), &author, &slug, None).await;
+
+    let (status, body) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/bookmark", slug),
+        Some(&author),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["bookmarked"], false);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/bookmarks",
+        Some(&author),
+        None,
+    )
+    .await;
+    assert_eq!(body["bookmarksCount"], 0);
+}
+
+#[tokio::test]
+async fn test_bookmark_errors() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Article").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/articles/missing/bookmark",
+        Some(&author),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/bookmark", slug),
+        Some(&author),
+        Some(json!({ "bookmark": { "folder": "  " } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/bookmark", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = send(app.clone(), "GET", "/api/user/bookmarks", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,252 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::{Value, json};
+
+async fn invite(app: axum::Router, token: &str, slug: &str, username: &str) -> (StatusCode, Value) {
+    send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/co-authors/{}", slug, username),
+        Some(token),
+        None,
+    )
+    .await
+}
+
+async fn accept(app: axum::Router, token: &str, slug: &str, username: &str) -> (StatusCode, Value) {
+    send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/co-authors/{}/accept", slug, username),
+        Some(token),
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_co_author_can_update_article() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Shared article").await;
+
+    let (status, body) = invite(app.clone(), &alice, &slug, "bob").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["coAuthors"], json!([]));
+
+    let (status, body) = accept(app.clone(), &bob, &slug, "bob").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["author"]["username"], "alice");
+    assert_eq!(body["article"]["coAuthors"][0]["username"], "bob");
+
+    let update = json!({ "article": { "body": "Written together" } });
+
+    let (status, body) = send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        Some(&bob),
+        Some(update.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["body"], "Written together");
+    assert_eq!(body["article"]["author"]["username"], "alice");
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        Some(&carol),
+        Some(update),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // Only the author can delete the article or invite co-authors.
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = invite(app.clone(), &bob, &slug, "carol").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = invite(app.clone(), &alice, &slug, "alice").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_pending_co_author_cannot_edit() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Shared article").await;
+
+    let (status, _) = invite(app.clone(), &alice, &slug, "bob").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        Some(&bob),
+        Some(json!({ "article": { "body": "Not yet" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles?author=bob", None, None).await;
+    assert_eq!(body["articlesCount"], 0);
+
+    // Nobody accepts for the invitee, and there's nothing to accept without an invitation.
+    let (status, _) = accept(app.clone(), &carol, &slug, "bob").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = accept(app.clone(), &carol, &slug, "carol").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Declined invitations can't be accepted anymore.
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/co-authors/bob", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = accept(app.clone(), &bob, &slug, "bob").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_co_author_permission_errors() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Shared article").await;
+
+    for (username, token) in [("bob", &bob), ("carol", &carol)] {
+        invite(app.clone(), &alice, &slug, username).await;
+        let (status, _) = accept(app.clone(), token, &slug, username).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/co-authors/bob", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = invite(app.clone(), &alice, &slug, "nobody").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = invite(app.clone(), &alice, "missing-article", "bob").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Co-authors only remove themselves.
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/co-authors/carol", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/co-authors/alice", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_filter_articles_by_co_author() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Shared article").await;
+    create_article(app.clone(), &alice, "Solo article").await;
+
+    invite(app.clone(), &alice, &slug, "bob").await;
+    accept(app.clone(), &bob, &slug, "bob").await;
+
+    let (status, body) = send(app.clone(), "GET", "/api/articles?author=bob", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["articlesCount"], 1);
+    assert_eq!(body["articles"][0]["slug"], slug.as_str());
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles?author=alice", None, None).await;
+    assert_eq!(body["articlesCount"], 2);
+}
+
+#[tokio::test]
+async fn test_remove_co_author() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Shared article").await;
+
+    invite(app.clone(), &alice, &slug, "bob").await;
+    accept(app.clone(), &bob, &slug, "bob").await;
+
+    // Co-authors can step down themselves.
+    let (status, body) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/co-authors/bob", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["coAuthors"], json!([]));
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/co-authors/bob", slug),
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        Some(&bob),
+        Some(json!({ "article": { "body": "Too late" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,216 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::json;
+
+const MODERATOR_ID: &str = "00000000-0000-4000-8000-000000000001";
+const MODERATORS: &[(&str, &str)] = &[("ADMIN_USER_IDS", MODERATOR_ID)];
+
+async fn add_comment(app: axum::Router, token: &str, slug: &str) -> StatusCode {
+    let (status, _) = send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(token),
+        Some(json!({ "comment": { "body": "Hot take" } })),
+    )
+    .await;
+    status
+}
+
+#[tokio::test]
+async fn test_author_locks_and_unlocks_comments() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Heated article").await;
+    assert_eq!(
+        add_comment(app.clone(), &bob, &slug).await,
+        StatusCode::CREATED
+    );
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments/lock", slug),
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["commentsLocked"], true);
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&bob),
+        Some(json!({ "comment": { "body": "One more thing" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(
+        body["errors"]["body"][0],
+        "Comments are locked on this article"
+    );
+
+    // The author can't comment either, existing comments stay visible.
+    assert_eq!(
+        add_comment(app.clone(), &alice, &slug).await,
+        StatusCode::CONFLICT
+    );
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}/comments", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["comments"].as_array().unwrap().len(), 1);
+
+    let (status, body) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/comments/lock", slug),
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["commentsLocked"], false);
+
+    assert_eq!(
+        add_comment(app.clone(), &bob, &slug).await,
+        StatusCode::CREATED
+    );
+}
+
+#[tokio::test]
+async fn test_only_author_or_moderator_locks_comments() {
+    let (app, pool) = common::create_test_app_with_db(MODERATORS).await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let moderator = common::register_user_with_id(
+        app.clone(),
+        &pool,
+        MODERATOR_ID,
+        "moderator",
+        "moderator@example.com",
+        "password123",
+    )
+    .await;
+
+    let slug = create_article(app.clone(), &alice, "Moderated article").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments/lock", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments/lock", slug),
+        Some(&moderator),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["commentsLocked"], true);
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["commentsLocked"], true);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/articles/missing/comments/lock",
+        Some(&moderator),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_comment_lock_permission_errors() {
+    let (app, pool) = common::create_test_app_with_db(MODERATORS).await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+    let moderator = common::register_user_with_id(
+        app.clone(),
+        &pool,
+        MODERATOR_ID,
+        "moderator",
+        "moderator@example.com",
+        "password123",
+    )
+    .await;
+
+    let slug = create_article(app.clone(), &alice, "Moderated article").await;
+    let lock = format!("/api/articles/{}/comments/lock", slug);
+
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/co-authors/carol", slug),
+        Some(&alice),
+        None,
+    )
+    .await;
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/co-authors/carol/accept", slug),
+        Some(&carol),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Co-authors edit the article, but only its author locks comments.
+    let (status, _) = send(app.clone(), "POST", &lock, Some(&carol), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    for method in ["POST", "DELETE"] {
+        let (status, _) = send(app.clone(), method, &lock, None, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    let (status, _) = send(app.clone(), "POST", &lock, Some(&alice), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(app.clone(), "DELETE", &lock, Some(&bob), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(app.clone(), "DELETE", &lock, Some(&carol), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = send(app.clone(), "DELETE", &lock, Some(&moderator), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["commentsLocked"], false);
+
+    assert_eq!(
+        add_comment(app.clone(), &bob, &slug).await,
+        StatusCode::CREATED
+    );
+}
@@ -0,0 +1,212 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::json;
+
+async fn add_comments(app: axum::Router, token: &str, slug: &str, count: usize) -> Vec<String> {
+    let mut ids = Vec::new();
+
+    for i in 1..=count {
+        let (_, body) = send(
+            app.clone(),
+            "POST",
+            &format!("/api/articles/{}/comments", slug),
+            Some(token),
+            Some(json!({ "comment": { "body": format!("Comment {}", i) } })),
+        )
+        .await;
+        ids.push(body["comment"]["id"].as_str().unwrap().to_string());
+    }
+
+    ids
+}
+
+/// Follows `nextCursor` until the last page and returns the bodies of every page.
+async fn collect_pages(app: axum::Router, slug: &str, params: &str) -> Vec<Vec<String>> {
+    let mut pages = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    loop {
+        let uri = match &cursor {
+            Some(cursor) => format!(
+                "/api/articles/{}/comments?{}&cursor={}",
+                slug, params, cursor
+            ),
+            None => format!("/api/articles/{}/comments?{}", slug, params),
+        };
+
+        let (status, body) = send(app.clone(), "GET", &uri, None, None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        pages.push(
+            body["comments"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|comment| comment["body"].as_str().unwrap().to_string())
+                .collect(),
+        );
+
+        match body["nextCursor"].as_str() {
+            Some(next) => cursor = Some(next.to_string()),
+            None => return pages,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_comments_are_paged_newest_first_by_default() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let slug = create_article(app.clone(), &alice, "Busy article").await;
+
+    add_comments(app.clone(), &alice, &slug, 5).await;
+
+    let pages = collect_pages(app.clone(), &slug, "limit=2").await;
+    assert_eq!(
+        pages,
+        vec![
+            vec!["Comment 5", "Comment 4"],
+            vec!["Comment 3", "Comment 2"],
+            vec!["Comment 1"],
+        ]
+    );
+
+    let pages = collect_pages(app.clone(), &slug, "limit=3&sort=oldest").await;
+    assert_eq!(
+        pages,
+        vec![
+            vec!["Comment 1", "Comment 2", "Comment 3"],
+            vec!["Comment 4", "Comment 5"],
+        ]
+    );
+
+    // A full last page doesn't lead to an empty one.
+    let pages = collect_pages(app.clone(), &slug, "limit=5").await;
+    assert_eq!(pages.len(), 1);
+
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}/comments?sort=random", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_top_comments_are_sorted_by_reactions() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Debated article").await;
+    let ids = add_comments(app.clone(), &alice, &slug, 4).await;
+
+    for (token, id, kind) in [
+        (&alice, &ids[1], "like"),
+        (&bob, &ids[1], "like"),
+        (&bob, &ids[2], "funny"),
+    ] {
+        let (status, _) = send(
+            app.clone(),
+            "POST",
+            &format!("/api/articles/{}/comments/{}/reactions/{}", slug, id, kind),
+            Some(token),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // Ties are broken by the newest comment first.
+    let pages = collect_pages(app.clone(), &slug, "sort=top&limit=2").await;
+    assert_eq!(
+        pages,
+        vec![
+            vec!["Comment 2", "Comment 3"],
+            vec!["Comment 4", "Comment 1"],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_articles_show_comments_count() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Counted article").await;
+    create_article(app.clone(), &alice, "Quiet article").await;
+
+    let ids = add_comments(app.clone(), &alice, &slug, 3).await;
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/comments/{}", slug, ids[0]),
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["commentsCount"], 2);
+
+    let (status, body) = send(app.clone(), "GET", "/api/articles", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let counts: Vec<_> = body["articles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|article| {
+            (
+                article["slug"].as_str().unwrap(),
+                article["commentsCount"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(counts, vec![("quiet-article", 0), (slug.as_str(), 2)]);
+}
+
+#[tokio::test]
+async fn test_comment_page_size_is_capped() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let slug = create_article(app.clone(), &token, "Capped article").await;
+    add_comments(app.clone(), &token, &slug, 2).await;
+
+    let pages = collect_pages(app.clone(), &slug, "limit=100").await;
+    assert_eq!(pages, vec![vec!["Comment 2", "Comment 1"]]);
+
+    for limit in ["101", "18446744073709551615"] {
+        let (status, body) = send(
+            app.clone(),
+            "GET",
+            &format!("/api/articles/{}/comments?limit={}", slug, limit),
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body["errors"]["body"][0],
+            "limit cannot be greater than 100"
+        );
+    }
+
+    let (status, _) = send(app, "GET", "/api/articles?limit=101", None, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
@@ -1,10 +1,18 @@
+// Shared by every integration test binary, each of which only uses some of the helpers.
+#![allow(dead_code)]
+
 use axum::Router;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
 use rand::Rng;
 use realworld::app_config::AppConfig;
 use realworld::application::create_app_state;
 use realworld::http::router;
+use serde_json::{Value, json};
+use sqlx::PgPool;
 use sqlx::postgres::PgPoolOptions;
 use std::sync::Once;
+use tower::ServiceExt;
 use tracing::info;
 use tryphon::{Config, EnvOverrides};
 
@@ -20,6 +28,15 @@ pub fn init_tracing() {
 }
 
 pub async fn create_test_app() -> Router {
+    create_test_app_with_env(&[]).await
+}
+
+pub async fn create_test_app_with_env(env: &[(&str, &str)]) -> Router {
+    create_test_app_with_db(env).await.0
+}
+
+/// Also returns a pool on the test database, to set up what can't be done through the API.
+pub async fn create_test_app_with_db(env: &[(&str, &str)]) -> (Router, PgPool) {
     let db = TestDatabase::new("realworld".to_string(), "password".to_string())
         .await
         .unwrap();
@@ -28,17 +45,114 @@ pub async fn create_test_app() -> Router {
 
     env_overrides.set("DATABASE_NAME", &db.name);
 
+    for (key, value) in env {
+        env_overrides.set(key, value);
+    }
+
     let config = AppConfig::load().unwrap();
 
     let app_state = create_app_state(&config).await;
 
     init_tracing();
 
-    router(app_state)
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&db.url)
+        .await
+        .unwrap();
+
+    (router(app_state), pool)
+}
+
+/// Registers the user and returns their token.
+pub async fn register_user(app: Router, username: &str, email: &str, password: &str) -> String {
+    let payload = json!({
+        "user": {
+            "username": username,
+            "email": email,
+            "password": password
+        }
+    });
+
+    let (_, body) = send(app, "POST", "/api/users", None, Some(payload)).await;
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+/// Registers the user under a known id, e.g. one configured in `ADMIN_USER_IDS`, and returns
+/// their token.
+pub async fn register_user_with_id(
+    app: Router,
+    pool: &PgPool,
+    id: &str,
+    username: &str,
+    email: &str,
+    password: &str,
+) -> String {
+    register_user(app.clone(), username, email, password).await;
+
+    sqlx::query("UPDATE users SET id = $1::uuid WHERE username = $2")
+        .bind(id)
+        .bind(username)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    // The token of the registration still carries the generated id.
+    let payload = json!({ "user": { "email": email, "password": password } });
+    let (_, body) = send(app, "POST", "/api/users/login", None, Some(payload)).await;
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+/// Sends a JSON request, returning the status and the body, `Null` when it's not JSON.
+pub async fn send(
+    app: Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<Value>,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+
+    let body = match payload {
+        Some(payload) => {
+            request = request.header("content-type", "application/json");
+            Body::from(serde_json::to_string(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+    (status, body)
+}
+
+/// Creates an article and returns its slug.
+pub async fn create_article(app: Router, token: &str, title: &str) -> String {
+    let payload = json!({
+        "article": {
+            "title": title,
+            "description": "Test article",
+            "body": "Content"
+        }
+    });
+
+    let (_, body) = send(app, "POST", "/api/articles", Some(token), Some(payload)).await;
+    body["article"]["slug"].as_str().unwrap().to_string()
 }
 
 struct TestDatabase {
     name: String,
+    url: String,
 }
 
 fn random_string() -> String {
@@ -61,17 +175,19 @@ impl TestDatabase {
 
         info!("Created test database: {}", name);
 
+        let url = format!("postgresql://{}:{}@localhost:5432/{}", user, password, name);
+
         // Run migrations on the new database
         let test_db = PgPoolOptions::new()
             .max_connections(1)
-            .connect(format!("postgresql://{}:{}@localhost:5432/{}", user, password, name).as_str())
+            .connect(&url)
             .await?;
 
         sqlx::migrate!("./migrations").run(&test_db).await?;
 
         info!("Ran migrations on test database: {}", name);
 
-        Ok(TestDatabase { name })
+        Ok(TestDatabase { name, url })
     }
 }
 
@@ -0,0 +1,273 @@
+mod common;
+
+use axum::body::{Body, BodyDataStream};
+use axum::http::{Request, StatusCode};
+use common::{create_article, register_user, send};
+use serde_json::{Value, json};
+use std::time::Duration;
+use tokio_stream::StreamExt;
+use tower::ServiceExt;
+
+async fn subscribe(app: axum::Router, uri: &str, token: Option<&str>) -> BodyDataStream {
+    let mut request = Request::builder().method("GET").uri(uri);
+
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+
+    response.into_body().into_data_stream()
+}
+
+/// Reads the stream until the next event with a name, skipping keep-alive comments.
+async fn next_event(stream: &mut BodyDataStream) -> (String, Value) {
+    let mut buffer = String::new();
+
+    loop {
+        let chunk = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("Timed out waiting for an event")
+            .expect("Event stream ended")
+            .unwrap();
+
+        buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+
+        while let Some(end) = buffer.find("\n\n") {
+            let frame: String = buffer.drain(..end + 2).collect();
+
+            let mut name = None;
+            let mut data = None;
+
+            for line in frame.lines() {
+                if let Some(value) = line.strip_prefix("event: ") {
+                    name = Some(value.to_string());
+                } else if let Some(value) = line.strip_prefix("data: ") {
+                    data = Some(serde_json::from_str(value).unwrap());
+                }
+            }
+
+            if let (Some(name), Some(data)) = (name, data) {
+                return (name, data);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_article_events_stream_comments() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Live Article").await;
+
+    let mut events = subscribe(
+        app.clone(),
+        &format!("/api/articles/{}/events", slug),
+        Some(&author),
+    )
+    .await;
+
+    let (_, comment) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&reader),
+        Some(json!({ "comment": { "body": "First!" } })),
+    )
+    .await;
+    let comment_id = comment["comment"]["id"].clone();
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "commentCreated");
+    assert_eq!(data["comment"]["id"], comment_id);
+    assert_eq!(data["comment"]["body"], "First!");
+    assert_eq!(data["comment"]["author"]["username"], "reader");
+
+    let (status, _) = send(
+        app,
+        "DELETE",
+        &format!(
+            "/api/articles/{}/comments/{}",
+            slug,
+            comment_id.as_str().unwrap()
+        ),
+        Some(&reader),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "commentDeleted");
+    assert_eq!(data["id"], comment_id);
+}
+
+#[tokio::test]
+async fn test_article_events_stream_favorite_count() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Popular Article").await;
+
+    let mut events = subscribe(app.clone(), &format!("/api/articles/{}/events", slug), None).await;
+
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&reader),
+        None,
+    )
+    .await;
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "favoritesChanged");
+    assert_eq!(data["favoritesCount"], 1);
+
+    send(
+        app,
+        "DELETE",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&reader),
+        None,
+    )
+    .await;
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "favoritesChanged");
+    assert_eq!(data["favoritesCount"], 0);
+}
+
+#[tokio::test]
+async fn test_article_events_ignore_other_articles() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let watched = create_article(app.clone(), &author, "Watched Article").await;
+    let other = create_article(app.clone(), &author, "Other Article").await;
+
+    let mut events = subscribe(
+        app.clone(),
+        &format!("/api/articles/{}/events", watched),
+        None,
+    )
+    .await;
+
+    for slug in [&other, &watched] {
+        send(
+            app.clone(),
+            "POST",
+            &format!("/api/articles/{}/comments", slug),
+            Some(&author),
+            Some(json!({ "comment": { "body": format!("On {}", slug) } })),
+        )
+        .await;
+    }
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "commentCreated");
+    assert_eq!(data["comment"]["body"], format!("On {}", watched));
+}
+
+#[tokio::test]
+async fn test_article_events_for_unknown_article() {
+    let app = common::create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri("/api/articles/missing-article/events")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_notification_events_stream_only_own_notifications() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Followed Article").await;
+
+    let mut events = subscribe(app.clone(), "/api/notifications/events", Some(&author)).await;
+
+    // The author follows the reader first; that notification belongs to the reader.
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/reader/follow",
+        Some(&author),
+        None,
+    )
+    .await;
+    send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&reader),
+        None,
+    )
+    .await;
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "notificationCreated");
+    assert_eq!(data["notification"]["type"], "articleFavorited");
+    assert_eq!(data["notification"]["actor"]["username"], "reader");
+    assert_eq!(data["notification"]["actor"]["following"], true);
+    assert_eq!(data["notification"]["article"]["slug"], slug);
+}
+
+#[tokio::test]
+async fn test_notification_events_without_authentication_fails() {
+    let app = common::create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri("/api/notifications/events")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_postgres_backend_delivers_events() {
+    let app = common::create_test_app_with_env(&[("EVENTS_BACKEND", "postgres")]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Distributed Article").await;
+
+    let mut events = subscribe(app.clone(), &format!("/api/articles/{}/events", slug), None).await;
+
+    send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&reader),
+        Some(json!({ "comment": { "body": "Through NOTIFY" } })),
+    )
+    .await;
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "commentCreated");
+    assert_eq!(data["comment"]["body"], "Through NOTIFY");
+}
@@ -0,0 +1,597 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::{Value, json};
+
+fn usernames(body: &Value, list: &str, field: &str) -> Vec<String> {
+    body[list]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item[field].as_str().unwrap().to_string())
+        .collect()
+}
+
+async fn make_private(app: axum::Router, token: &str) {
+    let (status, body) = send(
+        app,
+        "PUT",
+        "/api/user",
+        Some(token),
+        Some(json!({ "user": { "private": true } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["private"], true);
+}
+
+#[tokio::test]
+async fn test_follow_private_user_sends_request() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let (_, body) = send(app.clone(), "GET", "/api/user", Some(&alice), None).await;
+    assert_eq!(body["user"]["private"], false);
+
+    make_private(app.clone(), &alice).await;
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profile"]["private"], true);
+    assert_eq!(body["profile"]["following"], false);
+    assert_eq!(body["profile"]["followRequested"], true);
+    assert_eq!(body["profile"]["followersCount"], 0);
+
+    let (_, body) = send(app.clone(), "GET", "/api/notifications", Some(&alice), None).await;
+    assert_eq!(body["notifications"][0]["type"], "followRequested");
+    assert_eq!(body["notifications"][0]["actor"]["username"], "bob");
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/follow-requests",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(usernames(&body, "profiles", "username"), ["bob"]);
+    assert_eq!(body["profilesCount"], 1);
+
+    // Unfollowing cancels the request.
+    let (_, body) = send(
+        app.clone(),
+        "DELETE",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(body["profile"]["followRequested"], false);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/follow-requests",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(body["profilesCount"], 0);
+}
+
+#[tokio::test]
+async fn test_approve_and_reject_follow_requests() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    make_private(app.clone(), &alice).await;
+
+    for token in [&bob, &carol] {
+        send(
+            app.clone(),
+            "POST",
+            "/api/profiles/alice/follow",
+            Some(token),
+            None,
+        )
+        .await;
+    }
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/follow-requests",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(usernames(&body, "profiles", "username"), ["carol", "bob"]);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/user/follow-requests/bob/approve",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        "/api/user/follow-requests/carol",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Both requests are gone.
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/user/follow-requests/carol/approve",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/alice/followers",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(usernames(&body, "profiles", "username"), ["bob"]);
+
+    let (_, body) = send(app.clone(), "GET", "/api/profiles/alice", Some(&bob), None).await;
+    assert_eq!(body["profile"]["following"], true);
+    assert_eq!(body["profile"]["followRequested"], false);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/alice",
+        Some(&carol),
+        None,
+    )
+    .await;
+    assert_eq!(body["profile"]["following"], false);
+    assert_eq!(body["profile"]["followRequested"], false);
+}
+
+#[tokio::test]
+async fn test_declined_follow_request() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    make_private(app.clone(), &alice).await;
+    let slug = create_article(app.clone(), &alice, "Private article").await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        "/api/user/follow-requests/bob",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Declining doesn't let the requester in, and can only be done once.
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/alice/followers",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(body["profilesCount"], 0);
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        "/api/user/follow-requests/bob",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // The requester may ask again.
+    let (_, body) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(body["profile"]["followRequested"], true);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/follow-requests",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(usernames(&body, "profiles", "username"), ["bob"]);
+}
+
+#[tokio::test]
+async fn test_private_articles_visible_to_approved_followers() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    make_private(app.clone(), &alice).await;
+    create_article(app.clone(), &alice, "Alice's article").await;
+    create_article(app.clone(), &carol, "Carol's article").await;
+
+    let titles = |body: Value| usernames(&body, "articles", "title");
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles", None, None).await;
+    assert_eq!(titles(body), ["Carol's article"]);
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles", Some(&bob), None).await;
+    assert_eq!(titles(body.clone()), ["Carol's article"]);
+    assert_eq!(body["articlesCount"], 1);
+
+    // Authors still see their own articles.
+    let (_, body) = send(app.clone(), "GET", "/api/articles", Some(&alice), None).await;
+    assert_eq!(titles(body).len(), 2);
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles/feed", Some(&bob), None).await;
+    assert!(titles(body).is_empty());
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/user/follow-requests/bob/approve",
+        Some(&alice),
+        None,
+    )
+    .await;
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles/feed", Some(&bob), None).await;
+    assert_eq!(titles(body), ["Alice's article"]);
+
+    let (_, body) = send(app.clone(), "GET", "/api/articles", Some(&bob), None).await;
+    assert_eq!(titles(body.clone()).len(), 2);
+    assert_eq!(body["articlesCount"], 2);
+}
+
+#[tokio::test]
+async fn test_private_article_not_found_for_others() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    make_private(app.clone(), &alice).await;
+    let slug = create_article(app.clone(), &alice, "Alice's article").await;
+
+    for token in [None, Some(bob.as_str())] {
+        for path in ["", "/comments", "/events"] {
+            let uri = format!("/api/articles/{}{}", slug, path);
+            let (status, _) = send(app.clone(), "GET", &uri, token, None).await;
+            assert_eq!(status, StatusCode::NOT_FOUND, "GET {}", uri);
+        }
+    }
+
+    for (uri, payload) in [
+        (format!("/api/articles/{}/favorite", slug), None),
+        (
+            format!("/api/articles/{}/bookmark", slug),
+            Some(json!({ "bookmark": {} })),
+        ),
+        (
+            format!("/api/articles/{}/comments", slug),
+            Some(json!({ "comment": { "body": "Hello" } })),
+        ),
+    ] {
+        let (status, _) = send(app.clone(), "POST", &uri, Some(&bob), payload).await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "POST {}", uri);
+    }
+
+    // Nothing was recorded along the way.
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(body["article"]["favoritesCount"], 0);
+    assert_eq!(body["article"]["commentsCount"], 0);
+
+    // Co-authors read the article without following its author.
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/co-authors/carol", slug),
+        Some(&alice),
+        None,
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/co-authors/carol/accept", slug),
+        Some(&carol),
+        None,
+    )
+    .await;
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        Some(&carol),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        "/api/user/follow-requests/bob/approve",
+        Some(&alice),
+        None,
+    )
+    .await;
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["title"], "Alice's article");
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&bob),
+        Some(json!({ "comment": { "body": "Hello" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+async fn create_tagged_article(app: axum::Router, token: &str, title: &str, tag: &str) -> String {
+    let payload = json!({
+        "article": {
+            "title": title,
+            "description": "Test article",
+            "body": "Content",
+            "tagList": [tag]
+        }
+    });
+
+    let (_, body) = send(app, "POST", "/api/articles", Some(token), Some(payload)).await;
+    body["article"]["slug"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_private_articles_hidden_from_trending() {
+    let app = common::create_test_app_with_env(&[("TRENDING_REFRESH_INTERVAL_MS", "50")]).await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    make_private(app.clone(), &alice).await;
+    let private_slug = create_article(app.clone(), &alice, "Alice's article").await;
+    let public_slug = create_article(app.clone(), &bob, "Bob's article").await;
+
+    for slug in [&private_slug, &public_slug] {
+        send(
+            app.clone(),
+            "POST",
+            &format!("/api/articles/{}/favorite", slug),
+            Some(&alice),
+            None,
+        )
+        .await;
+    }
+
+    // The author sees both once the trending view is refreshed.
+    let mut body = Value::Null;
+    for _ in 0..100 {
+        (_, body) = send(
+            app.clone(),
+            "GET",
+            "/api/articles/trending",
+            Some(&alice),
+            None,
+        )
+        .await;
+        if body["articlesCount"] == 2 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert_eq!(body["articles"].as_array().unwrap().len(), 2);
+
+    for token in [None, Some(bob.as_str())] {
+        let (status, body) = send(app.clone(), "GET", "/api/articles/trending", token, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(usernames(&body, "articles", "title"), ["Bob's article"]);
+        assert_eq!(body["articlesCount"], 1);
+    }
+}
+
+#[tokio::test]
+async fn test_private_articles_hidden_from_related() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    make_private(app.clone(), &alice).await;
+    create_tagged_article(app.clone(), &alice, "Alice's article", "rust").await;
+    let slug = create_tagged_article(app.clone(), &carol, "Carol's article", "rust").await;
+
+    let uri = format!("/api/articles/{}/related", slug);
+
+    for token in [None, Some(bob.as_str())] {
+        let (status, body) = send(app.clone(), "GET", &uri, token, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(usernames(&body, "articles", "title").is_empty());
+    }
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        "/api/user/follow-requests/bob/approve",
+        Some(&alice),
+        None,
+    )
+    .await;
+
+    let (_, body) = send(app.clone(), "GET", &uri, Some(&bob), None).await;
+    assert_eq!(usernames(&body, "articles", "title"), ["Alice's article"]);
+}
+
+#[tokio::test]
+async fn test_block_drops_follow_requests() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    make_private(app.clone(), &alice).await;
+
+    for token in [&bob, &carol] {
+        send(
+            app.clone(),
+            "POST",
+            "/api/profiles/alice/follow",
+            Some(token),
+            None,
+        )
+        .await;
+    }
+
+    // Blocking removes the request whichever side blocks.
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/bob/block",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/alice/block",
+        Some(&carol),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/follow-requests",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert!(usernames(&body, "profiles", "username").is_empty());
+
+    // Approval is refused while the block exists.
+    for requester in ["bob", "carol"] {
+        let (status, _) = send(
+            app.clone(),
+            "POST",
+            &format!("/api/user/follow-requests/{}/approve", requester),
+            Some(&alice),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/alice/followers",
+        None,
+        None,
+    )
+    .await;
+    assert!(usernames(&body, "profiles", "username").is_empty());
+}
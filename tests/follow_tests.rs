@@ -0,0 +1,202 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::Value;
+
+async fn follow(app: axum::Router, token: &str, username: &str) -> Value {
+    let (status, body) = send(
+        app,
+        "POST",
+        &format!("/api/profiles/{}/follow", username),
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    body
+}
+
+fn usernames(body: &Value) -> Vec<&str> {
+    body["profiles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["username"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_profile_counts() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "First").await;
+    create_article(app.clone(), &alice, "Second").await;
+
+    follow(app.clone(), &bob, "alice").await;
+    follow(app.clone(), &alice, "carol").await;
+    let body = follow(app.clone(), &carol, "alice").await;
+
+    assert_eq!(body["profile"]["following"], true);
+    assert_eq!(body["profile"]["followersCount"], 2);
+
+    let (status, body) = send(app.clone(), "GET", "/api/profiles/alice", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let profile = &body["profile"];
+    assert_eq!(profile["following"], false);
+    assert_eq!(profile["followersCount"], 2);
+    assert_eq!(profile["followingCount"], 1);
+    assert_eq!(profile["articlesCount"], 2);
+
+    let (_, body) = send(
+        app.clone(),
+        "DELETE",
+        "/api/profiles/alice/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(body["profile"]["following"], false);
+    assert_eq!(body["profile"]["followersCount"], 1);
+
+    // Authors embedded in articles don't carry counters.
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(body["article"]["author"]["username"], "alice");
+    assert!(body["article"]["author"].get("followersCount").is_none());
+}
+
+#[tokio::test]
+async fn test_list_followers() {
+    let app = common::create_test_app().await;
+    register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+    let dave = register_user(app.clone(), "dave", "dave@example.com", "password123").await;
+
+    follow(app.clone(), &bob, "alice").await;
+    follow(app.clone(), &carol, "alice").await;
+    follow(app.clone(), &dave, "alice").await;
+    follow(app.clone(), &dave, "carol").await;
+    follow(app.clone(), &bob, "carol").await;
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/alice/followers",
+        Some(&dave),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profilesCount"], 3);
+    assert_eq!(usernames(&body), vec!["dave", "carol", "bob"]);
+
+    let carol_profile = &body["profiles"][1];
+    assert_eq!(carol_profile["following"], true);
+    assert_eq!(carol_profile["followersCount"], 2);
+    assert_eq!(carol_profile["followingCount"], 1);
+    assert_eq!(carol_profile["articlesCount"], 0);
+    assert_eq!(body["profiles"][2]["following"], false);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/alice/followers?limit=1&offset=1",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(body["profilesCount"], 3);
+    assert_eq!(usernames(&body), vec!["carol"]);
+    assert_eq!(body["profiles"][0]["following"], false);
+}
+
+#[tokio::test]
+async fn test_list_following() {
+    let app = common::create_test_app().await;
+    register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    follow(app.clone(), &bob, "carol").await;
+    follow(app.clone(), &bob, "alice").await;
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/bob/following",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profilesCount"], 2);
+    assert_eq!(usernames(&body), vec!["alice", "carol"]);
+
+    send(
+        app.clone(),
+        "DELETE",
+        "/api/profiles/carol/follow",
+        Some(&bob),
+        None,
+    )
+    .await;
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/bob/following",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(body["profilesCount"], 1);
+    assert_eq!(usernames(&body), vec!["alice"]);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/alice/following",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(body["profilesCount"], 0);
+    assert!(usernames(&body).is_empty());
+}
+
+#[tokio::test]
+async fn test_follow_lists_of_unknown_profile() {
+    let app = common::create_test_app().await;
+
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/nobody/followers",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        "/api/profiles/nobody/following",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{register_user, send};
+use serde_json::json;
+
+async fn create_article(app: axum::Router, token: &str, title: &str, body: &str) -> String {
+    let payload = json!({
+        "article": {
+            "title": title,
+            "description": "Test article",
+            "body": body
+        }
+    });
+
+    let (_, body) = send(app, "POST", "/api/articles", Some(token), Some(payload)).await;
+    body["article"]["slug"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_get_article_as_html() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_article(
+        app.clone(),
+        &author,
+        "Markdown",
+        "# Title\n\nSome **bold** text.<script>alert(1)</script>\n\n## Next steps",
+    )
+    .await;
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}?format=html", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let article = &body["article"];
+    assert_eq!(
+        article["body"],
+        "# Title\n\nSome **bold** text.<script>alert(1)</script>\n\n## Next steps"
+    );
+    assert_eq!(
+        article["bodyHtml"],
+        "<h1 id=\"user-content-title\">Title</h1>\n<p>Some <strong>bold</strong> text.</p>\n<h2 id=\"user-content-next-steps\">Next steps</h2>\n"
+    );
+    assert_eq!(article["readingTimeMinutes"], 1);
+    assert_eq!(
+        article["toc"],
+        json!([
+            { "level": 1, "text": "Title", "anchor": "user-content-title" },
+            { "level": 2, "text": "Next steps", "anchor": "user-content-next-steps" }
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_get_article_as_markdown_by_default() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let body = format!("## Only heading\n\n{}", "word ".repeat(450));
+    let slug = create_article(app.clone(), &author, "Long read", &body).await;
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let article = &body["article"];
+    assert!(article.get("bodyHtml").is_none());
+    assert_eq!(article["readingTimeMinutes"], 3);
+    assert_eq!(article["toc"][0]["anchor"], "user-content-only-heading");
+
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}?format=pdf", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_rendered_body_follows_updates() {
+    let app = common::create_test_app_with_env(&[("MARKDOWN_CACHE_CAPACITY", "1")]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let first = create_article(app.clone(), &author, "First", "*first*").await;
+    let second = create_article(app.clone(), &author, "Second", "*second*").await;
+
+    let html = |slug: String| {
+        let app = app.clone();
+        async move {
+            let (_, body) = send(
+                app,
+                "GET",
+                &format!("/api/articles/{}?format=html", slug),
+                None,
+                None,
+            )
+            .await;
+            body["article"]["bodyHtml"].as_str().unwrap().to_string()
+        }
+    };
+
+    assert_eq!(html(first.clone()).await, "<p><em>first</em></p>\n");
+    assert_eq!(html(second.clone()).await, "<p><em>second</em></p>\n");
+    assert_eq!(html(first.clone()).await, "<p><em>first</em></p>\n");
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", first),
+        Some(&author),
+        Some(json!({ "article": { "body": "*updated*" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    assert_eq!(html(first.clone()).await, "<p><em>updated</em></p>\n");
+}
@@ -0,0 +1,329 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_comment_notifies_article_author() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Notified Article").await;
+
+    let (status, comment) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&reader),
+        Some(json!({ "comment": { "body": "Nice!" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = send(app, "GET", "/api/notifications", Some(&author), None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["notificationsCount"], 1);
+    assert_eq!(body["unreadCount"], 1);
+    assert_eq!(body["notifications"][0]["type"], "articleCommented");
+    assert_eq!(body["notifications"][0]["read"], false);
+    assert_eq!(body["notifications"][0]["actor"]["username"], "reader");
+    assert_eq!(body["notifications"][0]["article"]["slug"], slug);
+    assert_eq!(
+        body["notifications"][0]["commentId"],
+        comment["comment"]["id"]
+    );
+}
+
+#[tokio::test]
+async fn test_own_comment_does_not_notify() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Self Commented Article").await;
+
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&author),
+        Some(json!({ "comment": { "body": "Replying to myself" } })),
+    )
+    .await;
+
+    let (_, body) = send(app, "GET", "/api/notifications", Some(&author), None).await;
+
+    assert_eq!(body["notificationsCount"], 0);
+    assert_eq!(body["notifications"], json!([]));
+}
+
+#[tokio::test]
+async fn test_favorite_notifies_article_author_once() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Favorited Article").await;
+
+    for _ in 0..2 {
+        send(
+            app.clone(),
+            "POST",
+            &format!("/api/articles/{}/favorite", slug),
+            Some(&reader),
+            None,
+        )
+        .await;
+    }
+
+    let (_, body) = send(app, "GET", "/api/notifications", Some(&author), None).await;
+
+    assert_eq!(body["notificationsCount"], 1);
+    assert_eq!(body["notifications"][0]["type"], "articleFavorited");
+    assert_eq!(body["notifications"][0]["actor"]["username"], "reader");
+}
+
+#[tokio::test]
+async fn test_follow_notifies_followed_user() {
+    let app = common::create_test_app().await;
+    let followee = register_user(
+        app.clone(),
+        "followee",
+        "followee@example.com",
+        "password123",
+    )
+    .await;
+    let follower = register_user(
+        app.clone(),
+        "follower",
+        "follower@example.com",
+        "password123",
+    )
+    .await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/followee/follow",
+        Some(&follower),
+        None,
+    )
+    .await;
+
+    let (_, body) = send(app, "GET", "/api/notifications", Some(&followee), None).await;
+
+    assert_eq!(body["notificationsCount"], 1);
+    assert_eq!(body["notifications"][0]["type"], "userFollowed");
+    assert_eq!(body["notifications"][0]["actor"]["username"], "follower");
+    assert!(body["notifications"][0].get("article").is_none());
+}
+
+#[tokio::test]
+async fn test_notifications_are_paginated() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Busy Article").await;
+
+    for i in 0..3 {
+        send(
+            app.clone(),
+            "POST",
+            &format!("/api/articles/{}/comments", slug),
+            Some(&reader),
+            Some(json!({ "comment": { "body": format!("Comment {}", i) } })),
+        )
+        .await;
+    }
+
+    let (_, body) = send(
+        app,
+        "GET",
+        "/api/notifications?limit=2&offset=1",
+        Some(&author),
+        None,
+    )
+    .await;
+
+    assert_eq!(body["notifications"].as_array().unwrap().len(), 2);
+    assert_eq!(body["notificationsCount"], 3);
+    assert_eq!(body["unreadCount"], 3);
+}
+
+#[tokio::test]
+async fn test_mark_notification_as_read() {
+    let app = common::create_test_app().await;
+    let followee = register_user(
+        app.clone(),
+        "followee",
+        "followee@example.com",
+        "password123",
+    )
+    .await;
+    let follower = register_user(
+        app.clone(),
+        "follower",
+        "follower@example.com",
+        "password123",
+    )
+    .await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/followee/follow",
+        Some(&follower),
+        None,
+    )
+    .await;
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/notifications",
+        Some(&followee),
+        None,
+    )
+    .await;
+    let id = body["notifications"][0]["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/notifications/{}/read", id),
+        Some(&follower),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/notifications/{}/read", id),
+        Some(&followee),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, body) = send(app, "GET", "/api/notifications", Some(&followee), None).await;
+
+    assert_eq!(body["unreadCount"], 0);
+    assert_eq!(body["notificationsCount"], 1);
+    assert_eq!(body["notifications"][0]["read"], true);
+}
+
+#[tokio::test]
+async fn test_mark_all_notifications_as_read() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Read Everything").await;
+
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&reader),
+        None,
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/author/follow",
+        Some(&reader),
+        None,
+    )
+    .await;
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/notifications/read",
+        Some(&author),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, body) = send(app, "GET", "/api/notifications", Some(&author), None).await;
+
+    assert_eq!(body["notificationsCount"], 2);
+    assert_eq!(body["unreadCount"], 0);
+}
+
+#[tokio::test]
+async fn test_notification_preferences_default_to_enabled() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "user", "user@example.com", "password123").await;
+
+    let (status, body) = send(
+        app,
+        "GET",
+        "/api/notifications/preferences",
+        Some(&token),
+        None,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["preferences"],
+        json!({ "articleCommented": true, "articleFavorited": true, "userFollowed": true })
+    );
+}
+
+#[tokio::test]
+async fn test_disabled_preference_skips_recording() {
+    let app = common::create_test_app().await;
+    let followee = register_user(
+        app.clone(),
+        "followee",
+        "followee@example.com",
+        "password123",
+    )
+    .await;
+    let follower = register_user(
+        app.clone(),
+        "follower",
+        "follower@example.com",
+        "password123",
+    )
+    .await;
+
+    let (status, body) = send(
+        app.clone(),
+        "PUT",
+        "/api/notifications/preferences",
+        Some(&followee),
+        Some(json!({ "preferences": { "userFollowed": false } })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["preferences"]["userFollowed"], false);
+    assert_eq!(body["preferences"]["articleCommented"], true);
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/followee/follow",
+        Some(&follower),
+        None,
+    )
+    .await;
+
+    let (_, body) = send(app, "GET", "/api/notifications", Some(&followee), None).await;
+
+    assert_eq!(body["notificationsCount"], 0);
+}
+
+#[tokio::test]
+async fn test_list_notifications_without_authentication_fails() {
+    let app = common::create_test_app().await;
+
+    let (status, _) = send(app, "GET", "/api/notifications", None, None).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
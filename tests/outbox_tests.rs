@@ -0,0 +1,312 @@
+mod common;
+
+use axum::body::{Body, BodyDataStream, Bytes};
+use axum::extract::State;
+use axum::http::{Request, StatusCode};
+use axum::routing::post;
+use common::{create_article, register_user, send};
+use serde_json::{Value, json};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_stream::StreamExt;
+use tower::ServiceExt;
+
+// The webhook receivers listen on localhost.
+const LOCAL_WEBHOOKS: &[(&str, &str)] = &[("WEBHOOKS_ALLOW_PRIVATE_HOSTS", "true")];
+
+async fn subscribe(app: axum::Router, uri: &str, token: Option<&str>) -> BodyDataStream {
+    let mut request = Request::builder().method("GET").uri(uri);
+
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+
+    response.into_body().into_data_stream()
+}
+
+/// Reads the stream until the next event with a name, skipping keep-alive comments.
+async fn next_event(stream: &mut BodyDataStream) -> (String, Value) {
+    let mut buffer = String::new();
+
+    loop {
+        let chunk = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("Timed out waiting for an event")
+            .expect("Event stream ended")
+            .unwrap();
+
+        buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+
+        while let Some(end) = buffer.find("\n\n") {
+            let frame: String = buffer.drain(..end + 2).collect();
+
+            let mut name = None;
+            let mut data = None;
+
+            for line in frame.lines() {
+                if let Some(value) = line.strip_prefix("event: ") {
+                    name = Some(value.to_string());
+                } else if let Some(value) = line.strip_prefix("data: ") {
+                    data = Some(serde_json::from_str(value).unwrap());
+                }
+            }
+
+            if let (Some(name), Some(data)) = (name, data) {
+                return (name, data);
+            }
+        }
+    }
+}
+
+/// Fails if the stream carries an event with a name within `duration`.
+async fn assert_no_event(stream: &mut BodyDataStream, duration: Duration) {
+    let deadline = tokio::time::Instant::now() + duration;
+
+    while let Ok(chunk) = tokio::time::timeout_at(deadline, stream.next()).await {
+        let chunk = chunk.expect("Event stream ended").unwrap();
+        let frame = std::str::from_utf8(&chunk).unwrap();
+
+        assert!(!frame.contains("event: "), "Unexpected event: {}", frame);
+    }
+}
+
+/// Starts a local HTTP server forwarding the body of every webhook request.
+async fn start_receiver() -> (String, mpsc::UnboundedReceiver<Bytes>) {
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let router = axum::Router::new()
+            .route(
+                "/hook",
+                post(
+                    |State(sender): State<mpsc::UnboundedSender<Bytes>>, body: Bytes| async move {
+                        sender.send(body).unwrap();
+                        StatusCode::OK
+                    },
+                ),
+            )
+            .with_state(sender);
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    (format!("http://{}/hook", address), receiver)
+}
+
+async fn next_payload(receiver: &mut mpsc::UnboundedReceiver<Bytes>) -> Value {
+    let body = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+        .await
+        .expect("Timed out waiting for a webhook request")
+        .unwrap();
+
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_committed_events_reach_every_sink() {
+    let app = common::create_test_app_with_env(LOCAL_WEBHOOKS).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let (url, mut receiver) = start_receiver().await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/webhooks",
+        Some(&author),
+        Some(json!({ "webhook": { "url": url, "events": ["comment.created"] } })),
+    )
+    .await;
+
+    let slug = create_article(app.clone(), &author, "Outboxed Article").await;
+    let mut events = subscribe(app.clone(), &format!("/api/articles/{}/events", slug), None).await;
+
+    send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&reader),
+        Some(json!({ "comment": { "body": "Both ways" } })),
+    )
+    .await;
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "commentCreated");
+    assert_eq!(data["comment"]["body"], "Both ways");
+
+    let payload = next_payload(&mut receiver).await;
+    assert_eq!(payload["event"], "comment.created");
+    assert_eq!(payload["data"]["comment"]["body"], "Both ways");
+}
+
+#[tokio::test]
+async fn test_events_are_published_in_commit_order() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Ordered Article").await;
+
+    let mut events = subscribe(app.clone(), &format!("/api/articles/{}/events", slug), None).await;
+
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&reader),
+        None,
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&reader),
+        Some(json!({ "comment": { "body": "In between" } })),
+    )
+    .await;
+    send(
+        app,
+        "DELETE",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&reader),
+        None,
+    )
+    .await;
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "favoritesChanged");
+    assert_eq!(data["favoritesCount"], 1);
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "commentCreated");
+    assert_eq!(data["comment"]["body"], "In between");
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "favoritesChanged");
+    assert_eq!(data["favoritesCount"], 0);
+}
+
+#[tokio::test]
+async fn test_failed_operation_publishes_nothing() {
+    let app = common::create_test_app_with_env(LOCAL_WEBHOOKS).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let (url, mut receiver) = start_receiver().await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/webhooks",
+        Some(&author),
+        Some(json!({ "webhook": { "url": url, "events": ["article.updated"] } })),
+    )
+    .await;
+
+    let slug = create_article(app.clone(), &author, "Stable Article").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        Some(&author),
+        Some(json!({ "article": {} })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    send(
+        app,
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        Some(&author),
+        Some(json!({ "article": { "body": "Actually updated" } })),
+    )
+    .await;
+
+    let payload = next_payload(&mut receiver).await;
+    assert_eq!(payload["event"], "article.updated");
+    assert_eq!(payload["data"]["article"]["body"], "Actually updated");
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(receiver.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_retried_events_are_broadcast_once() {
+    let (app, pool) = common::create_test_app_with_db(&[
+        ("OUTBOX_POLL_INTERVAL_MS", "50"),
+        ("OUTBOX_MAX_ATTEMPTS", "100"),
+    ])
+    .await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Retried Article").await;
+
+    let mut events = subscribe(app.clone(), &format!("/api/articles/{}/events", slug), None).await;
+
+    // The webhooks sink fails the event until its table is back.
+    sqlx::query("ALTER TABLE webhooks RENAME TO webhooks_unavailable")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&reader),
+        Some(json!({ "comment": { "body": "Only once" } })),
+    )
+    .await;
+
+    assert_no_event(&mut events, Duration::from_millis(300)).await;
+
+    sqlx::query("ALTER TABLE webhooks_unavailable RENAME TO webhooks")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let (name, data) = next_event(&mut events).await;
+    assert_eq!(name, "commentCreated");
+    assert_eq!(data["comment"]["body"], "Only once");
+
+    assert_no_event(&mut events, Duration::from_millis(300)).await;
+}
+
+#[tokio::test]
+async fn test_processed_events_are_deleted() {
+    let (app, pool) = common::create_test_app_with_db(&[
+        ("OUTBOX_RETENTION_SECS", "0"),
+        ("OUTBOX_CLEANUP_INTERVAL_MS", "50"),
+    ])
+    .await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    create_article(app.clone(), &author, "Published Article").await;
+
+    let mut count = -1;
+    for _ in 0..100 {
+        count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM outbox_events")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        if count == 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,301 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_article_reactions_are_counted_per_kind() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Reactive article").await;
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["reactions"], json!([]));
+
+    for (token, kind) in [
+        (&bob, "insightful"),
+        (&carol, "insightful"),
+        (&carol, "funny"),
+    ] {
+        let (status, _) = send(
+            app.clone(),
+            "POST",
+            &format!("/api/articles/{}/reactions/{}", slug, kind),
+            Some(token),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // Reacting twice with the same kind is a no-op.
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/reactions/insightful", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["article"]["reactions"],
+        json!([
+            { "kind": "funny", "count": 1, "reacted": false },
+            { "kind": "insightful", "count": 2, "reacted": true }
+        ])
+    );
+    assert_eq!(body["article"]["favoritesCount"], 0);
+
+    let (status, body) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/reactions/insightful", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["article"]["reactions"],
+        json!([
+            { "kind": "funny", "count": 1, "reacted": false },
+            { "kind": "insightful", "count": 1, "reacted": false }
+        ])
+    );
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/reactions/angry", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/articles/missing/reactions/funny",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_favorite_is_the_like_reaction() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Liked article").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/reactions/like", slug),
+        Some(&carol),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["favorited"], true);
+    assert_eq!(body["article"]["favoritesCount"], 2);
+    assert_eq!(
+        body["article"]["reactions"],
+        json!([{ "kind": "like", "count": 2, "reacted": true }])
+    );
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        "/api/articles?favorited=carol",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["articlesCount"], 1);
+
+    let (status, body) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&carol),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["favorited"], false);
+    assert_eq!(body["article"]["favoritesCount"], 1);
+    assert_eq!(
+        body["article"]["reactions"],
+        json!([{ "kind": "like", "count": 1, "reacted": false }])
+    );
+}
+
+#[tokio::test]
+async fn test_comment_reactions() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Commented article").await;
+
+    let (_, body) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&alice),
+        Some(json!({ "comment": { "body": "First!" } })),
+    )
+    .await;
+    let comment_id = body["comment"]["id"].as_str().unwrap().to_string();
+    assert_eq!(body["comment"]["reactions"], json!([]));
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        &format!(
+            "/api/articles/{}/comments/{}/reactions/funny",
+            slug, comment_id
+        ),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["comment"]["reactions"],
+        json!([{ "kind": "funny", "count": 1, "reacted": true }])
+    );
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["comments"][0]["reactions"],
+        json!([{ "kind": "funny", "count": 1, "reacted": false }])
+    );
+
+    let (status, body) = send(
+        app.clone(),
+        "DELETE",
+        &format!(
+            "/api/articles/{}/comments/{}/reactions/funny",
+            slug, comment_id
+        ),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["comment"]["reactions"], json!([]));
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!(
+            "/api/articles/{}/comments/00000000-0000-0000-0000-000000000000/reactions/funny",
+            slug
+        ),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_comment_reactions_respect_privacy_and_blocks() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+    let carol = register_user(app.clone(), "carol", "carol@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Commented article").await;
+
+    let (_, body) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&carol),
+        Some(json!({ "comment": { "body": "First!" } })),
+    )
+    .await;
+    let uri = format!(
+        "/api/articles/{}/comments/{}/reactions/funny",
+        slug,
+        body["comment"]["id"].as_str().unwrap()
+    );
+
+    let (status, _) = send(app.clone(), "POST", &uri, Some(&bob), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Blocked by the commenter, bob can only take his reaction back.
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/bob/block",
+        Some(&carol),
+        None,
+    )
+    .await;
+
+    let (status, _) = send(app.clone(), "POST", &uri, Some(&bob), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = send(app.clone(), "DELETE", &uri, Some(&bob), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["comment"]["reactions"], json!([]));
+
+    // Once the article is private, the comment is out of reach for those who can't read it.
+    send(
+        app.clone(),
+        "PUT",
+        "/api/user",
+        Some(&alice),
+        Some(json!({ "user": { "private": true } })),
+    )
+    .await;
+
+    for method in ["POST", "DELETE"] {
+        let (status, _) = send(app.clone(), method, &uri, Some(&carol), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    let (status, _) = send(app.clone(), "POST", &uri, Some(&alice), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
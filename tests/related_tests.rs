@@ -0,0 +1,143 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{register_user, send};
+use serde_json::json;
+
+async fn create_article(app: axum::Router, token: &str, title: &str, tags: &[&str]) -> String {
+    let payload = json!({
+        "article": {
+            "title": title,
+            "description": "Test article",
+            "body": "Content",
+            "tagList": tags
+        }
+    });
+
+    let (_, body) = send(app, "POST", "/api/articles", Some(token), Some(payload)).await;
+    body["article"]["slug"].as_str().unwrap().to_string()
+}
+
+async fn favorite(app: axum::Router, token: &str, slug: &str) {
+    let (status, _) = send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+async fn related_titles(app: axum::Router, uri: &str, token: Option<&str>) -> Vec<String> {
+    let (status, body) = send(app, "GET", uri, token, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let titles: Vec<String> = body["articles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["title"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(body["articlesCount"], titles.len());
+
+    titles
+}
+
+#[tokio::test]
+async fn test_related_articles_rank_rare_shared_tags_first() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Source", &["rust", "niche"]).await;
+    create_article(app.clone(), &author, "Common 1", &["rust"]).await;
+    create_article(app.clone(), &author, "Common 2", &["rust"]).await;
+    create_article(app.clone(), &author, "Rare", &["niche"]).await;
+    create_article(app.clone(), &author, "Unrelated", &["golang"]).await;
+
+    let titles = related_titles(
+        app.clone(),
+        &format!("/api/articles/{}/related", slug),
+        None,
+    )
+    .await;
+
+    assert_eq!(titles, vec!["Rare", "Common 2", "Common 1"]);
+}
+
+#[tokio::test]
+async fn test_related_articles_include_co_favorited_articles() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader1 = register_user(app.clone(), "reader1", "reader1@example.com", "password123").await;
+    let reader2 = register_user(app.clone(), "reader2", "reader2@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Source", &[]).await;
+    let once = create_article(app.clone(), &author, "Once", &[]).await;
+    let twice = create_article(app.clone(), &author, "Twice", &[]).await;
+    create_article(app.clone(), &author, "Unrelated", &[]).await;
+
+    favorite(app.clone(), &reader1, &slug).await;
+    favorite(app.clone(), &reader2, &slug).await;
+    favorite(app.clone(), &reader1, &twice).await;
+    favorite(app.clone(), &reader2, &twice).await;
+    favorite(app.clone(), &reader1, &once).await;
+
+    let titles = related_titles(
+        app.clone(),
+        &format!("/api/articles/{}/related", slug),
+        None,
+    )
+    .await;
+
+    assert_eq!(titles, vec!["Twice", "Once"]);
+}
+
+#[tokio::test]
+async fn test_related_articles_exclude_readers_own_articles() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Source", &["rust"]).await;
+    create_article(app.clone(), &author, "By author", &["rust"]).await;
+    create_article(app.clone(), &reader, "By reader", &["rust"]).await;
+
+    let uri = format!("/api/articles/{}/related", slug);
+
+    let titles = related_titles(app.clone(), &uri, Some(&reader)).await;
+    assert_eq!(titles, vec!["By author"]);
+
+    let titles = related_titles(app.clone(), &uri, None).await;
+    assert_eq!(titles, vec!["By reader", "By author"]);
+}
+
+#[tokio::test]
+async fn test_related_articles_limit() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Source", &["rust"]).await;
+    for i in 0..3 {
+        create_article(app.clone(), &author, &format!("Related {}", i), &["rust"]).await;
+    }
+
+    let titles = related_titles(
+        app.clone(),
+        &format!("/api/articles/{}/related?limit=2", slug),
+        None,
+    )
+    .await;
+
+    assert_eq!(titles, vec!["Related 2", "Related 1"]);
+}
+
+#[tokio::test]
+async fn test_related_articles_of_missing_article() {
+    let app = common::create_test_app().await;
+
+    let (status, _) = send(app, "GET", "/api/articles/missing/related", None, None).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
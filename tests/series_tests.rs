@@ -0,0 +1,304 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{create_article, register_user, send};
+use serde_json::{Value, json};
+
+fn slugs(body: &Value) -> Vec<&str> {
+    body["series"]["articles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|article| article["slug"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_create_series() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+
+    let part1 = create_article(app.clone(), &alice, "Rust web apps part 1").await;
+    let part2 = create_article(app.clone(), &alice, "Rust web apps part 2").await;
+    let part3 = create_article(app.clone(), &alice, "Rust web apps part 3").await;
+    let other = create_article(app.clone(), &alice, "Unrelated article").await;
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/series",
+        Some(&alice),
+        Some(json!({
+            "series": {
+                "title": "Rust web apps",
+                "description": "From an empty project to production",
+                "articles": [part1, part2, part3]
+            }
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["series"]["slug"], "rust-web-apps");
+    assert_eq!(body["series"]["author"]["username"], "alice");
+    assert_eq!(body["series"]["articlesCount"], 3);
+
+    let (status, body) = send(app.clone(), "GET", "/api/series/rust-web-apps", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(slugs(&body), vec![&part1, &part2, &part3]);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", part2),
+        None,
+        None,
+    )
+    .await;
+    let series = &body["article"]["series"];
+    assert_eq!(series["slug"], "rust-web-apps");
+    assert_eq!(series["position"], 2);
+    assert_eq!(series["articlesCount"], 3);
+    assert_eq!(series["previous"]["slug"], part1.as_str());
+    assert_eq!(series["next"]["slug"], part3.as_str());
+    assert_eq!(series["next"]["title"], "Rust web apps part 3");
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", part1),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(body["article"]["series"]["previous"], Value::Null);
+    assert_eq!(body["article"]["series"]["next"]["slug"], part2.as_str());
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", other),
+        None,
+        None,
+    )
+    .await;
+    assert!(body["article"].get("series").is_none());
+
+    let (status, _) = send(app.clone(), "GET", "/api/series/missing", None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_update_series_articles() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let part1 = create_article(app.clone(), &alice, "Part 1").await;
+    let part2 = create_article(app.clone(), &alice, "Part 2").await;
+    let part3 = create_article(app.clone(), &alice, "Part 3").await;
+    let bobs = create_article(app.clone(), &bob, "Bob's article").await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/series",
+        Some(&alice),
+        Some(json!({ "series": { "title": "Tutorial", "articles": [part1, part2] } })),
+    )
+    .await;
+
+    let (status, body) = send(
+        app.clone(),
+        "PUT",
+        "/api/series/tutorial",
+        Some(&alice),
+        Some(json!({ "series": { "articles": [part3, part1] } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(slugs(&body), vec![&part3, &part1]);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", part2),
+        None,
+        None,
+    )
+    .await;
+    assert!(body["article"].get("series").is_none());
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        "/api/series/tutorial",
+        Some(&bob),
+        Some(json!({ "series": { "title": "Taken over" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        "/api/series/tutorial",
+        Some(&alice),
+        Some(json!({ "series": { "articles": [part1, bobs] } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        "/api/series/tutorial",
+        Some(&alice),
+        Some(json!({ "series": { "articles": [part1, part1] } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // An article belongs to one series at most.
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/series",
+        Some(&alice),
+        Some(json!({ "series": { "title": "Another tutorial", "articles": [part3] } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_delete_series() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let part1 = create_article(app.clone(), &alice, "Part 1").await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/series",
+        Some(&alice),
+        Some(json!({ "series": { "title": "Tutorial", "articles": [part1] } })),
+    )
+    .await;
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        "/api/series/tutorial",
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        "/api/series/tutorial",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(app.clone(), "GET", "/api/series/tutorial", None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", part1),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["article"].get("series").is_none());
+}
+
+#[tokio::test]
+async fn test_series_navigation_skips_unreadable_articles() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let part1 = create_article(app.clone(), &alice, "Private series part 1").await;
+    let part2 = create_article(app.clone(), &alice, "Private series part 2").await;
+    let part3 = create_article(app.clone(), &alice, "Private series part 3").await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/series",
+        Some(&alice),
+        Some(json!({
+            "series": {
+                "title": "Private series",
+                "description": "Only for followers",
+                "articles": [part1, part2, part3]
+            }
+        })),
+    )
+    .await;
+
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/co-authors/bob", part2),
+        Some(&alice),
+        None,
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/co-authors/bob/accept", part2),
+        Some(&bob),
+        None,
+    )
+    .await;
+
+    send(
+        app.clone(),
+        "PUT",
+        "/api/user",
+        Some(&alice),
+        Some(json!({ "user": { "private": true } })),
+    )
+    .await;
+
+    // Bob reads the article he co-authors, but none of the others.
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", part2),
+        Some(&bob),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let series = &body["article"]["series"];
+    assert_eq!(series["position"], 1);
+    assert_eq!(series["articlesCount"], 1);
+    assert_eq!(series["previous"], Value::Null);
+    assert_eq!(series["next"], Value::Null);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", part2),
+        Some(&alice),
+        None,
+    )
+    .await;
+    let series = &body["article"]["series"];
+    assert_eq!(series["position"], 2);
+    assert_eq!(series["previous"]["slug"], part1.as_str());
+    assert_eq!(series["next"]["slug"], part3.as_str());
+}
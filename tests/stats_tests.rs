@@ -0,0 +1,306 @@
+mod common;
+
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{Request, StatusCode};
+use common::{create_article, register_user, send};
+use serde_json::json;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tower::ServiceExt;
+
+const FAST_FLUSH: (&str, &str) = ("VIEWS_FLUSH_INTERVAL_MS", "50");
+
+/// Reads the article as an anonymous client connected from `peer`, through a proxy reporting
+/// `forwarded_for` when given.
+async fn view_anonymously(app: axum::Router, slug: &str, peer: &str, forwarded_for: Option<&str>) {
+    let peer: SocketAddr = format!("{}:4000", peer).parse().unwrap();
+
+    let mut request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/articles/{}", slug))
+        .extension(ConnectInfo(peer));
+
+    if let Some(forwarded_for) = forwarded_for {
+        request = request.header("x-forwarded-for", forwarded_for);
+    }
+
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+async fn view(app: axum::Router, slug: &str, token: &str) {
+    let (status, _) = send(
+        app,
+        "GET",
+        &format!("/api/articles/{}", slug),
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+/// Waits for the buffered views to be flushed until the article shows `expected` views, and
+/// returns the last count seen.
+async fn views_count(app: axum::Router, slug: &str, expected: i64) -> i64 {
+    let mut count = -1;
+
+    for _ in 0..100 {
+        let (_, body) = send(
+            app.clone(),
+            "GET",
+            &format!("/api/articles/{}", slug),
+            None,
+            None,
+        )
+        .await;
+        count = body["article"]["viewsCount"].as_i64().unwrap();
+
+        if count == expected {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    count
+}
+
+#[tokio::test]
+async fn test_views_are_counted_once_per_viewer() {
+    let app = common::create_test_app_with_env(&[FAST_FLUSH]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader1 = register_user(app.clone(), "reader1", "reader1@example.com", "password123").await;
+    let reader2 = register_user(app.clone(), "reader2", "reader2@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Article").await;
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", slug),
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(body["article"]["viewsCount"], 0);
+
+    view(app.clone(), &slug, &reader1).await;
+    view(app.clone(), &slug, &reader1).await;
+    view(app.clone(), &slug, &reader2).await;
+    view_anonymously(app.clone(), &slug, "10.0.0.1", None).await;
+    view_anonymously(app.clone(), &slug, "10.0.0.1", None).await;
+    view_anonymously(app.clone(), &slug, "10.0.0.2", None).await;
+    // Neither the author nor spoofed forwarded addresses count.
+    view(app.clone(), &slug, &author).await;
+    view_anonymously(app.clone(), &slug, "10.0.0.1", Some("10.0.0.3")).await;
+
+    assert_eq!(views_count(app.clone(), &slug, 4).await, 4);
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(views_count(app.clone(), &slug, 4).await, 4);
+}
+
+#[tokio::test]
+async fn test_views_use_forwarded_for_behind_trusted_proxy() {
+    let app =
+        common::create_test_app_with_env(&[FAST_FLUSH, ("HTTP_TRUST_FORWARDED_FOR", "true")]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Article").await;
+
+    view_anonymously(app.clone(), &slug, "10.0.0.1", Some("192.168.1.1")).await;
+    view_anonymously(app.clone(), &slug, "10.0.0.1", Some("192.168.1.2")).await;
+    // Entries before the one the proxy appended are up to the client.
+    for spoofed in ["10.0.0.5, 192.168.1.2", "10.0.0.6, 192.168.1.1"] {
+        view_anonymously(app.clone(), &slug, "10.0.0.1", Some(spoofed)).await;
+    }
+
+    assert_eq!(views_count(app.clone(), &slug, 2).await, 2);
+}
+
+#[tokio::test]
+async fn test_views_are_counted_again_in_the_next_window() {
+    let app =
+        common::create_test_app_with_env(&[FAST_FLUSH, ("VIEWS_DEDUP_WINDOW_SECS", "1")]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Article").await;
+
+    view(app.clone(), &slug, &reader).await;
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    view(app.clone(), &slug, &reader).await;
+
+    assert_eq!(views_count(app.clone(), &slug, 2).await, 2);
+}
+
+#[tokio::test]
+async fn test_views_are_kept_while_flushing_fails() {
+    let (app, pool) =
+        common::create_test_app_with_db(&[FAST_FLUSH, ("VIEWS_MAX_PENDING", "2")]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Article").await;
+
+    sqlx::query("ALTER TABLE article_views RENAME TO article_views_unavailable")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Only the first two views fit in the buffer while the flushes fail.
+    for peer in ["10.0.0.1", "10.0.0.2", "10.0.0.3"] {
+        view_anonymously(app.clone(), &slug, peer, None).await;
+    }
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(views_count(app.clone(), &slug, 0).await, 0);
+
+    sqlx::query("ALTER TABLE article_views_unavailable RENAME TO article_views")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(views_count(app.clone(), &slug, 2).await, 2);
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(views_count(app.clone(), &slug, 2).await, 2);
+}
+
+#[tokio::test]
+async fn test_viewer_ips_are_not_stored_and_old_views_are_deleted() {
+    let (app, pool) =
+        common::create_test_app_with_db(&[FAST_FLUSH, ("VIEWS_CLEANUP_INTERVAL_MS", "50")]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &author, "Article").await;
+
+    sqlx::query(
+        "INSERT INTO article_views (article_id, viewer, window_start, viewed_at)
+         SELECT id, 'user:old', NOW() - INTERVAL '400 days', NOW() - INTERVAL '400 days'
+         FROM articles WHERE slug = $1",
+    )
+    .bind(&slug)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    view_anonymously(app.clone(), &slug, "10.0.0.1", None).await;
+    assert_eq!(views_count(app.clone(), &slug, 1).await, 1);
+
+    let mut viewers: Vec<String> = Vec::new();
+    for _ in 0..100 {
+        viewers = sqlx::query_scalar("SELECT viewer FROM article_views")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+
+        if viewers.len() == 1 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    assert_eq!(viewers.len(), 1);
+    assert!(viewers[0].starts_with("ip:"));
+    assert!(!viewers[0].contains("10.0.0.1"));
+}
+
+#[tokio::test]
+async fn test_author_stats() {
+    let app = common::create_test_app_with_env(&[FAST_FLUSH]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    let first = create_article(app.clone(), &author, "First").await;
+    let second = create_article(app.clone(), &author, "Second").await;
+    create_article(app.clone(), &reader, "Not mine").await;
+
+    view(app.clone(), &first, &reader).await;
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/favorite", first),
+        Some(&reader),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", second),
+        Some(&reader),
+        Some(json!({ "comment": { "body": "Nice" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    assert_eq!(views_count(app.clone(), &first, 1).await, 1);
+
+    let (status, body) = send(app.clone(), "GET", "/api/user/stats", Some(&author), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let stats = &body["stats"];
+    assert_eq!(stats["views"], 1);
+    assert_eq!(stats["favorites"], 1);
+    assert_eq!(stats["comments"], 1);
+
+    let articles = stats["articles"].as_array().unwrap();
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0]["slug"], second);
+    assert_eq!(articles[0]["viewsCount"], 0);
+    assert_eq!(articles[0]["commentsCount"], 1);
+    assert_eq!(articles[1]["slug"], first);
+    assert_eq!(articles[1]["viewsCount"], 1);
+    assert_eq!(articles[1]["favoritesCount"], 1);
+    assert_eq!(articles[1]["commentsCount"], 0);
+
+    let today = chrono::Utc::now().date_naive().to_string();
+    assert_eq!(
+        articles[1]["daily"],
+        json!([{ "date": today, "views": 1, "favorites": 1, "comments": 0 }])
+    );
+    assert_eq!(
+        articles[0]["daily"],
+        json!([{ "date": today, "views": 0, "favorites": 0, "comments": 1 }])
+    );
+
+    let (_, body) = send(app.clone(), "GET", "/api/user/stats", Some(&reader), None).await;
+    let articles = body["stats"]["articles"].as_array().unwrap();
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0]["title"], "Not mine");
+    assert_eq!(articles[0]["daily"], json!([]));
+}
+
+#[tokio::test]
+async fn test_stats_errors() {
+    let app = common::create_test_app().await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let (status, _) = send(app.clone(), "GET", "/api/user/stats", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        "/api/user/stats?days=0",
+        Some(&author),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        "/api/user/stats?days=7",
+        Some(&author),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["stats"]["articles"], json!([]));
+}
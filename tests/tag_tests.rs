@@ -2,7 +2,7 @@ mod common;
 
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
-use serde_json::json;
+use serde_json::{Value, json};
 use tower::ServiceExt;
 
 async fn register_user(app: axum::Router, username: &str, email: &str, password: &str) -> String {
@@ -235,3 +235,485 @@ async fn test_get_tags_returns_unique_tags() {
     let duplicate_count = tag_strings.iter().filter(|t| *t == "duplicate").count();
     assert_eq!(duplicate_count, 1);
 }
+
+async fn send(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: &str,
+    payload: Option<Value>,
+) -> (StatusCode, Value) {
+    let body = match payload {
+        Some(payload) => Body::from(serde_json::to_string(&payload).unwrap()),
+        None => Body::empty(),
+    };
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .header("authorization", format!("Token {}", token))
+                .body(body)
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+    (status, body)
+}
+
+async fn create_tagged_article(
+    app: axum::Router,
+    token: &str,
+    title: &str,
+    tags: &[&str],
+) -> String {
+    let payload = json!({
+        "article": {
+            "title": title,
+            "description": "Test article",
+            "body": "Content",
+            "tagList": tags
+        }
+    });
+
+    let (status, body) = send(app, "POST", "/api/articles", token, Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    body["article"]["slug"].as_str().unwrap().to_string()
+}
+
+const ADMIN_ID: &str = "00000000-0000-4000-8000-000000000001";
+const MODERATOR_ID: &str = "00000000-0000-4000-8000-000000000002";
+const ADMINS: &[(&str, &str)] = &[(
+    "ADMIN_USER_IDS",
+    "00000000-0000-4000-8000-000000000001, 00000000-0000-4000-8000-000000000002",
+)];
+
+#[tokio::test]
+async fn test_get_tags_sorted_by_popularity() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    create_tagged_article(app.clone(), &token, "First", &["rust", "web"]).await;
+    create_tagged_article(app.clone(), &token, "Second", &["web", "axum"]).await;
+    create_tagged_article(app.clone(), &token, "Third", &["web"]).await;
+
+    let (status, body) = send(
+        app.clone(),
+        "GET",
+        "/api/tags?sort=popular&limit=2",
+        &token,
+        None,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["tags"], json!(["web", "axum"]));
+    assert_eq!(body["tagDetails"][0]["name"], "web");
+    assert_eq!(body["tagDetails"][0]["articlesCount"], 3);
+    assert_eq!(body["tagDetails"][1]["articlesCount"], 1);
+
+    let (status, body) = send(app, "GET", "/api/tags", &token, None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["tags"], json!(["axum", "rust", "web"]));
+}
+
+#[tokio::test]
+async fn test_get_tags_excludes_tags_no_longer_used() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_tagged_article(app.clone(), &token, "Retagged", &["rust", "web"]).await;
+
+    send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        &token,
+        Some(json!({ "article": { "removeTags": ["rust"] } })),
+    )
+    .await;
+
+    let (_, body) = send(app, "GET", "/api/tags", &token, None).await;
+
+    assert_eq!(body["tags"], json!(["web"]));
+}
+
+#[tokio::test]
+async fn test_admin_renames_and_describes_tag() {
+    let (app, pool) = common::create_test_app_with_db(ADMINS).await;
+    let admin = common::register_user_with_id(
+        app.clone(),
+        &pool,
+        MODERATOR_ID,
+        "moderator",
+        "moderator@example.com",
+        "password123",
+    )
+    .await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_tagged_article(app.clone(), &author, "Renamed", &["rust"]).await;
+
+    let (status, body) = send(
+        app.clone(),
+        "PUT",
+        "/api/tags/rust",
+        &admin,
+        Some(json!({ "tag": { "name": "rustlang", "description": "The Rust language" } })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["tag"]["name"], "rustlang");
+    assert_eq!(body["tag"]["description"], "The Rust language");
+    assert_eq!(body["tag"]["articlesCount"], 1);
+
+    let (_, body) = send(
+        app,
+        "GET",
+        &format!("/api/articles/{}", slug),
+        &author,
+        None,
+    )
+    .await;
+
+    assert_eq!(body["article"]["tagList"], json!(["rustlang"]));
+}
+
+#[tokio::test]
+async fn test_non_admin_cannot_manage_tags() {
+    let app = common::create_test_app_with_env(ADMINS).await;
+    // Admins are known by id, whatever the username.
+    let author = register_user(app.clone(), "Admin", "author@example.com", "password123").await;
+
+    create_tagged_article(app.clone(), &author, "Protected", &["rust", "web"]).await;
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        "/api/tags/rust",
+        &author,
+        Some(json!({ "tag": { "description": "Mine now" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(
+        app,
+        "POST",
+        "/api/tags/rust/merge",
+        &author,
+        Some(json!({ "tag": { "into": "web" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_rename_tag_to_existing_name_fails() {
+    let (app, pool) = common::create_test_app_with_db(ADMINS).await;
+    let admin = common::register_user_with_id(
+        app.clone(),
+        &pool,
+        ADMIN_ID,
+        "admin",
+        "admin@example.com",
+        "password123",
+    )
+    .await;
+
+    create_tagged_article(app.clone(), &admin, "Conflicting", &["rust", "web"]).await;
+
+    let (status, _) = send(
+        app,
+        "PUT",
+        "/api/tags/rust",
+        &admin,
+        Some(json!({ "tag": { "name": "web" } })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_admin_merges_tags() {
+    let (app, pool) = common::create_test_app_with_db(ADMINS).await;
+    let admin = common::register_user_with_id(
+        app.clone(),
+        &pool,
+        ADMIN_ID,
+        "admin",
+        "admin@example.com",
+        "password123",
+    )
+    .await;
+
+    let both = create_tagged_article(app.clone(), &admin, "Both", &["rust", "rustlang"]).await;
+    let merged = create_tagged_article(app.clone(), &admin, "Merged", &["rustlang", "web"]).await;
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/tags/rustlang/merge",
+        &admin,
+        Some(json!({ "tag": { "into": "rust" } })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["tag"]["name"], "rust");
+    assert_eq!(body["tag"]["articlesCount"], 2);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", both),
+        &admin,
+        None,
+    )
+    .await;
+    assert_eq!(body["article"]["tagList"], json!(["rust"]));
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/articles/{}", merged),
+        &admin,
+        None,
+    )
+    .await;
+    assert_eq!(body["article"]["tagList"], json!(["rust", "web"]));
+
+    let (status, _) = send(
+        app,
+        "POST",
+        "/api/tags/rustlang/merge",
+        &admin,
+        Some(json!({ "tag": { "into": "rust" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_orphaned_tags_are_cleaned_up() {
+    let (app, pool) = common::create_test_app_with_db(&[("TAGS_CLEANUP_INTERVAL_MS", "50")]).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    let slug = create_tagged_article(app.clone(), &token, "Deleted", &["rust", "web"]).await;
+    create_tagged_article(app.clone(), &token, "Kept", &["web"]).await;
+
+    send(
+        app,
+        "DELETE",
+        &format!("/api/articles/{}", slug),
+        &token,
+        None,
+    )
+    .await;
+
+    let mut tags: Vec<String> = Vec::new();
+    for _ in 0..100 {
+        tags = sqlx::query_scalar("SELECT name FROM tags ORDER BY name")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+
+        if tags.len() == 1 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    assert_eq!(tags, vec!["web".to_string()]);
+}
+
+#[tokio::test]
+async fn test_new_tags_survive_concurrent_articles_and_cleanup() {
+    let (app, pool) = common::create_test_app_with_db(&[("TAGS_CLEANUP_INTERVAL_MS", "5")]).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    for round in 0..5 {
+        let tag = format!("fresh-{}", round);
+        let creations = (0..5).map(|i| {
+            let title = format!("Round {} article {}", round, i);
+            let (app, token, tag) = (app.clone(), token.clone(), tag.clone());
+            async move { create_tagged_article(app, &token, &title, &[&tag]).await }
+        });
+        futures_util::future::join_all(creations).await;
+
+        let tagged: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM article_tags JOIN tags ON tags.id = article_tags.tag_id WHERE tags.name = $1",
+        )
+        .bind(&tag)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(tagged, 5);
+    }
+}
+
+#[tokio::test]
+async fn test_follow_and_unfollow_tag() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    create_tagged_article(app.clone(), &token, "Followed", &["rust", "web"]).await;
+
+    let (status, body) = send(app.clone(), "POST", "/api/tags/rust/follow", &token, None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["tag"]["name"], "rust");
+    assert_eq!(body["tag"]["following"], true);
+
+    let (_, body) = send(app.clone(), "GET", "/api/user", &token, None).await;
+    assert_eq!(body["user"]["followedTags"], json!(["rust"]));
+
+    let (_, body) = send(app.clone(), "GET", "/api/tags", &token, None).await;
+    assert_eq!(body["tagDetails"][0]["following"], true);
+    assert_eq!(body["tagDetails"][1]["following"], false);
+
+    let (status, body) = send(app.clone(), "DELETE", "/api/tags/rust/follow", &token, None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["tag"]["following"], false);
+
+    let (_, body) = send(app.clone(), "GET", "/api/user", &token, None).await;
+    assert_eq!(body["user"]["followedTags"], json!([]));
+
+    let (status, _) = send(app, "POST", "/api/tags/unknown/follow", &token, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_feed_sources() {
+    let app = common::create_test_app().await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let followed = register_user(
+        app.clone(),
+        "followed",
+        "followed@example.com",
+        "password123",
+    )
+    .await;
+    let other = register_user(app.clone(), "other", "other@example.com", "password123").await;
+
+    create_tagged_article(app.clone(), &followed, "By Author", &["web"]).await;
+    create_tagged_article(app.clone(), &followed, "By Author And Tag", &["rust"]).await;
+    create_tagged_article(app.clone(), &other, "By Tag", &["rust"]).await;
+    create_tagged_article(app.clone(), &other, "Unrelated", &["web"]).await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/followed/follow",
+        &reader,
+        None,
+    )
+    .await;
+    send(app.clone(), "POST", "/api/tags/rust/follow", &reader, None).await;
+
+    let titles = |body: &Value| -> Vec<String> {
+        let mut titles: Vec<String> = body["articles"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| a["title"].as_str().unwrap().to_string())
+            .collect();
+        titles.sort();
+        titles
+    };
+
+    let (status, body) = send(app.clone(), "GET", "/api/articles/feed", &reader, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&body), vec!["By Author", "By Author And Tag"]);
+    assert_eq!(body["articlesCount"], 2);
+
+    let (_, body) = send(
+        app.clone(),
+        "GET",
+        "/api/articles/feed?source=tags",
+        &reader,
+        None,
+    )
+    .await;
+    assert_eq!(titles(&body), vec!["By Author And Tag", "By Tag"]);
+    assert_eq!(body["articlesCount"], 2);
+
+    let (_, body) = send(app, "GET", "/api/articles/feed?source=all", &reader, None).await;
+    assert_eq!(
+        titles(&body),
+        vec!["By Author", "By Author And Tag", "By Tag"]
+    );
+    assert_eq!(body["articlesCount"], 3);
+}
+
+#[tokio::test]
+async fn test_merge_moves_followers_and_keeps_followed_tags() {
+    let (app, pool) = common::create_test_app_with_db(&[
+        ("ADMIN_USER_IDS", ADMIN_ID),
+        ("TAGS_CLEANUP_INTERVAL_MS", "50"),
+    ])
+    .await;
+    let admin = common::register_user_with_id(
+        app.clone(),
+        &pool,
+        ADMIN_ID,
+        "admin",
+        "admin@example.com",
+        "password123",
+    )
+    .await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    let slug = create_tagged_article(app.clone(), &admin, "Merged", &["rust", "rustlang"]).await;
+    send(
+        app.clone(),
+        "POST",
+        "/api/tags/rustlang/follow",
+        &reader,
+        None,
+    )
+    .await;
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/tags/rustlang/merge",
+        &admin,
+        Some(json!({ "tag": { "into": "rust" } })),
+    )
+    .await;
+
+    // Once the article is gone, only the follow keeps the tag around.
+    send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}", slug),
+        &admin,
+        None,
+    )
+    .await;
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+
+    let (_, body) = send(app, "GET", "/api/user", &reader, None).await;
+    assert_eq!(body["user"]["followedTags"], json!(["rust"]));
+
+    let tags: Vec<String> = sqlx::query_scalar("SELECT name FROM tags")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(tags, vec!["rust".to_string()]);
+}
@@ -0,0 +1,253 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::{register_user, send};
+use serde_json::json;
+use sqlx::PgPool;
+
+async fn create_article(app: axum::Router, token: &str, title: &str) -> String {
+    let payload = json!({
+        "article": {
+            "title": title,
+            "description": "Test article",
+            "body": "Content",
+            "tagList": ["rust", "transactions"]
+        }
+    });
+
+    let (_, body) = send(app, "POST", "/api/articles", Some(token), Some(payload)).await;
+    body["article"]["slug"].as_str().unwrap().to_string()
+}
+
+/// Makes every following `operation` (INSERT, UPDATE or DELETE) on `table` fail.
+async fn inject_failure(pool: &PgPool, table: &str, operation: &str) {
+    sqlx::query(
+        "CREATE OR REPLACE FUNCTION injected_failure() RETURNS trigger AS $$
+         BEGIN
+             RAISE EXCEPTION 'Injected failure on %', TG_TABLE_NAME;
+         END
+         $$ LANGUAGE plpgsql",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(&format!(
+        "CREATE TRIGGER injected_failure BEFORE {} ON {} FOR EACH ROW EXECUTE FUNCTION injected_failure()",
+        operation, table
+    ))
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn count(pool: &PgPool, query: &str) -> i64 {
+    sqlx::query_scalar(query).fetch_one(pool).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_create_article_is_rolled_back_when_tagging_fails() {
+    let (app, pool) = common::create_test_app_with_db(&[]).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+
+    inject_failure(&pool, "article_tags", "INSERT").await;
+
+    let (status, _) = send(
+        app,
+        "POST",
+        "/api/articles",
+        Some(&token),
+        Some(json!({
+            "article": {
+                "title": "Half Written",
+                "description": "Test article",
+                "body": "Content",
+                "tagList": ["rust"]
+            }
+        })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(count(&pool, "SELECT COUNT(*) FROM articles").await, 0);
+    assert_eq!(count(&pool, "SELECT COUNT(*) FROM tags").await, 0);
+    assert_eq!(count(&pool, "SELECT COUNT(*) FROM outbox_events").await, 0);
+}
+
+#[tokio::test]
+async fn test_update_article_is_rolled_back_when_recording_event_fails() {
+    let (app, pool) = common::create_test_app_with_db(&[]).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let slug = create_article(app.clone(), &token, "Unchanged Article").await;
+
+    inject_failure(&pool, "outbox_events", "INSERT").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        Some(&token),
+        Some(json!({ "article": { "title": "Renamed Article", "body": "New content" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+
+    let (status, body) = send(
+        app,
+        "GET",
+        &format!("/api/articles/{}", slug),
+        Some(&token),
+        None,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["title"], "Unchanged Article");
+    assert_eq!(body["article"]["body"], "Content");
+}
+
+#[tokio::test]
+async fn test_delete_article_is_rolled_back_when_recording_event_fails() {
+    let (app, pool) = common::create_test_app_with_db(&[]).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let slug = create_article(app.clone(), &token, "Surviving Article").await;
+
+    inject_failure(&pool, "outbox_events", "INSERT").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}", slug),
+        Some(&token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+
+    let (status, body) = send(
+        app,
+        "GET",
+        &format!("/api/articles/{}", slug),
+        Some(&token),
+        None,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["article"]["tagList"], json!(["rust", "transactions"]));
+}
+
+#[tokio::test]
+async fn test_favorite_is_rolled_back_when_notifying_fails() {
+    let (app, pool) = common::create_test_app_with_db(&[]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Unfavorited Article").await;
+
+    inject_failure(&pool, "notifications", "INSERT").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&reader),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+
+    let (_, body) = send(
+        app,
+        "GET",
+        &format!("/api/articles/{}", slug),
+        Some(&reader),
+        None,
+    )
+    .await;
+
+    assert_eq!(body["article"]["favorited"], false);
+    assert_eq!(body["article"]["favoritesCount"], 0);
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM outbox_events WHERE event_type = 'favoritesChanged'"
+        )
+        .await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_comment_is_rolled_back_when_notifying_fails() {
+    let (app, pool) = common::create_test_app_with_db(&[]).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let slug = create_article(app.clone(), &author, "Uncommented Article").await;
+
+    inject_failure(&pool, "notifications", "INSERT").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&reader),
+        Some(json!({ "comment": { "body": "Lost comment" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+
+    let (_, body) = send(
+        app,
+        "GET",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&reader),
+        None,
+    )
+    .await;
+
+    assert_eq!(body["comments"], json!([]));
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM outbox_events WHERE event_type = 'commentCreated'"
+        )
+        .await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_follow_is_rolled_back_when_recording_event_fails() {
+    let (app, pool) = common::create_test_app_with_db(&[]).await;
+    register_user(
+        app.clone(),
+        "followee",
+        "followee@example.com",
+        "password123",
+    )
+    .await;
+    let follower = register_user(
+        app.clone(),
+        "follower",
+        "follower@example.com",
+        "password123",
+    )
+    .await;
+
+    inject_failure(&pool, "outbox_events", "INSERT").await;
+
+    let (status, _) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/followee/follow",
+        Some(&follower),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+
+    let (_, body) = send(app, "GET", "/api/profiles/followee", Some(&follower), None).await;
+
+    assert_eq!(body["profile"]["following"], false);
+    assert_eq!(count(&pool, "SELECT COUNT(*) FROM notifications").await, 0);
+}
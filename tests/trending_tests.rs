@@ -0,0 +1,201 @@
+mod common;
+
+use axum::body::Body;
+use axum::http::{HeaderMap, Request, StatusCode, header};
+use common::{create_article, register_user, send};
+use serde_json::{Value, json};
+use sqlx::PgPool;
+use std::time::Duration;
+use tower::ServiceExt;
+
+const FAST_REFRESH: &[(&str, &str)] = &[("TRENDING_REFRESH_INTERVAL_MS", "50")];
+
+/// Like `common::send`, but also returns the response headers.
+async fn send_with_headers(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    payload: Option<Value>,
+) -> (StatusCode, HeaderMap, Value) {
+    let body = match payload {
+        Some(payload) => Body::from(serde_json::to_string(&payload).unwrap()),
+        None => Body::empty(),
+    };
+
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json");
+
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+
+    let response = app.oneshot(request.body(body).unwrap()).await.unwrap();
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+    (status, headers, body)
+}
+
+async fn comment(app: axum::Router, token: &str, slug: &str) {
+    let (status, _) = send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(token),
+        Some(json!({ "comment": { "body": "Nice" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+async fn favorite(app: axum::Router, token: &str, slug: &str) {
+    let (status, _) = send(
+        app,
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+/// Waits for a refresh of the trending view to rank the `expected` articles, as the view may
+/// still reflect an earlier state.
+async fn trending_titles(app: axum::Router, uri: &str, expected: &[&str]) -> Vec<String> {
+    let mut titles = Vec::new();
+
+    for _ in 0..100 {
+        let (_, body) = send(app.clone(), "GET", uri, None, None).await;
+        titles = body["articles"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| a["title"].as_str().unwrap().to_string())
+            .collect();
+
+        if titles == expected {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    titles
+}
+
+#[tokio::test]
+async fn test_trending_ranks_articles_by_recent_activity() {
+    let app = common::create_test_app_with_env(FAST_REFRESH).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    let favorited = create_article(app.clone(), &author, "Favorited").await;
+    let discussed = create_article(app.clone(), &author, "Discussed").await;
+    create_article(app.clone(), &author, "Quiet").await;
+
+    favorite(app.clone(), &reader, &favorited).await;
+    favorite(app.clone(), &reader, &discussed).await;
+    comment(app.clone(), &reader, &discussed).await;
+
+    let titles = trending_titles(
+        app.clone(),
+        "/api/articles/trending",
+        &["Discussed", "Favorited"],
+    )
+    .await;
+    assert_eq!(titles, vec!["Discussed", "Favorited"]);
+
+    let (status, headers, body) = send_with_headers(
+        app.clone(),
+        "GET",
+        "/api/articles/trending?window=7d",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["articlesCount"], 2);
+    assert!(
+        headers[header::CACHE_CONTROL]
+            .to_str()
+            .unwrap()
+            .starts_with("public, max-age=")
+    );
+    assert_eq!(headers[header::VARY], "Authorization");
+
+    let (_, headers, body) =
+        send_with_headers(app, "GET", "/api/articles/trending", Some(&reader), None).await;
+    assert_eq!(body["articles"][0]["favorited"], true);
+    assert!(
+        headers[header::CACHE_CONTROL]
+            .to_str()
+            .unwrap()
+            .starts_with("private, max-age=")
+    );
+    assert_eq!(headers[header::VARY], "Authorization");
+}
+
+#[tokio::test]
+async fn test_trending_window_only_counts_recent_activity() {
+    let (app, pool): (axum::Router, PgPool) = common::create_test_app_with_db(FAST_REFRESH).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+
+    let older = create_article(app.clone(), &author, "Older").await;
+    let recent = create_article(app.clone(), &author, "Recent").await;
+
+    favorite(app.clone(), &reader, &older).await;
+    comment(app.clone(), &reader, &older).await;
+    favorite(app.clone(), &reader, &recent).await;
+
+    sqlx::query("UPDATE article_favorites SET created_at = NOW() - INTERVAL '3 days'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE comments SET created_at = NOW() - INTERVAL '3 days'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "UPDATE article_favorites SET created_at = NOW() \
+         WHERE article_id = (SELECT id FROM articles WHERE slug = $1)",
+    )
+    .bind(&recent)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let titles = trending_titles(
+        app.clone(),
+        "/api/articles/trending?window=24h",
+        &["Recent"],
+    )
+    .await;
+    assert_eq!(titles, vec!["Recent"]);
+
+    // Older activity still counts over a week, but has decayed below the fresh favorite.
+    let titles = trending_titles(
+        app,
+        "/api/articles/trending?window=7d",
+        &["Recent", "Older"],
+    )
+    .await;
+    assert_eq!(titles, vec!["Recent", "Older"]);
+}
+
+#[tokio::test]
+async fn test_trending_rejects_unknown_window() {
+    let app = common::create_test_app().await;
+
+    let (status, _) = send(app, "GET", "/api/articles/trending?window=1y", None, None).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,380 @@
+mod common;
+
+use axum::Router;
+use axum::body::{Body, Bytes};
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, Request, StatusCode};
+use axum::routing::put;
+use common::{register_user, send};
+use image::{DynamicImage, ImageFormat};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+
+const BOUNDARY: &str = "realworld-test-boundary";
+
+fn encode_image(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+    let mut bytes = Cursor::new(Vec::new());
+    DynamicImage::new_rgb8(width, height)
+        .write_to(&mut bytes, format)
+        .unwrap();
+    bytes.into_inner()
+}
+
+fn multipart_body(field: &str, content_type: &str, bytes: &[u8]) -> Vec<u8> {
+    let mut body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field}\"; filename=\"upload\"\r\nContent-Type: {content_type}\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(bytes);
+    body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+    body
+}
+
+async fn upload(app: Router, token: Option<&str>, body: Vec<u8>) -> (StatusCode, Value) {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri("/api/uploads")
+        .header(
+            "content-type",
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        );
+
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Token {}", token));
+    }
+
+    let response = app
+        .oneshot(request.body(Body::from(body)).unwrap())
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+    (status, body)
+}
+
+/// Fetches an upload through the path of its public URL.
+async fn download(app: Router, url: &str) -> (StatusCode, HeaderMap, Bytes) {
+    let path = url.strip_prefix("http://localhost:8080").unwrap();
+
+    let response = app
+        .oneshot(Request::builder().uri(path).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    (status, headers, body)
+}
+
+fn local_storage_env(dir: &std::path::Path) -> Vec<(&'static str, String)> {
+    vec![
+        ("UPLOADS_LOCAL_DIR", dir.to_str().unwrap().to_string()),
+        ("UPLOADS_THUMBNAIL_SIZE", "64".to_string()),
+    ]
+}
+
+fn temp_dir() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("realworld-uploads-{}", uuid::Uuid::new_v4()))
+}
+
+async fn create_app(env: &[(&'static str, String)]) -> Router {
+    let env: Vec<(&str, &str)> = env.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    common::create_test_app_with_env(&env).await
+}
+
+#[tokio::test]
+async fn test_upload_image_with_thumbnail() {
+    let dir = temp_dir();
+    let app = create_app(&local_storage_env(&dir)).await;
+    let token = register_user(
+        app.clone(),
+        "uploader",
+        "uploader@example.com",
+        "password123",
+    )
+    .await;
+
+    let image = encode_image(200, 100, ImageFormat::Png);
+    let (status, body) = upload(
+        app.clone(),
+        Some(&token),
+        multipart_body("file", "application/octet-stream", &image),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let upload = &body["upload"];
+    assert_eq!(upload["contentType"], "image/png");
+    assert_eq!(upload["width"], 200);
+    assert_eq!(upload["height"], 100);
+
+    let url = upload["url"].as_str().unwrap();
+    assert!(url.starts_with("http://localhost:8080/api/uploads/"));
+
+    let (status, headers, content) = download(app.clone(), url).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["content-type"], "image/png");
+    assert!(
+        headers["cache-control"]
+            .to_str()
+            .unwrap()
+            .contains("immutable")
+    );
+    assert_eq!(headers["x-content-type-options"], "nosniff");
+    assert_eq!(upload["size"], content.len());
+    let stored = image::load_from_memory(&content).unwrap();
+    assert_eq!((stored.width(), stored.height()), (200, 100));
+
+    let (status, _, content) =
+        download(app.clone(), upload["thumbnailUrl"].as_str().unwrap()).await;
+    assert_eq!(status, StatusCode::OK);
+    let thumbnail = image::load_from_memory(&content).unwrap();
+    assert_eq!((thumbnail.width(), thumbnail.height()), (64, 32));
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn test_uploaded_image_can_be_used_as_avatar() {
+    let dir = temp_dir();
+    let app = create_app(&local_storage_env(&dir)).await;
+    let token = register_user(
+        app.clone(),
+        "uploader",
+        "uploader@example.com",
+        "password123",
+    )
+    .await;
+
+    let (_, body) = upload(
+        app.clone(),
+        Some(&token),
+        multipart_body(
+            "file",
+            "image/jpeg",
+            &encode_image(10, 10, ImageFormat::Jpeg),
+        ),
+    )
+    .await;
+    let url = body["upload"]["url"].as_str().unwrap().to_string();
+    assert!(url.ends_with(".jpg"));
+
+    let (status, body) = send(
+        app.clone(),
+        "PUT",
+        "/api/user",
+        Some(&token),
+        Some(json!({ "user": { "image": url } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["image"], url);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn test_upload_validation() {
+    let dir = temp_dir();
+    let mut env = local_storage_env(&dir);
+    env.push(("UPLOADS_MAX_BYTES", "1024".to_string()));
+    env.push(("UPLOADS_MAX_DIMENSION", "100".to_string()));
+    let app = create_app(&env).await;
+    let token = register_user(
+        app.clone(),
+        "uploader",
+        "uploader@example.com",
+        "password123",
+    )
+    .await;
+
+    let image = encode_image(10, 10, ImageFormat::Png);
+
+    let (status, _) = upload(
+        app.clone(),
+        None,
+        multipart_body("file", "image/png", &image),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, body) = upload(
+        app.clone(),
+        Some(&token),
+        multipart_body("avatar", "image/png", &image),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["errors"]["body"][0], "Missing file field");
+
+    // The declared content type is ignored, the content is checked.
+    let (status, _) = upload(
+        app.clone(),
+        Some(&token),
+        multipart_body("file", "image/png", b"<svg onload=\"alert(1)\"></svg>"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, body) = upload(
+        app.clone(),
+        Some(&token),
+        multipart_body("file", "image/png", &vec![0u8; 2048]),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body["errors"]["body"][0],
+        "Image cannot be larger than 1024 bytes"
+    );
+
+    let (status, _) = upload(
+        app.clone(),
+        Some(&token),
+        multipart_body("file", "image/png", &encode_image(101, 1, ImageFormat::Png)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    assert!(!dir.exists());
+}
+
+#[tokio::test]
+async fn test_get_unknown_upload() {
+    let dir = temp_dir();
+    let app = create_app(&local_storage_env(&dir)).await;
+
+    let (status, _, _) =
+        download(app.clone(), "http://localhost:8080/api/uploads/missing.png").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _, _) = download(
+        app.clone(),
+        "http://localhost:8080/api/uploads/..%2F..%2Fetc%2Fpasswd",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+type FakeBucket = Arc<Mutex<HashMap<String, (String, Vec<u8>)>>>;
+
+async fn fake_s3_put(
+    State(bucket): State<FakeBucket>,
+    Path((name, key)): Path<(String, String)>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    let signed = headers
+        .get("authorization")
+        .and_then(|h| h.to_str().ok())
+        .is_some_and(|h| h.starts_with("AWS4-HMAC-SHA256 Credential=test-key/"));
+    if name != "media" || !signed {
+        return StatusCode::FORBIDDEN;
+    }
+
+    let content_type = headers["content-type"].to_str().unwrap().to_string();
+    bucket
+        .lock()
+        .unwrap()
+        .insert(key, (content_type, body.to_vec()));
+
+    StatusCode::OK
+}
+
+async fn fake_s3_get(
+    State(bucket): State<FakeBucket>,
+    Path((_, key)): Path<(String, String)>,
+) -> Result<([(&'static str, String); 1], Vec<u8>), StatusCode> {
+    let (content_type, bytes) = bucket
+        .lock()
+        .unwrap()
+        .get(&key)
+        .cloned()
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(([("content-type", content_type)], bytes))
+}
+
+/// Starts a minimal S3 stand-in keeping objects in memory.
+async fn start_fake_s3() -> (String, FakeBucket) {
+    let bucket = FakeBucket::default();
+
+    let router = Router::new()
+        .route("/{bucket}/{key}", put(fake_s3_put).get(fake_s3_get))
+        .with_state(bucket.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    (endpoint, bucket)
+}
+
+#[tokio::test]
+async fn test_upload_to_s3_storage() {
+    let (endpoint, bucket) = start_fake_s3().await;
+    let app = create_app(&[
+        ("UPLOADS_STORAGE_BACKEND", "s3".to_string()),
+        ("S3_ENDPOINT", endpoint),
+        ("S3_BUCKET", "media".to_string()),
+        ("S3_ACCESS_KEY_ID", "test-key".to_string()),
+        ("S3_SECRET_ACCESS_KEY", "test-secret".to_string()),
+    ])
+    .await;
+    let token = register_user(
+        app.clone(),
+        "uploader",
+        "uploader@example.com",
+        "password123",
+    )
+    .await;
+
+    let image = encode_image(300, 300, ImageFormat::Gif);
+    let (status, body) = upload(
+        app.clone(),
+        Some(&token),
+        multipart_body("file", "image/gif", &image),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["upload"]["contentType"], "image/gif");
+
+    {
+        let objects = bucket.lock().unwrap();
+        assert_eq!(objects.len(), 2);
+        assert!(
+            objects
+                .values()
+                .any(|(content_type, _)| content_type == "image/gif")
+        );
+        assert!(
+            objects
+                .values()
+                .any(|(content_type, _)| content_type == "image/png")
+        );
+    }
+
+    let (status, headers, content) =
+        download(app.clone(), body["upload"]["url"].as_str().unwrap()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["content-type"], "image/gif");
+    let stored = image::load_from_memory(&content).unwrap();
+    assert_eq!((stored.width(), stored.height()), (300, 300));
+
+    let (status, _, _) =
+        download(app.clone(), "http://localhost:8080/api/uploads/missing.gif").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
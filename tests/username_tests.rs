@@ -0,0 +1,313 @@
+mod common;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use common::{create_article, register_user, send};
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+async fn rename(app: axum::Router, token: &str, username: &str) -> (StatusCode, Value) {
+    send(
+        app,
+        "PUT",
+        "/api/user",
+        Some(token),
+        Some(json!({ "user": { "username": username } })),
+    )
+    .await
+}
+
+async fn register(app: axum::Router, username: &str, email: &str) -> StatusCode {
+    let payload = json!({
+        "user": { "username": username, "email": email, "password": "password123" }
+    });
+    send(app, "POST", "/api/users", None, Some(payload)).await.0
+}
+
+#[tokio::test]
+async fn test_previous_username_redirects_to_profile() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+
+    create_article(app.clone(), &alice, "Alice's article").await;
+
+    let (status, body) = rename(app.clone(), &alice, "alicia").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "alicia");
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/profiles/Alice")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::TEMPORARY_REDIRECT);
+    assert_eq!(response.headers()["location"], "/api/profiles/alicia");
+
+    let (status, _) = send(app.clone(), "GET", "/api/profiles/nobody", None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Filtering on the previous username still finds the articles.
+    let (_, body) = send(app.clone(), "GET", "/api/articles?author=alice", None, None).await;
+    assert_eq!(body["articlesCount"], 1);
+    assert_eq!(body["articles"][0]["author"]["username"], "alicia");
+
+    // Nobody else can take the previous username, whatever the case.
+    assert_eq!(
+        register(app.clone(), "ALICE", "other@example.com").await,
+        StatusCode::CONFLICT
+    );
+    assert_eq!(
+        register(app.clone(), "Alicia", "other@example.com").await,
+        StatusCode::CONFLICT
+    );
+}
+
+#[tokio::test]
+async fn test_username_change_cooldown() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+
+    let (status, _) = rename(app.clone(), &alice, "alicia").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = rename(app.clone(), &alice, "ally").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body["errors"]["body"][0],
+        "Username can only be changed once every 30 days"
+    );
+
+    // Other fields can still be updated.
+    let (status, _) = send(
+        app.clone(),
+        "PUT",
+        "/api/user",
+        Some(&alice),
+        Some(json!({ "user": { "username": "alicia", "bio": "Hi" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_take_back_previous_username() {
+    let app = common::create_test_app_with_env(&[("USERNAMES_CHANGE_COOLDOWN_DAYS", "0")]).await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+
+    rename(app.clone(), &alice, "alicia").await;
+    let (status, body) = rename(app.clone(), &alice, "alice").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "alice");
+
+    let (status, _) = send(app.clone(), "GET", "/api/profiles/alice", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/profiles/alicia")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.headers()["location"], "/api/profiles/alice");
+}
+
+#[tokio::test]
+async fn test_reserved_usernames() {
+    let app = common::create_test_app_with_env(&[("USERNAMES_RESERVED", "admin, Support")]).await;
+
+    assert_eq!(
+        register(app.clone(), "support", "support@example.com").await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let (status, body) = rename(app.clone(), &alice, "Admin").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["errors"]["body"][0], "Username 'Admin' is reserved");
+}
+
+#[tokio::test]
+async fn test_lookalike_usernames() {
+    let app = common::create_test_app_with_env(&[("USERNAMES_RESERVED", "ace")]).await;
+    let scope = register_user(app.clone(), "scope", "scope@example.com", "password123").await;
+
+    // "scope" written only with Cyrillic letters.
+    let lookalike = "\u{455}\u{441}\u{43e}\u{440}\u{435}";
+    assert_eq!(
+        register(app.clone(), lookalike, "lookalike@example.com").await,
+        StatusCode::CONFLICT
+    );
+
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let (status, body) = rename(app.clone(), &alice, lookalike).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(
+        body["errors"]["body"][0],
+        format!("Username '{}' is too similar to an existing one", lookalike)
+    );
+
+    // "ace" written only with Cyrillic letters.
+    let (status, _) = rename(app.clone(), &alice, "\u{430}\u{441}\u{435}").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Users can still change the case of their own username.
+    let (status, body) = rename(app.clone(), &scope, "Scope").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["user"]["username"], "Scope");
+}
+
+#[tokio::test]
+async fn test_legacy_usernames_still_work() {
+    let (app, pool) = common::create_test_app_with_db(&[]).await;
+    let legacy = register_user(app.clone(), "legacy", "legacy@example.com", "password123").await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+
+    // Registered before usernames needed two characters of a single script.
+    for (username, path) in [("x", "x"), ("p\u{430}ypal", "p%D0%B0ypal")] {
+        sqlx::query("UPDATE users SET username = $1 WHERE email = 'legacy@example.com'")
+            .bind(username)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let uri = format!("/api/profiles/{}", path);
+        let (status, body) = send(app.clone(), "GET", &uri, None, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["profile"]["username"], username);
+
+        let uri = format!("/api/profiles/{}/follow", path);
+        let (status, _) = send(app.clone(), "POST", &uri, Some(&alice), None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let payload = json!({ "user": { "bio": "Still here" } });
+        let (status, body) = send(
+            app.clone(),
+            "PUT",
+            "/api/user",
+            Some(&legacy),
+            Some(payload),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["user"]["username"], username);
+    }
+
+    // But new usernames follow the rules.
+    assert_eq!(
+        register(app.clone(), "y", "y@example.com").await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    let (status, body) = rename(app.clone(), &alice, "x\u{430}").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body["errors"]["body"][0],
+        "Username cannot mix characters of different scripts"
+    );
+}
+
+#[tokio::test]
+async fn test_usernames_match_whatever_the_case() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let (status, body) = send(app.clone(), "GET", "/api/profiles/BOB", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profile"]["username"], "bob");
+
+    let (status, body) = send(
+        app.clone(),
+        "POST",
+        "/api/profiles/BOB/follow",
+        Some(&alice),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["profile"]["username"], "bob");
+    assert_eq!(body["profile"]["following"], true);
+
+    for action in ["mute", "block"] {
+        let (status, body) = send(
+            app.clone(),
+            "POST",
+            &format!("/api/profiles/Bob/{}", action),
+            Some(&alice),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["profile"]["username"], "bob");
+    }
+}
+
+#[tokio::test]
+async fn test_article_filters_match_usernames_whatever_the_case() {
+    let app = common::create_test_app().await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    let bob = register_user(app.clone(), "bob", "bob@example.com", "password123").await;
+
+    let slug = create_article(app.clone(), &alice, "Alice's article").await;
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/favorite", slug),
+        Some(&bob),
+        None,
+    )
+    .await;
+
+    for uri in ["/api/articles?author=ALICE", "/api/articles?favorited=Bob"] {
+        let (status, body) = send(app.clone(), "GET", uri, None, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["articlesCount"], 1, "{}", uri);
+        assert_eq!(body["articles"][0]["slug"], slug.as_str());
+    }
+}
+
+#[tokio::test]
+async fn test_give_up_username_kept_by_case_renamed_account() {
+    let (app, pool) = common::create_test_app_with_db(&[]).await;
+    let alice = register_user(app.clone(), "alice", "alice@example.com", "password123").await;
+    register_user(
+        app.clone(),
+        "Alice_1a2b3c4d",
+        "other@example.com",
+        "password123",
+    )
+    .await;
+
+    // As left by the migration making usernames unique regardless of case.
+    sqlx::query(
+        "INSERT INTO username_history (user_id, username)
+         SELECT id, 'Alice' FROM users WHERE username = 'Alice_1a2b3c4d'",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let (status, _) = rename(app.clone(), &alice, "alicia").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/profiles/alice")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::TEMPORARY_REDIRECT);
+    assert_eq!(response.headers()["location"], "/api/profiles/alicia");
+}
@@ -0,0 +1,634 @@
+mod common;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use common::{register_user, send};
+use hmac::{Hmac, Mac};
+use serde_json::{Value, json};
+use sha2::Sha256;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+const FAST_RETRIES: &[(&str, &str)] = &[
+    ("WEBHOOKS_POLL_INTERVAL_MS", "20"),
+    ("WEBHOOKS_RETRY_BASE_DELAY_MS", "20"),
+    ("WEBHOOKS_MAX_ATTEMPTS", "3"),
+    // The receivers listen on localhost.
+    ("WEBHOOKS_ALLOW_PRIVATE_HOSTS", "true"),
+];
+
+async fn create_article(app: axum::Router, token: &str, title: &str) -> String {
+    let payload = json!({
+        "article": {
+            "title": title,
+            "description": "Test article",
+            "body": "Content",
+            "tagList": ["rust"]
+        }
+    });
+
+    let (_, body) = send(app, "POST", "/api/articles", Some(token), Some(payload)).await;
+    body["article"]["slug"].as_str().unwrap().to_string()
+}
+
+async fn create_webhook(app: axum::Router, token: &str, url: &str, events: Value) -> Value {
+    let (status, body) = send(
+        app,
+        "POST",
+        "/api/webhooks",
+        Some(token),
+        Some(json!({ "webhook": { "url": url, "events": events } })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    body["webhook"].clone()
+}
+
+struct ReceivedRequest {
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+#[derive(Clone)]
+struct ReceiverState {
+    failures_left: Arc<AtomicUsize>,
+    sender: mpsc::UnboundedSender<ReceivedRequest>,
+}
+
+async fn receive(
+    State(state): State<ReceiverState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    state
+        .sender
+        .send(ReceivedRequest { headers, body })
+        .unwrap();
+
+    let failed = state
+        .failures_left
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+            left.checked_sub(1)
+        })
+        .is_ok();
+
+    if failed {
+        StatusCode::INTERNAL_SERVER_ERROR
+    } else {
+        StatusCode::OK
+    }
+}
+
+/// Starts a local HTTP server recording every webhook request, failing the first `failures`.
+async fn start_receiver(failures: usize) -> (String, mpsc::UnboundedReceiver<ReceivedRequest>) {
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    let state = ReceiverState {
+        failures_left: Arc::new(AtomicUsize::new(failures)),
+        sender,
+    };
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let router = axum::Router::new()
+            .route("/hook", post(receive))
+            .with_state(state);
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    (format!("http://{}/hook", address), receiver)
+}
+
+async fn next_request(receiver: &mut mpsc::UnboundedReceiver<ReceivedRequest>) -> ReceivedRequest {
+    tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+        .await
+        .expect("Timed out waiting for a webhook request")
+        .unwrap()
+}
+
+fn signature(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Polls the delivery log until the newest delivery exists and is no longer pending.
+async fn settled_deliveries(app: axum::Router, token: &str, webhook_id: &str) -> Value {
+    for _ in 0..100 {
+        let (_, body) = send(
+            app.clone(),
+            "GET",
+            &format!("/api/webhooks/{}/deliveries", webhook_id),
+            Some(token),
+            None,
+        )
+        .await;
+
+        let status = &body["deliveries"][0]["status"];
+
+        if status.is_string() && status != "pending" {
+            return body;
+        }
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("Delivery didn't settle");
+}
+
+#[tokio::test]
+async fn test_create_and_list_webhooks() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "user", "user@example.com", "password123").await;
+
+    let webhook = create_webhook(
+        app.clone(),
+        &token,
+        "https://example.com/hooks",
+        json!(["article.created", "comment.created", "article.created"]),
+    )
+    .await;
+
+    assert_eq!(webhook["url"], "https://example.com/hooks");
+    assert_eq!(
+        webhook["events"],
+        json!(["article.created", "comment.created"])
+    );
+    assert_eq!(webhook["secret"].as_str().unwrap().len(), 64);
+
+    let (status, body) = send(app, "GET", "/api/webhooks", Some(&token), None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["webhooks"].as_array().unwrap().len(), 1);
+    assert_eq!(body["webhooks"][0]["id"], webhook["id"]);
+    assert!(body["webhooks"][0].get("secret").is_none());
+}
+
+#[tokio::test]
+async fn test_create_webhook_with_invalid_data_fails() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "user", "user@example.com", "password123").await;
+
+    for webhook in [
+        json!({ "url": "ftp://example.com/hooks", "events": ["article.created"] }),
+        json!({ "url": "not a url", "events": ["article.created"] }),
+        json!({ "url": "https://example.com/hooks", "events": ["article.liked"] }),
+        json!({ "url": "https://example.com/hooks", "events": [] }),
+    ] {
+        let (status, _) = send(
+            app.clone(),
+            "POST",
+            "/api/webhooks",
+            Some(&token),
+            Some(json!({ "webhook": webhook })),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
+
+#[tokio::test]
+async fn test_local_webhook_urls_are_rejected() {
+    let app = common::create_test_app().await;
+    let token = register_user(app.clone(), "user", "user@example.com", "password123").await;
+
+    for url in [
+        "http://localhost/hooks",
+        "http://127.0.0.1:8080/hooks",
+        "http://10.0.0.1/hooks",
+        "http://192.168.1.1/hooks",
+        "http://169.254.169.254/latest/meta-data",
+        "http://[::1]/hooks",
+        "http://0x7f.1/hooks",
+    ] {
+        let (status, _) = send(
+            app.clone(),
+            "POST",
+            "/api/webhooks",
+            Some(&token),
+            Some(json!({ "webhook": { "url": url, "events": ["article.created"] } })),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", url);
+    }
+}
+
+#[tokio::test]
+async fn test_deliveries_to_local_addresses_fail() {
+    let (app, pool) = common::create_test_app_with_db(&FAST_RETRIES[..3]).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let (url, mut receiver) = start_receiver(0).await;
+
+    let webhook = create_webhook(
+        app.clone(),
+        &token,
+        "https://example.com/hooks",
+        json!(["article.created"]),
+    )
+    .await;
+
+    // As if the URL was registered while local addresses were allowed.
+    sqlx::query("UPDATE webhooks SET url = $1")
+        .bind(&url)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    create_article(app.clone(), &token, "Not Delivered").await;
+
+    let body = settled_deliveries(app, &token, webhook["id"].as_str().unwrap()).await;
+
+    assert_eq!(body["deliveries"][0]["status"], "failed");
+    assert_eq!(body["deliveries"][0]["attempts"], 1);
+    assert!(receiver.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_article_created_is_delivered_signed() {
+    let app = common::create_test_app_with_env(FAST_RETRIES).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let (url, mut receiver) = start_receiver(0).await;
+
+    let webhook = create_webhook(app.clone(), &token, &url, json!(["article.created"])).await;
+    let slug = create_article(app.clone(), &token, "Hooked Article").await;
+
+    let request = next_request(&mut receiver).await;
+
+    let secret = webhook["secret"].as_str().unwrap();
+    assert_eq!(
+        request.headers["x-realworld-signature"],
+        signature(secret, &request.body)
+    );
+    assert_eq!(request.headers["x-realworld-event"], "article.created");
+    assert_eq!(request.headers["content-type"], "application/json");
+
+    let payload: Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(payload["event"], "article.created");
+    assert_eq!(payload["data"]["article"]["slug"], slug);
+    assert_eq!(payload["data"]["article"]["tagList"], json!(["rust"]));
+    assert_eq!(payload["data"]["article"]["author"]["username"], "author");
+
+    let body = settled_deliveries(app, &token, webhook["id"].as_str().unwrap()).await;
+
+    assert_eq!(body["deliveriesCount"], 1);
+    assert_eq!(
+        body["deliveries"][0]["id"],
+        request.headers["x-realworld-delivery"].to_str().unwrap()
+    );
+    assert_eq!(body["deliveries"][0]["status"], "succeeded");
+    assert_eq!(body["deliveries"][0]["attempts"], 1);
+    assert_eq!(body["deliveries"][0]["lastResponseStatus"], 200);
+}
+
+#[tokio::test]
+async fn test_only_subscribed_events_are_delivered() {
+    let app = common::create_test_app_with_env(FAST_RETRIES).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let reader = register_user(app.clone(), "reader", "reader@example.com", "password123").await;
+    let (url, mut receiver) = start_receiver(0).await;
+
+    create_webhook(
+        app.clone(),
+        &author,
+        &url,
+        json!(["comment.created", "user.followed"]),
+    )
+    .await;
+
+    let slug = create_article(app.clone(), &author, "Discussed Article").await;
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&reader),
+        Some(json!({ "comment": { "body": "Great read" } })),
+    )
+    .await;
+
+    let comment = next_request(&mut receiver).await;
+    let comment: Value = serde_json::from_slice(&comment.body).unwrap();
+    assert_eq!(comment["event"], "comment.created");
+    assert_eq!(comment["data"]["article"]["slug"], slug);
+    assert_eq!(comment["data"]["comment"]["body"], "Great read");
+    assert_eq!(comment["data"]["comment"]["author"]["username"], "reader");
+
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/author/follow",
+        Some(&reader),
+        None,
+    )
+    .await;
+
+    let follow = next_request(&mut receiver).await;
+    let follow: Value = serde_json::from_slice(&follow.body).unwrap();
+    assert_eq!(follow["event"], "user.followed");
+    assert_eq!(follow["data"]["follower"]["username"], "reader");
+    assert_eq!(follow["data"]["followee"]["username"], "author");
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(receiver.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_article_updated_and_deleted_are_delivered() {
+    let app = common::create_test_app_with_env(FAST_RETRIES).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let (url, mut receiver) = start_receiver(0).await;
+
+    create_webhook(
+        app.clone(),
+        &token,
+        &url,
+        json!(["article.updated", "article.deleted"]),
+    )
+    .await;
+
+    let slug = create_article(app.clone(), &token, "Short Lived").await;
+    send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        Some(&token),
+        Some(json!({ "article": { "body": "Updated content" } })),
+    )
+    .await;
+
+    let updated = next_request(&mut receiver).await;
+    let updated: Value = serde_json::from_slice(&updated.body).unwrap();
+    assert_eq!(updated["event"], "article.updated");
+    assert_eq!(updated["data"]["article"]["body"], "Updated content");
+
+    send(
+        app,
+        "DELETE",
+        &format!("/api/articles/{}", slug),
+        Some(&token),
+        None,
+    )
+    .await;
+
+    let deleted = next_request(&mut receiver).await;
+    let deleted: Value = serde_json::from_slice(&deleted.body).unwrap();
+    assert_eq!(deleted["event"], "article.deleted");
+    assert_eq!(deleted["data"]["article"]["slug"], slug);
+}
+
+#[tokio::test]
+async fn test_private_authors_articles_are_not_delivered() {
+    let app = common::create_test_app_with_env(FAST_RETRIES).await;
+    let private = register_user(app.clone(), "private", "private@example.com", "password123").await;
+    let public = register_user(app.clone(), "public", "public@example.com", "password123").await;
+    let (url, mut receiver) = start_receiver(0).await;
+
+    create_webhook(
+        app.clone(),
+        &public,
+        &url,
+        json!([
+            "article.created",
+            "article.updated",
+            "article.deleted",
+            "comment.created"
+        ]),
+    )
+    .await;
+
+    send(
+        app.clone(),
+        "PUT",
+        "/api/user",
+        Some(&private),
+        Some(json!({ "user": { "private": true } })),
+    )
+    .await;
+
+    let slug = create_article(app.clone(), &private, "Private Article").await;
+    send(
+        app.clone(),
+        "PUT",
+        &format!("/api/articles/{}", slug),
+        Some(&private),
+        Some(json!({ "article": { "body": "Updated content" } })),
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        &format!("/api/articles/{}/comments", slug),
+        Some(&private),
+        Some(json!({ "comment": { "body": "Note to self" } })),
+    )
+    .await;
+    send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/articles/{}", slug),
+        Some(&private),
+        None,
+    )
+    .await;
+
+    // Events are published in order, so the private ones would have come first.
+    let public_slug = create_article(app, &public, "Public Article").await;
+
+    let request = next_request(&mut receiver).await;
+    let payload: Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(payload["event"], "article.created");
+    assert_eq!(payload["data"]["article"]["slug"], public_slug);
+}
+
+/// Reads the next request and returns its event name and data.
+async fn next_event(receiver: &mut mpsc::UnboundedReceiver<ReceivedRequest>) -> (String, Value) {
+    let request = next_request(receiver).await;
+    let payload: Value = serde_json::from_slice(&request.body).unwrap();
+
+    (
+        payload["event"].as_str().unwrap().to_string(),
+        payload["data"].clone(),
+    )
+}
+
+#[tokio::test]
+async fn test_deliveries_go_to_the_author_and_followers() {
+    let app = common::create_test_app_with_env(FAST_RETRIES).await;
+    let author = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let follower = register_user(
+        app.clone(),
+        "follower",
+        "follower@example.com",
+        "password123",
+    )
+    .await;
+    let stranger = register_user(
+        app.clone(),
+        "stranger",
+        "stranger@example.com",
+        "password123",
+    )
+    .await;
+
+    let mut receivers = Vec::new();
+
+    for token in [&author, &follower, &stranger] {
+        let (url, receiver) = start_receiver(0).await;
+        create_webhook(
+            app.clone(),
+            token,
+            &url,
+            json!(["article.created", "user.followed"]),
+        )
+        .await;
+        receivers.push(receiver);
+    }
+
+    // Private authors are told about their own articles, and so are their approved followers.
+    send(
+        app.clone(),
+        "PUT",
+        "/api/user",
+        Some(&author),
+        Some(json!({ "user": { "private": true } })),
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        "/api/profiles/author/follow",
+        Some(&follower),
+        None,
+    )
+    .await;
+    send(
+        app.clone(),
+        "POST",
+        "/api/user/follow-requests/follower/approve",
+        Some(&author),
+        None,
+    )
+    .await;
+
+    let slug = create_article(app.clone(), &author, "Followed Article").await;
+    let stranger_slug = create_article(app, &stranger, "Stranger Article").await;
+
+    for receiver in &mut receivers[..2] {
+        let (event, data) = next_event(receiver).await;
+        assert_eq!(event, "user.followed");
+        assert_eq!(data["follower"]["username"], "follower");
+
+        let (event, data) = next_event(receiver).await;
+        assert_eq!(event, "article.created");
+        assert_eq!(data["article"]["slug"], slug);
+    }
+
+    // Events are published in order, the stranger would have been told about the others first.
+    let (event, data) = next_event(&mut receivers[2]).await;
+    assert_eq!(event, "article.created");
+    assert_eq!(data["article"]["slug"], stranger_slug);
+}
+
+#[tokio::test]
+async fn test_failed_delivery_is_retried() {
+    let app = common::create_test_app_with_env(FAST_RETRIES).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let (url, mut receiver) = start_receiver(2).await;
+
+    let webhook = create_webhook(app.clone(), &token, &url, json!(["article.created"])).await;
+    create_article(app.clone(), &token, "Retried Article").await;
+
+    let first = next_request(&mut receiver).await;
+    let second = next_request(&mut receiver).await;
+    let third = next_request(&mut receiver).await;
+
+    assert_eq!(first.body, third.body);
+    assert_eq!(
+        first.headers["x-realworld-delivery"],
+        second.headers["x-realworld-delivery"]
+    );
+
+    let body = settled_deliveries(app, &token, webhook["id"].as_str().unwrap()).await;
+
+    assert_eq!(body["deliveries"][0]["status"], "succeeded");
+    assert_eq!(body["deliveries"][0]["attempts"], 3);
+    assert_eq!(body["deliveries"][0]["lastResponseStatus"], 200);
+    assert_eq!(body["deliveries"][0]["lastError"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_delivery_fails_after_max_attempts() {
+    let app = common::create_test_app_with_env(FAST_RETRIES).await;
+    let token = register_user(app.clone(), "author", "author@example.com", "password123").await;
+    let (url, _receiver) = start_receiver(usize::MAX).await;
+
+    let webhook = create_webhook(app.clone(), &token, &url, json!(["article.created"])).await;
+    create_article(app.clone(), &token, "Undeliverable Article").await;
+
+    let body = settled_deliveries(app, &token, webhook["id"].as_str().unwrap()).await;
+
+    assert_eq!(body["deliveries"][0]["status"], "failed");
+    assert_eq!(body["deliveries"][0]["attempts"], 3);
+    assert_eq!(body["deliveries"][0]["lastResponseStatus"], 500);
+    assert!(body["deliveries"][0].get("nextAttemptAt").is_none());
+}
+
+#[tokio::test]
+async fn test_webhooks_are_private_to_their_owner() {
+    let app = common::create_test_app().await;
+    let owner = register_user(app.clone(), "owner", "owner@example.com", "password123").await;
+    let other = register_user(app.clone(), "other", "other@example.com", "password123").await;
+
+    let webhook = create_webhook(
+        app.clone(),
+        &owner,
+        "https://example.com/hooks",
+        json!(["article.created"]),
+    )
+    .await;
+    let id = webhook["id"].as_str().unwrap();
+
+    let (_, body) = send(app.clone(), "GET", "/api/webhooks", Some(&other), None).await;
+    assert_eq!(body["webhooks"], json!([]));
+
+    let (status, _) = send(
+        app.clone(),
+        "GET",
+        &format!("/api/webhooks/{}/deliveries", id),
+        Some(&other),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/webhooks/{}", id),
+        Some(&other),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(
+        app.clone(),
+        "DELETE",
+        &format!("/api/webhooks/{}", id),
+        Some(&owner),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, body) = send(app, "GET", "/api/webhooks", Some(&owner), None).await;
+    assert_eq!(body["webhooks"], json!([]));
+}